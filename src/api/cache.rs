@@ -0,0 +1,589 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Mutex,
+        },
+        time::{Duration, Instant},
+    },
+    bytes::Bytes,
+    reqwest::Method,
+    serde_json::Value,
+};
+
+use super::routes::const_routes::{
+    BLOCK_BY_HEIGHT_ROUTE, BLOCK_GET_RECEIPTS_ROUTE, BLOCK_GET_TRANSACTION_ROUTE, MOSAICS_ROUTE,
+    MOSAIC_NAMES_ROUTE, MOSAIC_ROUTE, NAMESPACE_NAMES_ROUTE, NETWORK_CONFIG_ROUTE,
+    TRANSACTIONS_ROUTE, TRANSACTION_ROUTE,
+};
+
+/// Default time an entry stays in the cache.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Default number of blocks below the chain height after which a block can no longer be
+/// rolled back.
+pub const DEFAULT_FINALITY_DEPTH: u64 = 360;
+
+/// Storage of the cached node responses.
+///
+/// Keys are built by [ResponseCache] and start with the route template of the request, so a
+/// whole route is removed with `remove_prefix`.
+pub trait CacheBackend: Send + Sync {
+    fn get(&self, key: &str) -> Option<Bytes>;
+
+    fn insert(&self, key: String, value: Bytes, ttl: Duration);
+
+    fn remove(&self, key: &str);
+
+    fn remove_prefix(&self, prefix: &str);
+
+    fn clear(&self);
+}
+
+struct CacheEntry {
+    value: Bytes,
+    expires_at: Instant,
+    last_used: Instant,
+}
+
+/// In-memory [CacheBackend] holding at most `capacity` entries.
+///
+/// When full, expired entries are dropped first and then the least recently used one.
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        MemoryCache {
+            capacity,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map(|entries| entries.len())
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let mut entries = self.entries.lock().ok()?;
+
+        let now = Instant::now();
+        match entries.get_mut(key) {
+            Some(entry) if entry.expires_at > now => {
+                entry.last_used = now;
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, value: Bytes, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        let now = Instant::now();
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            entries.retain(|_, entry| entry.expires_at > now);
+
+            if entries.len() >= self.capacity {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.to_owned());
+
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+
+        entries.insert(
+            key,
+            CacheEntry {
+                value,
+                expires_at: now + ttl,
+                last_used: now,
+            },
+        );
+    }
+
+    fn remove(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+
+    fn remove_prefix(&self, prefix: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|key, _| !key.starts_with(prefix));
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+/// Node data that does not change once it is returned and can be cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheableRoute {
+    /// Mosaic definitions, `MosaicRoutes::get_mosaic_info` and `get_mosaics_info`.
+    Mosaic,
+    /// Mosaic names, `MosaicRoutes::get_mosaics_names`.
+    MosaicNames,
+    /// Namespace names, `NamespaceRoutes::get_namespaces_names`.
+    NamespaceNames,
+    /// Blocks at finalized heights, `BlockRoutes::get_block_by_height`.
+    Block,
+    /// Transactions of blocks at finalized heights.
+    BlockTransactions,
+    /// Receipts of blocks at finalized heights.
+    BlockReceipts,
+    /// Transactions confirmed at finalized heights, `TransactionRoutes::get_transaction` and
+    /// `get_transactions`.
+    Transaction,
+    /// Network config at finalized heights.
+    NetworkConfig,
+}
+
+impl CacheableRoute {
+    /// Every cacheable route.
+    pub const ALL: [CacheableRoute; 8] = [
+        CacheableRoute::Mosaic,
+        CacheableRoute::MosaicNames,
+        CacheableRoute::NamespaceNames,
+        CacheableRoute::Block,
+        CacheableRoute::BlockTransactions,
+        CacheableRoute::BlockReceipts,
+        CacheableRoute::Transaction,
+        CacheableRoute::NetworkConfig,
+    ];
+
+    fn routes(self) -> &'static [(&'static str, &'static str)] {
+        use CacheableRoute::*;
+
+        match self {
+            Mosaic => &[(MOSAIC_ROUTE, "GET"), (MOSAICS_ROUTE, "POST")],
+            MosaicNames => &[(MOSAIC_NAMES_ROUTE, "POST")],
+            NamespaceNames => &[(NAMESPACE_NAMES_ROUTE, "POST")],
+            Block => &[(BLOCK_BY_HEIGHT_ROUTE, "GET")],
+            BlockTransactions => &[(BLOCK_GET_TRANSACTION_ROUTE, "GET")],
+            BlockReceipts => &[(BLOCK_GET_RECEIPTS_ROUTE, "GET")],
+            Transaction => &[(TRANSACTION_ROUTE, "GET"), (TRANSACTIONS_ROUTE, "POST")],
+            NetworkConfig => &[(NETWORK_CONFIG_ROUTE, "GET")],
+        }
+    }
+
+    /// Whether the data can change until the requested height is finalized.
+    fn is_height_bound(self) -> bool {
+        use CacheableRoute::*;
+
+        match self {
+            Block | BlockTransactions | BlockReceipts | NetworkConfig => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the data can change until the height found in the response is finalized.
+    fn is_response_height_bound(self) -> bool {
+        self == CacheableRoute::Transaction
+    }
+
+    fn __match(method: &Method, route: &str) -> Option<Self> {
+        CacheableRoute::ALL.iter().cloned().find(|cacheable| {
+            cacheable
+                .routes()
+                .iter()
+                .any(|(path, verb)| *path == route && *verb == method.as_str())
+        })
+    }
+}
+
+/// Optional cache of immutable node responses, set with [SiriusClient::set_cache].
+///
+/// Only the routes with a rule are cached, each with its own time to live. Responses of
+/// height bound routes are only stored once the height is `finality_depth` blocks below the
+/// last chain height seen by the client, so a rolled back block is never served. Transactions
+/// are gated the same way on the height they were confirmed at; unconfirmed and partial ones
+/// are never stored.
+///
+/// The cache does not poll the node: the chain height is only known after a call to
+/// `ChainRoutes::get_blockchain_height` or [ResponseCache::set_chain_height]. Until then every
+/// height bound response is fetched from the node, so refresh the height from time to time,
+/// e.g. on every new block of a websocket `block` subscription.
+///
+/// # Example
+///
+/// ```
+///use std::time::Duration;
+///use xpx_chain_sdk::api::{CacheableRoute, ResponseCache, SiriusClient};
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+/// let mut client = SiriusClient::new(node_url).await.unwrap();
+///
+///    client.set_cache(
+///        ResponseCache::in_memory(10_000)
+///            .with_rule(CacheableRoute::Mosaic, Duration::from_secs(24 * 60 * 60))
+///            .without_rule(CacheableRoute::NetworkConfig),
+///    );
+///
+///    // Forget the cached mosaic definitions, e.g. after a supply change.
+///    if let Some(cache) = client.cache() {
+///        cache.invalidate(CacheableRoute::Mosaic);
+///    }
+///}
+/// ```
+///
+/// [SiriusClient::set_cache]: struct.SiriusClient.html#method.set_cache
+pub struct ResponseCache {
+    backend: Box<dyn CacheBackend>,
+    rules: HashMap<CacheableRoute, Duration>,
+    finality_depth: u64,
+    chain_height: AtomicU64,
+}
+
+impl ResponseCache {
+    /// A cache stored in `backend` without any rule.
+    pub fn new<B: CacheBackend + 'static>(backend: B) -> Self {
+        ResponseCache {
+            backend: Box::new(backend),
+            rules: HashMap::new(),
+            finality_depth: DEFAULT_FINALITY_DEPTH,
+            chain_height: AtomicU64::new(0),
+        }
+    }
+
+    /// A [MemoryCache] of `capacity` entries caching every [CacheableRoute] for
+    /// [DEFAULT_CACHE_TTL].
+    pub fn in_memory(capacity: usize) -> Self {
+        CacheableRoute::ALL
+            .iter()
+            .fold(Self::new(MemoryCache::new(capacity)), |cache, route| {
+                cache.with_rule(*route, DEFAULT_CACHE_TTL)
+            })
+    }
+
+    pub fn with_rule(mut self, route: CacheableRoute, ttl: Duration) -> Self {
+        self.rules.insert(route, ttl);
+        self
+    }
+
+    pub fn without_rule(mut self, route: CacheableRoute) -> Self {
+        self.rules.remove(&route);
+        self
+    }
+
+    pub fn with_finality_depth(mut self, depth: u64) -> Self {
+        self.finality_depth = depth;
+        self
+    }
+
+    /// Records the current chain height; updated by `ChainRoutes::get_blockchain_height`.
+    /// Lower heights than the one already known are ignored.
+    pub fn set_chain_height(&self, height: u64) {
+        self.chain_height.fetch_max(height, Ordering::Relaxed);
+    }
+
+    /// The highest block that can no longer be rolled back, as far as the cache knows.
+    pub fn finalized_height(&self) -> u64 {
+        self.chain_height
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.finality_depth)
+    }
+
+    /// Removes every cached response of `route`.
+    pub fn invalidate(&self, route: CacheableRoute) {
+        for (path, method) in route.routes() {
+            self.backend.remove_prefix(&Self::__prefix(method, path));
+        }
+    }
+
+    /// Removes every cached response.
+    pub fn clear(&self) {
+        self.backend.clear()
+    }
+
+    /// The cache key of a request, or `None` if its response must not be cached.
+    pub(crate) fn key(
+        &self,
+        method: &Method,
+        route: &str,
+        path_params: &HashMap<String, String>,
+        url: &str,
+        body: Option<&str>,
+    ) -> Option<CacheKey> {
+        let cacheable = CacheableRoute::__match(method, route)?;
+        let ttl = *self.rules.get(&cacheable)?;
+
+        if cacheable.is_height_bound() {
+            let height: u64 = path_params.get("height")?.parse().ok()?;
+            if height == 0 || height > self.finalized_height() {
+                return None;
+            }
+        }
+
+        let key = format!(
+            "{}{} {}",
+            Self::__prefix(method.as_str(), route),
+            url,
+            body.unwrap_or_default()
+        );
+
+        Some(CacheKey {
+            key,
+            ttl,
+            route: cacheable,
+        })
+    }
+
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<Bytes> {
+        self.backend.get(&key.key)
+    }
+
+    /// Stores `value` unless it holds data that can still be rolled back.
+    pub(crate) fn insert(&self, key: CacheKey, value: Bytes) {
+        if key.route.is_response_height_bound() && !self.__is_final(&value) {
+            return;
+        }
+
+        self.backend.insert(key.key, value, key.ttl)
+    }
+
+    /// Whether every transaction of the response is confirmed at a finalized height.
+    fn __is_final(&self, value: &Bytes) -> bool {
+        let finalized_height = self.finalized_height();
+
+        let is_final = |transaction: &Value| match response_height(transaction) {
+            Some(height) => height > 0 && height <= finalized_height,
+            None => false,
+        };
+
+        match serde_json::from_slice::<Value>(value) {
+            Ok(Value::Array(transactions)) => transactions.iter().all(is_final),
+            Ok(transaction) => is_final(&transaction),
+            Err(_) => false,
+        }
+    }
+
+    fn __prefix(method: &str, route: &str) -> String {
+        format!("{} {}|", method, route)
+    }
+}
+
+/// Where the response of a cacheable request is stored.
+pub(crate) struct CacheKey {
+    key: String,
+    ttl: Duration,
+    route: CacheableRoute,
+}
+
+/// The `meta.height` of a transaction response, as the REST `[lower, higher]` pair.
+fn response_height(transaction: &Value) -> Option<u64> {
+    let height = transaction["meta"]["height"].as_array()?;
+    let lower = height.get(0)?.as_u64()?;
+    let higher = height.get(1)?.as_u64()?;
+
+    Some((higher << 32) | lower)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        ::std::{collections::HashMap, thread::sleep, time::Duration},
+        bytes::Bytes,
+        reqwest::Method,
+    };
+
+    use super::{
+        CacheBackend, CacheableRoute, MemoryCache, ResponseCache, BLOCK_BY_HEIGHT_ROUTE,
+        MOSAIC_ROUTE, TRANSACTION_ROUTE,
+    };
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn params(name: &str, value: &str) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        params.insert(name.to_string(), value.to_string());
+        params
+    }
+
+    fn block_key(cache: &ResponseCache, height: u64) -> Option<super::CacheKey> {
+        cache.key(
+            &Method::GET,
+            BLOCK_BY_HEIGHT_ROUTE,
+            &params("height", &height.to_string()),
+            &format!("http://node/block/{}", height),
+            None,
+        )
+    }
+
+    fn transaction_key(cache: &ResponseCache) -> super::CacheKey {
+        cache
+            .key(
+                &Method::GET,
+                TRANSACTION_ROUTE,
+                &params("transactionId", "A1"),
+                "http://node/transaction/A1",
+                None,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn memory_cache_expires_entries() {
+        let cache = MemoryCache::new(10);
+
+        cache.insert("short".into(), Bytes::from("1"), Duration::from_millis(0));
+        cache.insert("long".into(), Bytes::from("2"), TTL);
+
+        assert_eq!(cache.get("short"), None);
+        assert_eq!(cache.get("long"), Some(Bytes::from("2")));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn memory_cache_evicts_the_least_recently_used() {
+        let cache = MemoryCache::new(2);
+
+        cache.insert("a".into(), Bytes::from("a"), TTL);
+        sleep(Duration::from_millis(2));
+        cache.insert("b".into(), Bytes::from("b"), TTL);
+        sleep(Duration::from_millis(2));
+        assert!(cache.get("a").is_some());
+        sleep(Duration::from_millis(2));
+        cache.insert("c".into(), Bytes::from("c"), TTL);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn memory_cache_drops_expired_entries_before_used_ones() {
+        let cache = MemoryCache::new(2);
+
+        cache.insert("a".into(), Bytes::from("a"), TTL);
+        cache.insert("b".into(), Bytes::from("b"), Duration::from_millis(0));
+        cache.insert("c".into(), Bytes::from("c"), TTL);
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn height_bound_routes_wait_for_finality() {
+        let cache = ResponseCache::in_memory(10).with_finality_depth(10);
+
+        assert!(block_key(&cache, 5).is_none());
+
+        cache.set_chain_height(20);
+        assert!(block_key(&cache, 10).is_some());
+        assert!(block_key(&cache, 11).is_none());
+        assert!(block_key(&cache, 0).is_none());
+
+        // A lower height does not move the finalized height back.
+        cache.set_chain_height(12);
+        assert_eq!(cache.finalized_height(), 10);
+    }
+
+    #[test]
+    fn routes_without_rule_are_not_cached() {
+        let cache = ResponseCache::in_memory(10).without_rule(CacheableRoute::Block);
+        cache.set_chain_height(1_000);
+
+        assert!(block_key(&cache, 1).is_none());
+        assert!(cache
+            .key(
+                &Method::GET,
+                "/chain/height",
+                &HashMap::new(),
+                "http://node",
+                None
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn transactions_are_stored_once_final() {
+        let cache = ResponseCache::in_memory(10).with_finality_depth(10);
+        cache.set_chain_height(20);
+
+        let confirmed_at = |height: u64| {
+            Bytes::from(format!(
+                r#"{{"meta":{{"height":[{},0]}},"transaction":{{}}}}"#,
+                height
+            ))
+        };
+
+        cache.insert(transaction_key(&cache), confirmed_at(15));
+        assert!(cache.get(&transaction_key(&cache)).is_none());
+
+        cache.insert(transaction_key(&cache), confirmed_at(0));
+        assert!(cache.get(&transaction_key(&cache)).is_none());
+
+        cache.insert(transaction_key(&cache), confirmed_at(10));
+        assert_eq!(cache.get(&transaction_key(&cache)), Some(confirmed_at(10)));
+    }
+
+    #[test]
+    fn invalidate_removes_the_route_only() {
+        let cache = ResponseCache::in_memory(10);
+        cache.set_chain_height(1_000);
+
+        let mosaic_key = || {
+            cache
+                .key(
+                    &Method::GET,
+                    MOSAIC_ROUTE,
+                    &params("mosaicId", "0DC67FBE1CAD29E3"),
+                    "http://node/mosaic/0DC67FBE1CAD29E3",
+                    None,
+                )
+                .unwrap()
+        };
+
+        cache.insert(mosaic_key(), Bytes::from("mosaic"));
+        cache.insert(block_key(&cache, 1).unwrap(), Bytes::from("block"));
+
+        cache.invalidate(CacheableRoute::Mosaic);
+
+        assert!(cache.get(&mosaic_key()).is_none());
+        assert_eq!(
+            cache.get(&block_key(&cache, 1).unwrap()),
+            Some(Bytes::from("block"))
+        );
+    }
+}
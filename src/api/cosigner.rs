@@ -0,0 +1,426 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::HashSet,
+        sync::{Arc, Mutex},
+        time::Duration,
+    },
+    futures_util::{
+        future::{self, Either},
+        pin_mut, StreamExt,
+    },
+    tokio::{sync::mpsc, time::delay_for},
+};
+
+use crate::{
+    account::Account,
+    errors_const::ERR_COSIGNER_LOCK,
+    models::{error::Error, Result},
+    multisig::CosignatureTransaction,
+    transaction::{
+        AbsTransaction, AggregateTransaction, CosignatureSignedTransaction, Hash,
+        TransactionsPagination,
+    },
+    websocket::SiriusWebsocketClient,
+};
+
+use super::SiriusClient;
+
+const DEFAULT_BACKFILL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Decides whether an aggregate bonded transaction waiting for cosignatures gets cosigned.
+///
+/// Implemented for every `Fn(&AggregateTransaction) -> bool`.
+pub trait CosignPolicy: Send + Sync + 'static {
+    fn approve(&self, aggregate: &AggregateTransaction) -> bool;
+}
+
+impl<F> CosignPolicy for F
+where
+    F: Fn(&AggregateTransaction) -> bool + Send + Sync + 'static,
+{
+    fn approve(&self, aggregate: &AggregateTransaction) -> bool {
+        self(aggregate)
+    }
+}
+
+/// Cosigns the aggregate bonded transactions of an account that pass a [CosignPolicy].
+///
+/// New aggregates are received on the `partialAdded` websocket channel of the account and the
+/// ones announced before the cosigner started are read from the partial transactions of the
+/// account. Aggregates already signed by the account are skipped. While running, the partial
+/// transactions are read again on the backfill interval, so aggregates whose cosignature could
+/// not be announced are retried.
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::{
+///    account::Account,
+///    api::{Cosigner, SiriusClient},
+///    transaction::{AggregateTransaction, EntityTypeEnum, AbsTransaction},
+///};
+///
+///const PRIVATE_KEY: &str = "5D3E959EB0CD69CC1DB6E9C62CB81EC52747AB56FA740CF18AACB5003429AD2E";
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+/// let client = SiriusClient::new(node_url).await.unwrap();
+///
+///    let account = Account::from_private_key(PRIVATE_KEY, client.network_type()).unwrap();
+///
+///    // Only cosign aggregates made of transfers.
+///    let cosigner = Cosigner::new(&client, account, |aggregate: &AggregateTransaction| {
+///        aggregate
+///            .inner_transactions
+///            .iter()
+///            .all(|tx| tx.entity_type() == EntityTypeEnum::Transfer)
+///    });
+///
+///    if let Err(err) = cosigner.run().await {
+///        eprintln!("{}", err)
+///    }
+///}
+/// ```
+pub struct Cosigner<P> {
+    client: SiriusClient,
+    account: Account,
+    policy: Arc<P>,
+    signed: Arc<Mutex<HashSet<Hash>>>,
+    backfill_interval: Duration,
+}
+
+impl<P: CosignPolicy> Cosigner<P> {
+    pub fn new(client: &SiriusClient, account: Account, policy: P) -> Self {
+        Cosigner {
+            client: client.to_owned(),
+            account,
+            policy: Arc::new(policy),
+            signed: Arc::new(Mutex::new(HashSet::new())),
+            backfill_interval: DEFAULT_BACKFILL_INTERVAL,
+        }
+    }
+
+    /// How often [Cosigner::run] reads the partial transactions again. Defaults to one minute.
+    pub fn with_backfill_interval(mut self, interval: Duration) -> Self {
+        self.backfill_interval = interval;
+        self
+    }
+
+    /// The hashes of the aggregates cosigned so far.
+    pub fn signed(&self) -> Vec<Hash> {
+        match self.signed.lock() {
+            Ok(signed) => signed.iter().cloned().collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    /// Cosigns the aggregates already waiting for cosignatures and returns how many were
+    /// announced. An aggregate whose cosignature can not be announced is left for the next
+    /// backfill; an aggregate that can not be cosigned ends the backfill with the error.
+    pub async fn backfill(&self) -> Result<usize> {
+        let partials = self.client.account_api().partial_transactions_stream(
+            &self.account.public_account,
+            TransactionsPagination::default(),
+        );
+        pin_mut!(partials);
+
+        let mut announced = 0;
+        while let Some(transaction) = partials.next().await {
+            let transaction = transaction?;
+
+            let aggregate = match transaction.as_any().downcast_ref::<AggregateTransaction>() {
+                Some(aggregate) => aggregate,
+                None => continue,
+            };
+
+            let cosignature = match self.__cosign(aggregate)? {
+                Some(cosignature) => cosignature,
+                None => continue,
+            };
+
+            let hash = cosignature.parent_hash.to_owned();
+            match self.__announce(cosignature).await {
+                Ok(()) => announced += 1,
+                Err(_) => self.__forget(&hash),
+            }
+        }
+
+        Ok(announced)
+    }
+
+    /// Subscribes to `partialAdded`, runs [Cosigner::backfill] and cosigns every approved
+    /// aggregate until the websocket connection is closed or an aggregate can not be cosigned.
+    /// The backfill is repeated on the backfill interval.
+    pub async fn run(&self) -> Result<()> {
+        let mut ws = SiriusWebsocketClient::new(self.client.node()).await?;
+
+        let (sender, mut receiver) =
+            mpsc::unbounded_channel::<Result<CosignatureSignedTransaction>>();
+
+        let cosigner = self.__handle();
+        let _partial_added = ws
+            .add_partial_added_handlers(
+                &self.account.public_account.address,
+                move |aggregate: AggregateTransaction| {
+                    if let Some(cosignature) = cosigner.__cosign(&aggregate).transpose() {
+                        let _ = sender.send(cosignature);
+                    }
+                    false
+                },
+            )
+            .await?;
+
+        self.backfill().await?;
+
+        let listen = ws.listen();
+        let announce = async {
+            while let Some(cosignature) = receiver.recv().await {
+                let cosignature = cosignature?;
+                let hash = cosignature.parent_hash.to_owned();
+                if self.__announce(cosignature).await.is_err() {
+                    // Let the next backfill retry it.
+                    self.__forget(&hash);
+                }
+            }
+            Ok::<(), Error>(())
+        };
+        let retry = async {
+            loop {
+                delay_for(self.backfill_interval).await;
+                match self.backfill().await {
+                    // A page that could not be read is read again on the next round.
+                    Ok(_) | Err(Error::Transport(_)) | Err(Error::Api(_)) => {}
+                    Err(err) => break err,
+                }
+            }
+        };
+        pin_mut!(listen, announce, retry);
+
+        match future::select(listen, future::select(announce, retry)).await {
+            Either::Left((result, _)) => result,
+            Either::Right((Either::Left((result, _)), _)) => result,
+            Either::Right((Either::Right((err, _)), _)) => Err(err),
+        }
+    }
+
+    fn __handle(&self) -> CosignerHandle<P> {
+        CosignerHandle {
+            account: self.account.to_owned(),
+            policy: self.policy.clone(),
+            signed: self.signed.clone(),
+        }
+    }
+
+    fn __cosign(
+        &self,
+        aggregate: &AggregateTransaction,
+    ) -> Result<Option<CosignatureSignedTransaction>> {
+        self.__handle().__cosign(aggregate)
+    }
+
+    async fn __announce(&self, cosignature: CosignatureSignedTransaction) -> Result<()> {
+        self.client
+            .transaction_api()
+            .announce_aggregate_bonded_cosignature(&cosignature)
+            .await?;
+        Ok(())
+    }
+
+    fn __forget(&self, hash: &str) {
+        if let Ok(mut signed) = self.signed.lock() {
+            signed.remove(hash);
+        }
+    }
+}
+
+/// The part of a [Cosigner] moved into the websocket handler.
+struct CosignerHandle<P> {
+    account: Account,
+    policy: Arc<P>,
+    signed: Arc<Mutex<HashSet<Hash>>>,
+}
+
+impl<P: CosignPolicy> CosignerHandle<P> {
+    /// Signs `aggregate` if the policy approves it and the account did not sign it yet.
+    fn __cosign(
+        &self,
+        aggregate: &AggregateTransaction,
+    ) -> Result<Option<CosignatureSignedTransaction>> {
+        let hash = aggregate.transaction_hash().to_uppercase();
+        if hash.is_empty() {
+            return Ok(None);
+        }
+
+        let public_key = &self.account.public_account.public_key;
+
+        let already_signed = aggregate.abs_transaction.signer.public_key == *public_key
+            || aggregate
+                .cosignatures
+                .iter()
+                .any(|cosignature| cosignature.signer.public_key == *public_key);
+
+        {
+            let mut signed = self
+                .signed
+                .lock()
+                .map_err(|_| Error::internal(ERR_COSIGNER_LOCK))?;
+            if already_signed {
+                signed.insert(hash);
+                return Ok(None);
+            }
+            if signed.contains(&hash) {
+                return Ok(None);
+            }
+        }
+
+        if !self.policy.approve(aggregate) {
+            return Ok(None);
+        }
+
+        let cosignature =
+            self.account
+                .sign_cosignature_transaction(CosignatureTransaction::new(Box::new(
+                    aggregate.to_owned(),
+                ))?)?;
+
+        if let Ok(mut signed) = self.signed.lock() {
+            signed.insert(hash);
+        }
+
+        Ok(Some(cosignature))
+    }
+}
+
+#[cfg(all(test, feature = "mock-node"))]
+mod tests {
+    use crate::{
+        account::Account,
+        api::SiriusClient,
+        message::PlainMessage,
+        mock::MockNode,
+        mosaic::Mosaic,
+        transaction::{
+            AggregateTransaction, Deadline, LockFundsTransaction, Transaction, TransferTransaction,
+        },
+        Uint64,
+    };
+
+    use super::Cosigner;
+
+    struct Fixture {
+        client: SiriusClient,
+        cosigners: Vec<Account>,
+        hash: String,
+    }
+
+    /// An aggregate bonded waiting for the cosignatures of two accounts.
+    async fn fixture() -> Fixture {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+        let network_type = node.network_type();
+        let generation_hash = node.generation_hash();
+
+        let sender = Account::new(network_type);
+        let cosigners = vec![Account::new(network_type), Account::new(network_type)];
+
+        let inner_transactions = cosigners
+            .iter()
+            .map(|cosigner| {
+                let mut transfer = TransferTransaction::new(
+                    Deadline::default(),
+                    sender.address(),
+                    vec![Mosaic::xpx(1)],
+                    PlainMessage::empty(),
+                    network_type,
+                )
+                .unwrap();
+                transfer.set_aggregate(cosigner.public_account_to_owned());
+                Box::new(transfer) as Box<dyn Transaction>
+            })
+            .collect();
+        let aggregate =
+            AggregateTransaction::new_bonded(Deadline::default(), inner_transactions, network_type)
+                .unwrap();
+        let signed = sender.sign(aggregate, &generation_hash).unwrap();
+
+        let lock = LockFundsTransaction::new(
+            Deadline::default(),
+            Mosaic::xpx_relative(10),
+            Uint64::new(480),
+            signed.clone(),
+            network_type,
+        )
+        .unwrap();
+        let lock_signed = sender.sign(lock, &generation_hash).unwrap();
+        client
+            .transaction_api()
+            .announce(&lock_signed)
+            .await
+            .unwrap();
+        node.harvest();
+
+        client
+            .transaction_api()
+            .announce_aggregate_bonded(&signed)
+            .await
+            .unwrap();
+
+        Fixture {
+            client,
+            cosigners,
+            hash: signed.get_hash(),
+        }
+    }
+
+    impl Fixture {
+        async fn is_partial(&self) -> bool {
+            self.client
+                .transaction_api()
+                .get_transaction_status(&self.hash)
+                .await
+                .unwrap()
+                .is_partial()
+        }
+    }
+
+    #[tokio::test]
+    async fn a_rejected_aggregate_is_not_cosigned() {
+        let fixture = fixture().await;
+        let cosigner = Cosigner::new(
+            &fixture.client,
+            fixture.cosigners[0].to_owned(),
+            |_: &AggregateTransaction| false,
+        );
+
+        assert_eq!(cosigner.backfill().await.unwrap(), 0);
+        assert!(cosigner.signed().is_empty());
+        assert!(fixture.is_partial().await);
+    }
+
+    #[tokio::test]
+    async fn an_aggregate_already_cosigned_is_skipped_on_backfill() {
+        let fixture = fixture().await;
+        let cosign = || {
+            Cosigner::new(
+                &fixture.client,
+                fixture.cosigners[0].to_owned(),
+                |_: &AggregateTransaction| true,
+            )
+        };
+
+        assert_eq!(cosign().backfill().await.unwrap(), 1);
+
+        // A new cosigner knows nothing of the first one but reads the cosignature on the node.
+        let restarted = cosign();
+        assert_eq!(restarted.backfill().await.unwrap(), 0);
+        assert_eq!(restarted.signed(), vec![fixture.hash.clone()]);
+        assert!(fixture.is_partial().await);
+    }
+}
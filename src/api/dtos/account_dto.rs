@@ -5,7 +5,7 @@
  */
 
 use {
-    serde::{Deserialize, Deserializer},
+    serde::{de, Deserialize, Deserializer},
     serde_json::Value,
 };
 
@@ -15,7 +15,8 @@ use crate::{
         AccountPropertiesAddressModification, AccountPropertiesEntityTypeModification,
         AccountPropertiesMosaicModification, AccountPropertyType, Address,
     },
-    models::{error::Error::Failure, Result},
+    errors_const,
+    models::{error::Error, Result},
     mosaic::{Mosaic, MosaicId},
     transaction::{
         AccountPropertiesAddressTransaction, AccountPropertiesEntityTypeTransaction,
@@ -128,14 +129,22 @@ impl TransactionDto for AccountPropertiesTransactionInfoDto {
         let abs_transaction = dto.r#abstract.compact(info)?;
 
         if dto.property_type & AccountPropertyType::AllowAddress.value() != 0 {
-            let modifications: Vec<AccountPropertiesAddressModification> = dto
+            let modifications = dto
                 .modifications
                 .iter()
-                .map(move |p| AccountPropertiesAddressModification {
-                    modification_type: p.r#type,
-                    address: Address::from_encoded(p.value.as_str().unwrap()).unwrap_or_default(),
+                .map(move |p| -> Result<AccountPropertiesAddressModification> {
+                    let address = match p.value.as_str().map(Address::from_encoded) {
+                        Some(Ok(address)) => address,
+                        Some(Err(err)) => return Err(Error::decode(err.to_string())),
+                        None => return Err(Error::decode(errors_const::ERR_INVALID_ADDRESSES_HEX)),
+                    };
+
+                    Ok(AccountPropertiesAddressModification {
+                        modification_type: p.r#type,
+                        address,
+                    })
                 })
-                .collect();
+                .collect::<Result<Vec<AccountPropertiesAddressModification>>>()?;
 
             Ok(Box::new(AccountPropertiesAddressTransaction {
                 abs_transaction,
@@ -143,16 +152,18 @@ impl TransactionDto for AccountPropertiesTransactionInfoDto {
                 modifications,
             }))
         } else if dto.property_type & AccountPropertyType::AllowMosaic.value() != 0 {
-            let modifications: Vec<AccountPropertiesMosaicModification> = dto
+            let modifications = dto
                 .modifications
                 .iter()
-                .map(move |p| AccountPropertiesMosaicModification {
-                    modification_type: p.r#type.to_owned(),
-                    asset_id: Box::new(MosaicId::from(
-                        Uint64Dto::from_value(p.value.to_owned()).compact(),
-                    )),
+                .map(move |p| -> Result<AccountPropertiesMosaicModification> {
+                    Ok(AccountPropertiesMosaicModification {
+                        modification_type: p.r#type.to_owned(),
+                        asset_id: Box::new(MosaicId::from(
+                            Uint64Dto::from_value(p.value.to_owned())?.compact(),
+                        )),
+                    })
                 })
-                .collect();
+                .collect::<Result<Vec<AccountPropertiesMosaicModification>>>()?;
 
             Ok(Box::new(AccountPropertiesMosaicTransaction {
                 abs_transaction,
@@ -160,14 +171,17 @@ impl TransactionDto for AccountPropertiesTransactionInfoDto {
                 modifications,
             }))
         } else if dto.property_type & AccountPropertyType::AllowTransaction.value() != 0 {
-            let modifications: Vec<AccountPropertiesEntityTypeModification> = dto
+            let modifications = dto
                 .modifications
                 .iter()
-                .map(move |p| AccountPropertiesEntityTypeModification {
-                    modification_type: p.r#type.to_owned(),
-                    transaction_type: EntityTypeEnum::from(p.value.as_u64().unwrap() as u16),
+                .map(move |p| match p.value.as_u64() {
+                    Some(entity_type) => Ok(AccountPropertiesEntityTypeModification {
+                        modification_type: p.r#type.to_owned(),
+                        transaction_type: EntityTypeEnum::from(entity_type as u16),
+                    }),
+                    None => Err(Error::decode(errors_const::ERR_UNKNOWN_TYPE)),
                 })
-                .collect();
+                .collect::<Result<Vec<AccountPropertiesEntityTypeModification>>>()?;
 
             Ok(Box::new(AccountPropertiesEntityTypeTransaction {
                 abs_transaction,
@@ -175,7 +189,7 @@ impl TransactionDto for AccountPropertiesTransactionInfoDto {
                 modifications,
             }))
         } else {
-            Err(Failure(format_err!("invalid AccountPropertyType")))
+            Err(Error::decode("invalid AccountPropertyType"))
         }
     }
 }
@@ -204,19 +218,19 @@ impl AccountPropertiesInfoDto {
         let mut blocked_mosaic_id: Vec<MosaicId> = vec![];
         let mut blocked_entity_types: Vec<EntityTypeEnum> = vec![];
 
-        dto.properties.iter().for_each(|p_dto| {
+        for p_dto in dto.properties.iter() {
             if let Some(item) = p_dto.addresses.clone() {
                 let property_addresses = item
                     .into_iter()
-                    .map(|hex_address| Address::from_encoded(&hex_address).unwrap())
-                    .collect();
+                    .map(|hex_address| Address::from_encoded(&hex_address))
+                    .collect::<Result<Vec<Address>>>()?;
                 if p_dto.property_type == AccountPropertyType::AllowAddress.value() {
                     allowed_addresses = property_addresses;
                 } else {
                     blocked_addresses = property_addresses;
                 }
             };
-        });
+        }
 
         dto.properties.iter().for_each(|p_dto| {
             if let Some(item) = p_dto.mosaic_ids.clone() {
@@ -293,11 +307,12 @@ impl<'de> Deserialize<'de> for PropertiesDto {
         let mut entity_types = None;
 
         if property.r#type & AccountPropertyType::AllowAddress.value() != 0 {
-            addresses = Some(serde_json::from_value(property.values).unwrap());
+            addresses = Some(serde_json::from_value(property.values).map_err(de::Error::custom)?);
         } else if property.r#type & AccountPropertyType::AllowMosaic.value() != 0 {
-            mosaic_ids = Some(serde_json::from_value(property.values).unwrap())
+            mosaic_ids = Some(serde_json::from_value(property.values).map_err(de::Error::custom)?)
         } else if property.r#type & AccountPropertyType::AllowTransaction.value() != 0 {
-            entity_types = Some(serde_json::from_value(property.values).unwrap_or_default());
+            entity_types =
+                Some(serde_json::from_value(property.values).map_err(de::Error::custom)?);
         };
 
         Ok(PropertiesDto {
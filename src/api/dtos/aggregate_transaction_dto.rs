@@ -49,7 +49,7 @@ impl TransactionDto for AggregateTransactionInfoDto {
             cosignatures = c
                 .into_iter()
                 .map(|item| item.compact(abs_transaction.network_type))
-                .collect()
+                .collect::<Result<Vec<Cosignature>>>()?
         }
 
         let mut inner_transactions: Vec<Box<dyn Transaction>> = Vec::with_capacity(txs_dto.len());
@@ -7,6 +7,7 @@
 use crate::{
     account::Address,
     alias::AliasActionType,
+    api::required_field,
     models::Result,
     mosaic::MosaicId,
     namespace::{NamespaceAlias, NamespaceId},
@@ -75,7 +76,7 @@ impl TransactionDto for AddressAliasTransactionInfoDto {
 
         let info = self.meta.compact();
 
-        let address_encoded = dto.address.unwrap();
+        let address_encoded = required_field(dto.address, "address")?;
 
         let address = Address::from_encoded(&address_encoded)?;
 
@@ -106,7 +107,7 @@ impl TransactionDto for MosaicAliasTransactionInfoDto {
 
         let info = self.meta.compact();
 
-        let mosaic_id_dto = dto.mosaic_id.unwrap();
+        let mosaic_id_dto = required_field(dto.mosaic_id, "mosaicId")?;
 
         let mosaic_id = MosaicId::from(mosaic_id_dto.compact());
 
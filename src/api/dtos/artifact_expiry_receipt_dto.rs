@@ -4,22 +4,27 @@
  * license that can be found in the LICENSE file.
  */
 
-use super::{ReceiptTypeEnum, Uint64Dto};
+use crate::receipt::{ArtifactExpiryReceipt, ReceiptType};
+
+use super::Uint64Dto;
 
 /// ArtifactExpiryReceiptDto : An artifact namespace or mosaic expired.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct ArtifactExpiryReceiptDto {
     /// The version of the receipt.
-    #[serde(rename = "version")]
-    version: i32,
+    version: u32,
     #[serde(rename = "type")]
-    _type: ReceiptTypeEnum,
-    #[serde(rename = "artifactId")]
+    _type: u16,
     artifact_id: Uint64Dto,
 }
 
-#[derive(Serialize, Deserialize)]
-pub(crate) struct ArtifactExpiryReceiptDtoAllOf {
-    #[serde(rename = "artifactId")]
-    artifact_id: Uint64Dto,
+impl ArtifactExpiryReceiptDto {
+    pub fn compact(self) -> ArtifactExpiryReceipt {
+        ArtifactExpiryReceipt {
+            version: self.version,
+            receipt_type: ReceiptType::from(self._type),
+            artifact_id: self.artifact_id.compact(),
+        }
+    }
 }
@@ -4,63 +4,71 @@
  * license that can be found in the LICENSE file.
  */
 
-use super::{ReceiptTypeEnum, Uint64Dto};
+use crate::{
+    account::{Address, PublicAccount},
+    mosaic::{Mosaic, MosaicId},
+    network::NetworkType,
+    receipt::{BalanceChangeReceipt, BalanceTransferReceipt, ReceiptType},
+    Result,
+};
+
+use super::Uint64Dto;
 
 /// BalanceChangeReceiptDto : The invisible state change changed an account balance.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct BalanceChangeReceiptDto {
     /// The version of the receipt.
-    #[serde(rename = "version")]
-    version: i32,
+    version: u32,
     #[serde(rename = "type")]
-    _type: ReceiptTypeEnum,
+    _type: u16,
     /// The target account public key.
-    #[serde(rename = "account")]
     account: String,
-    #[serde(rename = "mosaic_id")]
     mosaic_id: Uint64Dto,
-    #[serde(rename = "amount")]
     amount: Uint64Dto,
 }
 
-#[derive(Serialize, Deserialize)]
-pub(crate) struct BalanceChangeReceiptDtoAllOf {
-    /// The target account public key.
-    account: String,
-    mosaic_id: Uint64Dto,
-    amount: Uint64Dto,
+impl BalanceChangeReceiptDto {
+    pub fn compact(self, network_type: NetworkType) -> Result<BalanceChangeReceipt> {
+        Ok(BalanceChangeReceipt {
+            version: self.version,
+            receipt_type: ReceiptType::from(self._type),
+            account: PublicAccount::from_public_key(&self.account, network_type)?,
+            mosaic: Mosaic::new(
+                MosaicId::from(self.mosaic_id.compact()),
+                self.amount.compact().as_u64(),
+            ),
+        })
+    }
 }
 
 /// BalanceTransferReceiptDto : The invisible state change triggered a mosaic transfer.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct BalanceTransferReceiptDto {
     /// The version of the receipt.
-    #[serde(rename = "version")]
-    version: i32,
+    version: u32,
     #[serde(rename = "type")]
-    _type: ReceiptTypeEnum,
+    _type: u16,
     /// The public key of the sender.
-    #[serde(rename = "sender")]
     sender: String,
-    /// The public key of the recipient.
-    #[serde(rename = "recipient")]
+    /// The encoded address of the recipient.
     recipient: String,
-    #[serde(rename = "mosaic_id")]
     mosaic_id: Uint64Dto,
-    #[serde(rename = "amount")]
     amount: Uint64Dto,
 }
 
-#[derive(Serialize, Deserialize)]
-pub(crate) struct BalanceTransferReceiptDtoAllOf {
-    /// The public key of the sender.
-    #[serde(rename = "sender")]
-    sender: String,
-    /// The public key of the recipient.
-    #[serde(rename = "recipient")]
-    recipient: String,
-    #[serde(rename = "mosaic_id")]
-    mosaic_id: Uint64Dto,
-    #[serde(rename = "amount")]
-    amount: Uint64Dto,
+impl BalanceTransferReceiptDto {
+    pub fn compact(self, network_type: NetworkType) -> Result<BalanceTransferReceipt> {
+        Ok(BalanceTransferReceipt {
+            version: self.version,
+            receipt_type: ReceiptType::from(self._type),
+            sender: PublicAccount::from_public_key(&self.sender, network_type)?,
+            recipient: Address::from_encoded(&self.recipient)?,
+            mosaic: Mosaic::new(
+                MosaicId::from(self.mosaic_id.compact()),
+                self.amount.compact().as_u64(),
+            ),
+        })
+    }
 }
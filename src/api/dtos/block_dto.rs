@@ -50,6 +50,7 @@ pub(crate) struct BlockDto {
 impl BlockDto {
     pub fn compact(
         self,
+        hash: String,
         generation_hash: String,
         num_transactions: u64,
         total_fee: [u32; 2],
@@ -106,6 +107,7 @@ impl BlockDto {
             difficulty: dto.difficulty.compact(),
             num_transactions,
             fee_multiplier,
+            hash,
             generation_hash,
             previous_block_hash: dto.previous_block_hash,
             block_transactions_hash: dto.block_transactions_hash,
@@ -130,6 +132,7 @@ pub(crate) struct BlockInfoDto {
 impl BlockInfoDto {
     pub fn compact(self) -> Result<BlockInfo> {
         self.block.compact(
+            self.meta.hash,
             self.meta.generation_hash,
             self.meta.num_transactions,
             self.meta.total_fee.0,
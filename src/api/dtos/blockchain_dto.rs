@@ -4,7 +4,10 @@
  * license that can be found in the LICENSE file.
  */
 
-use crate::blockchain::{BlockchainScore, HeightInfo};
+use crate::{
+    blockchain::{BlockchainScore, HeightInfo},
+    network::BlockchainUpgrade,
+};
 
 use super::{AbstractTransactionDto, Uint64Dto, UpgradeDto};
 
@@ -45,6 +48,15 @@ pub(crate) struct BlockchainUpgradeDto {
     blockchain_upgrade: UpgradeDto,
 }
 
+impl BlockchainUpgradeDto {
+    pub fn compact(&self) -> BlockchainUpgrade {
+        BlockchainUpgrade {
+            height: self.blockchain_upgrade.height.compact(),
+            block_chain_version: self.blockchain_upgrade.block_chain_version.compact(),
+        }
+    }
+}
+
 /// BlockchainUpgradeTransactionDto : Transaction that change version of blockchain.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct BlockchainUpgradeTransactionDto {
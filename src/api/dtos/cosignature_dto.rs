@@ -8,6 +8,7 @@ use crate::{
     account::PublicAccount,
     multisig::{CosignatoryModification, Cosignature, MultisigModificationType},
     network::NetworkType,
+    Result,
 };
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -19,14 +20,14 @@ pub(crate) struct CosignatoryModificationDto {
 }
 
 impl CosignatoryModificationDto {
-    pub fn compact(&self, network_type: NetworkType) -> CosignatoryModification {
+    pub fn compact(&self, network_type: NetworkType) -> Result<CosignatoryModification> {
         let public_account =
-            PublicAccount::from_public_key(&self.cosignatory_public_key, network_type).unwrap();
+            PublicAccount::from_public_key(&self.cosignatory_public_key, network_type)?;
 
-        CosignatoryModification {
+        Ok(CosignatoryModification {
             modification_type: MultisigModificationType::from(self.modification_type),
             public_account,
-        }
+        })
     }
 }
 
@@ -42,13 +43,13 @@ pub(crate) struct CosignatureDto {
 }
 
 impl CosignatureDto {
-    pub fn compact(&self, network_type: NetworkType) -> Cosignature {
-        let signer = PublicAccount::from_public_key(&self.signer, network_type).unwrap();
+    pub fn compact(&self, network_type: NetworkType) -> Result<Cosignature> {
+        let signer = PublicAccount::from_public_key(&self.signer, network_type)?;
 
-        Cosignature {
-            signature: (&self.signature).parse().unwrap(),
+        Ok(Cosignature {
+            signature: (&self.signature).parse()?,
             signer,
-        }
+        })
     }
 }
 
@@ -229,21 +229,20 @@ impl TransactionDto for ExchangeOfferTransactionInfoDto {
 
         let abs_transaction = dto.r#abstract.compact(info)?;
 
-        let confirmations: Vec<ExchangeConfirmation> = dto
+        let confirmations = dto
             .offers
             .into_iter()
-            .map(|offer| {
+            .map(|offer| -> Result<ExchangeConfirmation> {
                 let _offer = offer.offer_dto.compact();
-                ExchangeConfirmation {
+                Ok(ExchangeConfirmation {
                     offer: _offer,
                     owner: PublicAccount::from_public_key(
                         &offer.owner,
                         abs_transaction.network_type,
-                    )
-                    .unwrap(),
-                }
+                    )?,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<ExchangeConfirmation>>>()?;
 
         Ok(Box::new(ExchangeOfferTransaction {
             abs_transaction,
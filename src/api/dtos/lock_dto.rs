@@ -0,0 +1,87 @@
+// Copyright 2018 ProximaX Limited. All rights reserved.
+// Use of this source code is governed by the Apache 2.0
+// license that can be found in the LICENSE file.
+
+use crate::{
+    account::{Address, PublicAccount},
+    lock::{HashLockInfo, SecretLockInfo},
+    mosaic::{Mosaic, MosaicId},
+    network::NetworkType,
+    Result,
+};
+
+use super::Uint64Dto;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HashLockDto {
+    account: String,
+    account_address: String,
+    mosaic_id: Uint64Dto,
+    amount: Uint64Dto,
+    height: Uint64Dto,
+    status: u8,
+    hash: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct HashLockInfoDto {
+    lock: HashLockDto,
+}
+
+impl HashLockInfoDto {
+    pub fn compact(self, network_type: NetworkType) -> Result<HashLockInfo> {
+        let dto = self.lock;
+
+        Ok(HashLockInfo {
+            account: PublicAccount::from_public_key(&dto.account, network_type)?,
+            mosaic: Mosaic::new(
+                MosaicId::from(dto.mosaic_id.compact()),
+                dto.amount.compact().as_u64(),
+            ),
+            expiration_height: dto.height.compact(),
+            status: dto.status.into(),
+            hash: dto.hash,
+        })
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SecretLockDto {
+    account: String,
+    account_address: String,
+    mosaic_id: Uint64Dto,
+    amount: Uint64Dto,
+    height: Uint64Dto,
+    status: u8,
+    hash_algorithm: u8,
+    secret: String,
+    recipient: String,
+    composite_hash: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct SecretLockInfoDto {
+    lock: SecretLockDto,
+}
+
+impl SecretLockInfoDto {
+    pub fn compact(self, network_type: NetworkType) -> Result<SecretLockInfo> {
+        let dto = self.lock;
+
+        Ok(SecretLockInfo {
+            account: PublicAccount::from_public_key(&dto.account, network_type)?,
+            mosaic: Mosaic::new(
+                MosaicId::from(dto.mosaic_id.compact()),
+                dto.amount.compact().as_u64(),
+            ),
+            expiration_height: dto.height.compact(),
+            status: dto.status.into(),
+            hash_algorithm: dto.hash_algorithm.into(),
+            secret: dto.secret,
+            recipient: Address::from_encoded(&dto.recipient)?,
+            composite_hash: dto.composite_hash,
+        })
+    }
+}
@@ -4,7 +4,11 @@
  * license that can be found in the LICENSE file.
  */
 
-use crate::message::{Message, PlainMessage};
+use crate::{
+    errors_const,
+    message::{Message, PlainMessage},
+    Error, Result,
+};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct MessageDto {
@@ -15,18 +19,18 @@ pub(crate) struct MessageDto {
 }
 
 impl MessageDto {
-    pub fn compact(&self) -> Box<dyn Message> {
+    pub fn compact(&self) -> Result<Box<dyn Message>> {
         if self._type == 0 {
             let plain = if !self.payload.is_empty() {
-                let b = hex::decode(&self.payload).unwrap();
-                PlainMessage::new(&String::from_utf8(b).unwrap())
+                let b = hex::decode(&self.payload)?;
+                PlainMessage::new(&String::from_utf8_lossy(&b))
             } else {
                 PlainMessage::default()
             };
 
-            Box::new(plain)
+            Ok(Box::new(plain))
         } else {
-            unimplemented!()
+            Err(Error::decode(errors_const::ERR_UNKNOWN_TYPE))
         }
     }
 }
@@ -6,20 +6,26 @@
 
 pub(crate) use self::account_dto::*;
 pub(crate) use self::alias_dto::*;
+pub(crate) use self::artifact_expiry_receipt_dto::*;
+pub(crate) use self::balance_change_receipt_dto::*;
 pub(crate) use self::block_dto::*;
 pub(crate) use self::blockchain_dto::*;
 pub(crate) use self::cosignature_dto::*;
 pub(crate) use self::exchange_dto::*;
 pub(crate) use self::field_dto::*;
+pub(crate) use self::lock_dto::*;
 pub(crate) use self::message_dto::*;
 pub(crate) use self::metadata_dto::*;
 pub(crate) use self::mosaic_dto::*;
 pub(crate) use self::multisig_dto::*;
 pub(crate) use self::namespace_dto::*;
+pub(crate) use self::network_type_dto::*;
 pub(crate) use self::node_dto::*;
 pub(crate) use self::receipt_dto::*;
 pub(crate) use self::resolution_dto::*;
+pub(crate) use self::server_dto::*;
 pub(crate) use self::source_dto::*;
+pub(crate) use self::statements_dto::*;
 pub(crate) use self::transaction_dto::*;
 pub(crate) use self::uint_64_dto::*;
 pub(crate) use self::upgrade_dto::*;
@@ -35,6 +41,7 @@ mod blockchain_dto;
 mod cosignature_dto;
 mod exchange_dto;
 mod field_dto;
+mod lock_dto;
 mod message_dto;
 mod metadata_dto;
 mod mosaic_dto;
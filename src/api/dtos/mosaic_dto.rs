@@ -45,7 +45,7 @@ impl MosaicInfoDto {
     pub fn compact(&self) -> crate::Result<MosaicInfo> {
         ensure!(
             !self.mosaic.properties.is_empty(),
-            errors_const::ERR_INVALID_MOSAIC_PROPERTIES
+            decode: errors_const::ERR_INVALID_MOSAIC_PROPERTIES
         );
 
         let mosaic_id = MosaicId::from(self.mosaic.mosaic_id.compact());
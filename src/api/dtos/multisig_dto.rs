@@ -6,7 +6,7 @@
 
 use crate::{
     account::{Address, PublicAccount},
-    api::cosignatory_dto_vec_to_struct,
+    api::{cosignatory_dto_vec_to_struct, required_field},
     models::Result,
     multisig::MultisigAccountInfo,
     network::NetworkType,
@@ -39,11 +39,10 @@ pub(crate) struct MultisigAccountGraphInfoDto {
 
 impl MultisigAccountGraphInfoDto {
     pub fn compact(&self) -> crate::Result<Vec<MultisigAccountInfo>> {
-        Ok(self
-            .multisig_entries
+        self.multisig_entries
             .iter()
-            .map(|item| item.compact().unwrap())
-            .collect())
+            .map(|item| item.compact())
+            .collect()
     }
 }
 
@@ -57,20 +56,21 @@ impl MultisigAccountInfoDto {
     pub fn compact(&self) -> crate::Result<MultisigAccountInfo> {
         let dto = self.multisig.to_owned();
         let network_type: NetworkType =
-            Address::from_encoded(&dto.account_address.unwrap())?.network_type;
+            Address::from_encoded(&required_field(dto.account_address, "accountAddress")?)?
+                .network_type;
         let account = PublicAccount::from_public_key(&dto.account, network_type)?;
 
         let cs = dto
             .cosignatories
             .iter()
-            .map(|item| PublicAccount::from_public_key(item, network_type).unwrap())
-            .collect();
+            .map(|item| PublicAccount::from_public_key(item, network_type))
+            .collect::<crate::Result<Vec<PublicAccount>>>()?;
 
         let ms = dto
             .multisig_accounts
             .iter()
-            .map(|item| PublicAccount::from_public_key(item, network_type).unwrap())
-            .collect();
+            .map(|item| PublicAccount::from_public_key(item, network_type))
+            .collect::<crate::Result<Vec<PublicAccount>>>()?;
 
         Ok(MultisigAccountInfo {
             account,
@@ -109,7 +109,7 @@ impl TransactionDto for ModifyMultisigAccountTransactionInfoDto {
         let abs_transaction = dto.r#abstract.compact(info)?;
 
         let modifications =
-            cosignatory_dto_vec_to_struct(dto.modifications, abs_transaction.network_type);
+            cosignatory_dto_vec_to_struct(dto.modifications, abs_transaction.network_type)?;
 
         Ok(Box::new(ModifyMultisigAccountTransaction {
             abs_transaction,
@@ -59,7 +59,7 @@ impl NamespaceDto {
         extract_level(self.level1.to_owned());
         extract_level(self.level2.to_owned());
 
-        ensure!(!levels.is_empty(), errors_const::ERR_EMPTY_NAMESPACE_ID);
+        ensure!(!levels.is_empty(), decode: errors_const::ERR_EMPTY_NAMESPACE_ID);
 
         Ok(levels)
     }
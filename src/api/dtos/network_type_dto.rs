@@ -2,6 +2,13 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
+use crate::network::{
+    NetworkConfig, NetworkConfigInfo, NetworkInfo, MIJIN, MIJIN_TEST, NOT_SUPPORTED_NET, PRIVATE,
+    PRIVATE_TEST, PUBLIC, PUBLIC_TEST,
+};
+
+use super::Uint64Dto;
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct NetworkTypeDto {
     /// The name of the network.
@@ -10,4 +17,48 @@ pub(crate) struct NetworkTypeDto {
     /// A short text describing the network.
     #[serde(rename = "description")]
     description: String,
-}
\ No newline at end of file
+}
+
+impl NetworkTypeDto {
+    pub fn compact(self) -> NetworkInfo {
+        let network_type = match self.name.replace('_', "").to_lowercase().as_str() {
+            "mijin" => MIJIN,
+            "mijintest" => MIJIN_TEST,
+            "public" => PUBLIC,
+            "publictest" => PUBLIC_TEST,
+            "private" => PRIVATE,
+            "privatetest" => PRIVATE_TEST,
+            _ => NOT_SUPPORTED_NET,
+        };
+
+        NetworkInfo {
+            network_type,
+            name: self.name,
+            description: self.description,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct NetworkConfigDto {
+    #[serde(rename = "networkConfig")]
+    network_config: ConfigDto,
+}
+
+impl NetworkConfigDto {
+    pub fn compact(self) -> crate::Result<NetworkConfigInfo> {
+        Ok(NetworkConfigInfo {
+            started_height: self.network_config.height.compact(),
+            network_config: NetworkConfig::parse(&self.network_config.network_config)?,
+            supported_entity_versions: self.network_config.supported_entity_versions,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConfigDto {
+    height: Uint64Dto,
+    network_config: String,
+    supported_entity_versions: String,
+}
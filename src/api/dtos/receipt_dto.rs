@@ -2,64 +2,128 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
-/// ReceiptTypeEnum : The type of the receipt: * 0x134D (4941 decimal) - Mosaic_Rental_Fee. * 0x124E (4686 decimal) - Namespace_Rental_Fee. * 0x2143 (8515 decimal) - Harvest_Fee. * 0x2248 (8776 decimal) - LockHash_Completed. * 0x2348 (9032 decimal) - LockHash_Expired. * 0x2252 (8786 decimal) - LockSecret_Completed. * 0x2352 (9042 decimal) - LockSecret_Expired. * 0x3148 (12616 decimal) - LockHash_Created. * 0x3152 (12626 decimal) - LockSecret_Created. * 0x414D (16717 decimal) - Mosaic_Expired. * 0x414E (16718 decimal) - Namespace_Expired. * 0x5143 (20803 decimal) - Inflation. * 0xE134 (57652 decimal) - Transaction_Group. * 0xF143 (61763 decimal) - Address_Alias_Resolution. * 0xF243 (62019 decimal) - Mosaic_Alias_Resolution.
-/// The type of the receipt: * 0x134D (4941 decimal) - Mosaic_Rental_Fee. * 0x124E (4686 decimal) - Namespace_Rental_Fee. * 0x2143 (8515 decimal) - Harvest_Fee. * 0x2248 (8776 decimal) - LockHash_Completed. * 0x2348 (9032 decimal) - LockHash_Expired. * 0x2252 (8786 decimal) - LockSecret_Completed. * 0x2352 (9042 decimal) - LockSecret_Expired. * 0x3148 (12616 decimal) - LockHash_Created. * 0x3152 (12626 decimal) - LockSecret_Created. * 0x414D (16717 decimal) - Mosaic_Expired. * 0x414E (16718 decimal) - Namespace_Expired. * 0x5143 (20803 decimal) - Inflation. * 0xE134 (57652 decimal) - Transaction_Group. * 0xF143 (61763 decimal) - Address_Alias_Resolution. * 0xF243 (62019 decimal) - Mosaic_Alias_Resolution.
-#[derive(Serialize, Deserialize)]
-pub(crate) enum ReceiptTypeEnum {
-    #[serde(rename = "4685")]
-    _4685,
-    #[serde(rename = "4941")]
-    _4941,
-    #[serde(rename = "4686")]
-    _4686,
-    #[serde(rename = "8515")]
-    _8515,
-    #[serde(rename = "8776")]
-    _8776,
-    #[serde(rename = "9042")]
-    _9042,
-    #[serde(rename = "12616")]
-    _12616,
-    #[serde(rename = "12626")]
-    _12626,
-    #[serde(rename = "16717")]
-    _16717,
-    #[serde(rename = "16718")]
-    _16718,
-    #[serde(rename = "20803")]
-    _20803,
-    #[serde(rename = "57652")]
-    _57652,
-    #[serde(rename = "61763")]
-    _61763,
-    #[serde(rename = "62019")]
-    _62019,
-}
+use serde_json::Value;
+
+use crate::{
+    mosaic::{Mosaic, MosaicId},
+    network::NetworkType,
+    receipt::{InflationReceipt, Receipt, ReceiptType},
+    Result,
+};
+
+use super::{
+    ArtifactExpiryReceiptDto, BalanceChangeReceiptDto, BalanceTransferReceiptDto, Uint64Dto,
+};
 
 #[derive(Serialize, Deserialize)]
 pub(crate) struct ReceiptDto {
     /// The version of the receipt.
     #[serde(rename = "version")]
     pub version: u32,
+    /// The type of the receipt, see [ReceiptType].
     #[serde(rename = "type")]
-    pub _type: ReceiptTypeEnum,
+    pub _type: u16,
+}
+
+impl ReceiptDto {
+    /// Picks the receipt dto matching the `type` of the raw json receipt. Receipts of an
+    /// unsupported type are returned as [Receipt::Unknown].
+    pub fn compact(value: Value, network_type: NetworkType) -> Result<Receipt> {
+        let header: ReceiptDto = serde_json::from_value(value.clone())?;
+
+        let receipt_type = ReceiptType::from(header._type);
+
+        let receipt = match receipt_type.basic_type() {
+            0x1 => {
+                let dto: BalanceTransferReceiptDto = serde_json::from_value(value)?;
+                Receipt::BalanceTransfer(dto.compact(network_type)?)
+            }
+            0x2 | 0x3 => {
+                let dto: BalanceChangeReceiptDto = serde_json::from_value(value)?;
+                Receipt::BalanceChange(dto.compact(network_type)?)
+            }
+            0x4 => {
+                let dto: ArtifactExpiryReceiptDto = serde_json::from_value(value)?;
+                Receipt::ArtifactExpiry(dto.compact())
+            }
+            0x5 => {
+                let dto: InflationReceiptDto = serde_json::from_value(value)?;
+                Receipt::Inflation(dto.compact())
+            }
+            // Newer node versions may add receipt types; keep them instead of failing the
+            // whole statement set.
+            _ => Receipt::Unknown {
+                version: header.version,
+                receipt_type,
+                raw: value,
+            },
+        };
+
+        Ok(receipt)
+    }
 }
 
 /// InflationReceiptDto : Native currency mosaics were created due to inflation.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct InflationReceiptDto {
     /// The version of the receipt.
     version: u32,
     #[serde(rename = "type")]
-    _type: ReceiptTypeEnum,
-    mosaic_id: Vec<i32>,
-    amount: Vec<i32>,
+    _type: u16,
+    mosaic_id: Uint64Dto,
+    amount: Uint64Dto,
 }
 
-#[derive(Serialize, Deserialize)]
-pub(crate) struct InflationReceiptDtoAllOf {
-    #[serde(rename = "mosaic_id")]
-    mosaic_id: Vec<i32>,
-    #[serde(rename = "amount")]
-    amount: Vec<i32>,
+impl InflationReceiptDto {
+    pub fn compact(self) -> InflationReceipt {
+        InflationReceipt {
+            version: self.version,
+            receipt_type: ReceiptType::from(self._type),
+            mosaic: Mosaic::new(
+                MosaicId::from(self.mosaic_id.compact()),
+                self.amount.compact().as_u64(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{network::PUBLIC_TEST, receipt::Receipt};
+
+    use super::ReceiptDto;
+
+    const PUBLIC_KEY: &str = "C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2";
+
+    #[test]
+    fn known_receipts_are_decoded() {
+        let receipt = json!({
+            "version": 1,
+            "type": 0x2143,
+            "account": PUBLIC_KEY,
+            "mosaicId": [519256100, 642862634],
+            "amount": [100, 0],
+        });
+
+        match ReceiptDto::compact(receipt, PUBLIC_TEST).unwrap() {
+            Receipt::BalanceChange(change) => assert!(change.is_credit()),
+            other => panic!("unexpected receipt {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_receipts_are_kept() {
+        let receipt = json!({ "version": 1, "type": 0x6143, "data": "FF" });
+
+        match ReceiptDto::compact(receipt.clone(), PUBLIC_TEST).unwrap() {
+            Receipt::Unknown { version, raw, .. } => {
+                assert_eq!(version, 1);
+                assert_eq!(raw, receipt);
+            }
+            other => panic!("unexpected receipt {:?}", other),
+        }
+    }
 }
@@ -4,21 +4,87 @@
  * license that can be found in the LICENSE file.
  */
 
+use crate::{
+    account::Address,
+    mosaic::MosaicId,
+    receipt::{
+        AddressResolutionEntry, AddressResolutionStatement, MosaicResolutionEntry,
+        MosaicResolutionStatement,
+    },
+    Result,
+};
+
 use super::{SourceDto, Uint64Dto};
 
 #[derive(Serialize, Deserialize)]
-pub(crate) struct ResolutionEntryDto {
+pub(crate) struct AddressResolutionEntryDto {
+    #[serde(rename = "source")]
+    source: SourceDto,
+    /// The encoded address.
+    #[serde(rename = "resolved")]
+    resolved: String,
+}
+
+/// AddressResolutionStatementDto : A resolution statement keeps the relation between a namespace alias used in a transaction and the real address.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AddressResolutionStatementDto {
+    height: Uint64Dto,
+    /// The encoded unresolved address.
+    unresolved: String,
+    /// The array of resolution entries linked to the unresolved namespace_id. It is an array instead of a single UInt64 field since within one block the resolution might change for different sources due to alias related transactions.
+    resolution_entries: Vec<AddressResolutionEntryDto>,
+}
+
+impl AddressResolutionStatementDto {
+    pub fn compact(self) -> Result<AddressResolutionStatement> {
+        let mut resolution_entries = vec![];
+        for entry in self.resolution_entries.into_iter() {
+            resolution_entries.push(AddressResolutionEntry {
+                source: entry.source.compact(),
+                resolved: Address::from_encoded(&entry.resolved)?,
+            });
+        }
+
+        Ok(AddressResolutionStatement {
+            height: self.height.compact(),
+            unresolved: Address::from_encoded(&self.unresolved)?,
+            resolution_entries,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct MosaicResolutionEntryDto {
     #[serde(rename = "source")]
     source: SourceDto,
     #[serde(rename = "resolved")]
     resolved: Uint64Dto,
 }
 
-/// ResolutionStatementDto : A resolution statement keeps the relation between a namespace alias used in a transaction and the real address or mosaic_id.
+/// MosaicResolutionStatementDto : A resolution statement keeps the relation between a namespace alias used in a transaction and the real mosaic_id.
 #[derive(Serialize, Deserialize)]
-pub(crate) struct ResolutionStatementDto {
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MosaicResolutionStatementDto {
     height: Uint64Dto,
     unresolved: Uint64Dto,
     /// The array of resolution entries linked to the unresolved namespace_id. It is an array instead of a single UInt64 field since within one block the resolution might change for different sources due to alias related transactions.
-    resolution_entries: Vec<ResolutionEntryDto>,
+    resolution_entries: Vec<MosaicResolutionEntryDto>,
+}
+
+impl MosaicResolutionStatementDto {
+    pub fn compact(self) -> MosaicResolutionStatement {
+        MosaicResolutionStatement {
+            height: self.height.compact(),
+            unresolved: self.unresolved.compact(),
+            resolution_entries: self
+                .resolution_entries
+                .into_iter()
+                .map(|entry| MosaicResolutionEntry {
+                    source: entry.source.compact(),
+                    resolved: MosaicId::from(entry.resolved.compact()),
+                })
+                .collect(),
+        }
+    }
 }
@@ -2,15 +2,28 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
+use crate::node::ServerInfo;
+
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct ServerDto {
     server_info: ServerInfoDto,
 }
 
+impl ServerDto {
+    pub fn compact(self) -> ServerInfo {
+        ServerInfo {
+            rest_version: self.server_info.rest_version,
+            sdk_version: self.server_info.sdk_version,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct ServerInfoDto {
     /// The catapult-rest component version.
     rest_version: String,
     /// The catapult-sdk component version.
     sdk_version: String,
-}
\ No newline at end of file
+}
@@ -2,11 +2,23 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
+use crate::receipt::ReceiptSource;
+
 /// SourceDto : The transaction that triggered the receipt.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct SourceDto {
     /// The transaction index within the block.
-    primary_id: i32,
+    primary_id: u32,
     /// The transaction index inside within the aggregate transaction. If the transaction is not an inner transaction, then the secondary id is set to 0.
-    secondary_id: i32,
+    secondary_id: u32,
+}
+
+impl SourceDto {
+    pub fn compact(&self) -> ReceiptSource {
+        ReceiptSource {
+            primary_id: self.primary_id,
+            secondary_id: self.secondary_id,
+        }
+    }
 }
@@ -4,24 +4,76 @@
  * license that can be found in the LICENSE file.
  */
 
-use super::{ResolutionStatementDto, SourceDto};
+use serde_json::Value;
+
+use crate::{
+    network::NetworkType,
+    receipt::{Statements, TransactionStatement},
+    Result,
+};
+
+use super::{
+    AddressResolutionStatementDto, MosaicResolutionStatementDto, ReceiptDto, SourceDto, Uint64Dto,
+};
 
 /// StatementsDto : The collection of transaction statements and resolutions triggered for the block requested.
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct StatementsDto {
     /// The array of transaction statements for the block requested.
     transaction_statements: Vec<TransactionStatementDto>,
     /// The array of address resolutions for the block requested.
-    address_resolution_statements: Vec<ResolutionStatementDto>,
+    address_resolution_statements: Vec<AddressResolutionStatementDto>,
     /// The array of mosaic resolutions for the block requested.
-    mosaic_resolution_statements: Vec<ResolutionStatementDto>,
+    mosaic_resolution_statements: Vec<MosaicResolutionStatementDto>,
+}
+
+impl StatementsDto {
+    pub fn compact(self, network_type: NetworkType) -> Result<Statements> {
+        let mut transaction_statements = vec![];
+        for statement in self.transaction_statements.into_iter() {
+            transaction_statements.push(statement.compact(network_type)?);
+        }
+
+        let mut address_resolution_statements = vec![];
+        for statement in self.address_resolution_statements.into_iter() {
+            address_resolution_statements.push(statement.compact()?);
+        }
+
+        let mosaic_resolution_statements = self
+            .mosaic_resolution_statements
+            .into_iter()
+            .map(|statement| statement.compact())
+            .collect();
+
+        Ok(Statements {
+            transaction_statements,
+            address_resolution_statements,
+            mosaic_resolution_statements,
+        })
+    }
 }
 
 /// TransactionStatementDto : The collection of receipts related to a transaction.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct TransactionStatementDto {
-    height: Vec<i32>,
+    height: Uint64Dto,
     source: SourceDto,
-    /// The array of receipts.
-    receipts: Vec<String>,
+    /// The array of receipts, each one shaped by its `type`.
+    receipts: Vec<Value>,
+}
+
+impl TransactionStatementDto {
+    pub fn compact(self, network_type: NetworkType) -> Result<TransactionStatement> {
+        let mut receipts = vec![];
+        for receipt in self.receipts.into_iter() {
+            receipts.push(ReceiptDto::compact(receipt, network_type)?);
+        }
+
+        Ok(TransactionStatement {
+            height: self.height.compact(),
+            source: self.source.compact(),
+            receipts,
+        })
+    }
 }
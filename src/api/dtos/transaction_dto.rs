@@ -6,6 +6,7 @@
 
 use crate::{
     account::{Address, PublicAccount},
+    api::required_field,
     models::Result,
     mosaic::{Mosaic, MosaicId},
     network::extract_network_type,
@@ -188,7 +189,7 @@ pub(crate) struct TransactionStatusDto {
 }
 
 impl TransactionStatusDto {
-    pub fn compact(&self) -> TransactionStatus {
+    pub fn compact(&self) -> Result<TransactionStatus> {
         let dto = &self.to_owned();
 
         let mut deadline = None;
@@ -202,13 +203,13 @@ impl TransactionStatusDto {
             height = Some(value.compact());
         };
 
-        TransactionStatus::new(
-            dto.group.clone().unwrap(),
+        Ok(TransactionStatus::new(
+            required_field(dto.group.clone(), "group")?,
             dto.status.clone(),
-            dto.hash.clone().unwrap(),
+            required_field(dto.hash.clone(), "hash")?,
             deadline,
             height,
-        )
+        ))
     }
 }
 
@@ -240,7 +241,7 @@ impl TransactionDto for TransferTransactionInfoDto {
             abs_transaction,
             recipient,
             mosaics,
-            message: dto.message.compact(),
+            message: dto.message.compact()?,
         }))
     }
 }
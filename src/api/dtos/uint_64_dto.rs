@@ -16,7 +16,7 @@ impl Uint64Dto {
         Uint64::from_ints(self.0[0], self.0[1])
     }
 
-    pub fn from_value(value: Value) -> Self {
-        Self(serde_json::from_value(value).unwrap())
+    pub fn from_value(value: Value) -> crate::Result<Self> {
+        Ok(Self(serde_json::from_value(value)?))
     }
 }
@@ -2,10 +2,12 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
+use super::Uint64Dto;
+
 #[derive(Serialize, Deserialize)]
 pub(crate) struct UpgradeDto {
     #[serde(rename = "height")]
-    height: Vec<i32>,
+    pub height: Uint64Dto,
     #[serde(rename = "blockChainVersion")]
-    block_chain_version: Vec<i32>,
-}
\ No newline at end of file
+    pub block_chain_version: Uint64Dto,
+}
@@ -18,7 +18,7 @@ use crate::{
     network::NetworkType,
     transaction::{EntityTypeEnum as Entity, Hash},
     utils::is_hex,
-    Result, Uint64,
+    Error, Result, Uint64,
 };
 
 use super::dtos::{CosignatoryModificationDto, MosaicPropertyDto, TransactionDto};
@@ -38,7 +38,7 @@ impl AccountTransactionsOption {
             ensure!(
                 ordering.unwrap() == TRANSACTION_ORDER_ASC
                     || ordering.unwrap() == TRANSACTION_ORDER_DESC,
-                "Invalid value { } ordering",
+                "ordering" => "Invalid value { } ordering",
                 ordering.unwrap()
             );
         }
@@ -60,18 +60,18 @@ impl AccountTransactionsOption {
 pub(crate) fn str_to_hash(hash: &str) -> Result<Hash> {
     let raw_hash = hash.trim().to_uppercase();
 
-    ensure!(!raw_hash.is_empty(), errors_const::ERR_INVALID_HASH_HEX);
+    ensure!(!raw_hash.is_empty(), "hash" => errors_const::ERR_INVALID_HASH_HEX);
 
     ensure!(
         is_hex(&raw_hash),
-        "{} {}.",
+        "hash" => "{} {}.",
         errors_const::ERR_INVALID_HASH_HEX,
         raw_hash
     );
 
     ensure!(
         raw_hash.len() == 64,
-        "{} {}.",
+        "hash" => "{} {}.",
         errors_const::ERR_INVALID_HASH_LENGTH,
         raw_hash
     );
@@ -91,12 +91,12 @@ pub(crate) fn str_to_account_id(id: &str) -> Result<AccountId> {
     match id.trim().len() {
         64 => {
             if !is_hex(id) {
-                bail!(errors_const::ERR_INVALID_ACCOUNT_ID)
+                bail!("account_id" => errors_const::ERR_INVALID_ACCOUNT_ID)
             }
             Ok(id.to_uppercase())
         }
         40 | 46 => Ok(id.to_uppercase().replace("-", "")),
-        _ => bail!(errors_const::ERR_INVALID_ACCOUNT_ID),
+        _ => bail!("account_id" => errors_const::ERR_INVALID_ACCOUNT_ID),
     }
 }
 
@@ -109,13 +109,28 @@ pub(crate) fn valid_vec_hash(vector: &[&str]) -> Result<()> {
     Ok(())
 }
 
+/// Returns the value of an optional response field or a decode error naming it.
+pub(crate) fn required_field<T>(value: Option<T>, field: &str) -> Result<T> {
+    value.ok_or_else(|| {
+        Error::decode(format!(
+            "{} {}.",
+            errors_const::ERR_MISSING_RESPONSE_FIELD,
+            field
+        ))
+    })
+}
+
 pub(crate) fn map_transaction_dto_vec(body: Bytes) -> Result<String> {
     let value_dto_vec: Value = serde_json::from_slice(&body)?;
 
+    let dto_vec = value_dto_vec
+        .as_array()
+        .ok_or_else(|| Error::decode(errors_const::ERR_INVALID_TRANSACTIONS_DTO))?;
+
     let mut value_dto_vec_str: String = "".to_string();
     value_dto_vec_str.write_char('[')?;
-    for dto in 0..value_dto_vec.as_array().unwrap().len() {
-        let to_array = &value_dto_vec.as_array().unwrap()[dto];
+    for dto in 0..dto_vec.len() {
+        let to_array = &dto_vec[dto];
 
         let to_string = format!("{}", to_array);
 
@@ -123,7 +138,7 @@ pub(crate) fn map_transaction_dto_vec(body: Bytes) -> Result<String> {
 
         value_dto_vec_str.push_str(&serde_json::to_string(&transaction_dto)?);
 
-        if value_dto_vec.as_array().unwrap().len() != dto + 1 {
+        if dto_vec.len() != dto + 1 {
             value_dto_vec_str.write_char(',')?;
         }
     }
@@ -142,7 +157,10 @@ pub(crate) fn map_transaction_dto_vec(body: Bytes) -> Result<String> {
 pub(crate) fn map_transaction_dto(body: Bytes) -> Result<String> {
     let mut value_dto: Value = serde_json::from_slice(&body)?;
 
-    let entity_type = Entity::from(value_dto["transaction"]["type"].as_u64().unwrap() as u16);
+    let entity_type = match value_dto["transaction"]["type"].as_u64() {
+        Some(entity_type) => Entity::from(entity_type as u16),
+        None => return Err(Error::decode(errors_const::ERR_INVALID_TRANSACTION_DTO)),
+    };
 
     let entity_dto = match entity_type {
         Entity::AccountLink => "AccountLink",
@@ -176,7 +194,7 @@ pub(crate) fn map_transaction_dto(body: Bytes) -> Result<String> {
     }
 
     if value_dto["meta"].is_null() {
-        Ok(parse_meta(value_dto, entity_dto))
+        parse_meta(value_dto, entity_dto)
     } else {
         Ok(parse_entity_type_dto(value_dto, entity_dto))
     }
@@ -204,12 +222,15 @@ fn parse_meta_ws(value_dto: &mut Value) -> Result<()> {
     Ok(())
 }
 
-fn parse_meta(value_dto: Value, entity_dto: &str) -> String {
+fn parse_meta(value_dto: Value, entity_dto: &str) -> Result<String> {
     let meta = r#"{"meta": {}, "transaction":"#;
 
     let parse_meta = format!("{}", value_dto).replace(r#"{"transaction":"#, meta);
 
-    parse_entity_type_dto(parse_meta.parse().unwrap(), entity_dto)
+    Ok(parse_entity_type_dto(
+        serde_json::from_str(&parse_meta)?,
+        entity_dto,
+    ))
 }
 
 fn parse_entity_type_dto(value_dto: Value, entity_dto: &str) -> String {
@@ -243,7 +264,7 @@ pub(crate) fn mosaic_properties(dto: &[MosaicPropertyDto]) -> Result<MosaicPrope
             0 => flags = property.value.compact(),
             1 => divisibility = *property.value.compact() as u8,
             2 => duration = property.value.compact(),
-            _ => bail!("Unknown Property Id"),
+            _ => bail!(decode: "Unknown Property Id"),
         }
     }
 
@@ -258,7 +279,7 @@ pub(crate) fn mosaic_properties(dto: &[MosaicPropertyDto]) -> Result<MosaicPrope
 pub(crate) fn cosignatory_dto_vec_to_struct(
     modifications: Vec<CosignatoryModificationDto>,
     network_type: NetworkType,
-) -> Vec<CosignatoryModification> {
+) -> Result<Vec<CosignatoryModification>> {
     modifications
         .into_iter()
         .map(|item| item.compact(network_type))
@@ -268,3 +289,151 @@ pub(crate) fn cosignatory_dto_vec_to_struct(
 pub(crate) fn has_bits(number: Uint64, bits: u8) -> bool {
     (*number & bits as u64) == bits as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::{map_transaction_dto, map_transaction_dto_vec, TransactionDto};
+
+    const TRANSFER: &str = r#"{"meta":{"height":[10,0],"hash":"5D5A9D0E6A8E5C5A1C8E1D8C2A3A8B2F6B1D3E0E4F1C2A9D8B7C6E5F4A3B2C1D","merkleComponentHash":"5D5A9D0E6A8E5C5A1C8E1D8C2A3A8B2F6B1D3E0E4F1C2A9D8B7C6E5F4A3B2C1D","index":0,"id":"5E6A9E2B1C0D4E3F2A1B0C9D"},"transaction":{"signature":"AB3C6F0E0A7B3C3D1E4F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F8A9B0C1D2E3F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F","signer":"C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","version":2818572291,"type":16724,"maxFee":[0,0],"deadline":[123456,0],"recipient":"A8F5B1E3C8D2A4F6B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","message":{"type":0,"payload":"48656C6C6F"},"mosaics":[{"id":[519256100,642862634],"amount":[10000000,0]}]}}"#;
+
+    const AGGREGATE: &str = r#"{"meta":{"height":[10,0],"hash":"5D5A9D0E6A8E5C5A1C8E1D8C2A3A8B2F6B1D3E0E4F1C2A9D8B7C6E5F4A3B2C1D","index":0,"id":"5E6A9E2B1C0D4E3F2A1B0C9D"},"transaction":{"signature":"AB3C6F0E0A7B3C3D1E4F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F8A9B0C1D2E3F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F","signer":"C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","version":2818572290,"type":16961,"maxFee":[0,0],"deadline":[123456,0],"cosignatures":[{"signature":"AB3C6F0E0A7B3C3D1E4F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F8A9B0C1D2E3F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F","signer":"C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2"}],"transactions":[{"meta":{"aggregateHash":"5D5A9D0E6A8E5C5A1C8E1D8C2A3A8B2F6B1D3E0E4F1C2A9D8B7C6E5F4A3B2C1D","index":0,"id":"5E6A9E2B1C0D4E3F2A1B0C9E"},"transaction":{"signer":"C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","version":2818572291,"type":16724,"recipient":"A8F5B1E3C8D2A4F6B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","message":{"type":0,"payload":""},"mosaics":[]}}]}}"#;
+
+    /// Bytes that change the structure of a JSON document when swapped in.
+    const MUTATIONS: &[u8] = b"{}[]\",:0-a\\ \x00\xff";
+
+    /// xorshift64, enough to spread the inputs without extra dependencies.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn bytes(&mut self, len: usize) -> Vec<u8> {
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+    }
+
+    /// Runs `body` through the whole transaction mapping; only panics matter here.
+    fn decode(body: &[u8]) {
+        if let Ok(dto) = map_transaction_dto(Bytes::from(body.to_vec())) {
+            if let Ok(dto) = serde_json::from_str::<Box<dyn TransactionDto>>(&dto) {
+                let _ = dto.compact();
+            }
+        }
+    }
+
+    fn decode_vec(body: &[u8]) {
+        if let Ok(dto) = map_transaction_dto_vec(Bytes::from(body.to_vec())) {
+            if let Ok(dtos) = serde_json::from_str::<Vec<Box<dyn TransactionDto>>>(&dto) {
+                for dto in dtos {
+                    let _ = dto.compact();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_shapes_are_errors() {
+        for body in &[
+            "",
+            "null",
+            "{}",
+            "[]",
+            "[1,2]",
+            r#"{"transaction":{"type":"1"}}"#,
+        ] {
+            assert!(map_transaction_dto(Bytes::from(*body)).is_err(), "{}", body);
+        }
+
+        for body in &["", "null", "{}", "1", r#"{"transaction":[]}"#] {
+            assert!(
+                map_transaction_dto_vec(Bytes::from(*body)).is_err(),
+                "{}",
+                body
+            );
+        }
+    }
+
+    #[test]
+    fn samples_are_mapped() {
+        assert!(map_transaction_dto(Bytes::from(TRANSFER)).is_ok());
+        assert!(map_transaction_dto(Bytes::from(AGGREGATE)).is_ok());
+        assert!(
+            map_transaction_dto_vec(Bytes::from(format!("[{},{}]", TRANSFER, AGGREGATE))).is_ok()
+        );
+    }
+
+    #[test]
+    fn arbitrary_bytes_do_not_panic() {
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+        for len in 0..512 {
+            let body = rng.bytes(len % 256);
+            decode(&body);
+            decode_vec(&body);
+        }
+    }
+
+    #[test]
+    fn truncated_samples_do_not_panic() {
+        for sample in &[TRANSFER, AGGREGATE] {
+            for end in 0..sample.len() {
+                decode(&sample.as_bytes()[..end]);
+            }
+        }
+    }
+
+    #[test]
+    fn mutated_samples_do_not_panic() {
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+        for sample in &[TRANSFER, AGGREGATE] {
+            for position in 0..sample.len() {
+                let mut body = sample.as_bytes().to_vec();
+                body[position] = MUTATIONS[rng.next() as usize % MUTATIONS.len()];
+                decode(&body);
+
+                let list = format!("[{}]", String::from_utf8_lossy(&body));
+                decode_vec(list.as_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn swapped_values_do_not_panic() {
+        let values = [
+            "null",
+            "0",
+            "-1",
+            "4294967296",
+            "18446744073709551615",
+            "\"\"",
+            "\"ZZ\"",
+            "[]",
+            "[4294967295,4294967295]",
+            "{}",
+        ];
+
+        for sample in &[TRANSFER, AGGREGATE] {
+            let mut value: serde_json::Value = serde_json::from_str(sample).unwrap();
+            let keys: Vec<String> = value["transaction"]
+                .as_object()
+                .map(|object| object.keys().cloned().collect())
+                .unwrap_or_default();
+
+            for key in keys {
+                for replacement in values.iter() {
+                    value["transaction"][&key] = serde_json::from_str(replacement).unwrap();
+                    decode(value.to_string().as_bytes());
+                }
+                value = serde_json::from_str(sample).unwrap();
+            }
+        }
+    }
+}
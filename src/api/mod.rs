@@ -4,10 +4,14 @@
  * license that can be found in the LICENSE file.
  */
 
+pub use self::cache::*;
+pub use self::cosigner::*;
 pub(crate) use self::dtos::*;
 pub(crate) use self::internally::*;
 pub use self::sirius_client::*;
 
+mod cache;
+mod cosigner;
 mod dtos;
 mod internally;
 mod request;
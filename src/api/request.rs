@@ -6,6 +6,7 @@
 
 use {
     ::std::{collections::HashMap, sync::Arc},
+    bytes::Bytes,
     reqwest::{
         header::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
         Method, StatusCode, Url,
@@ -82,22 +83,21 @@ impl Request {
         let mut raw_headers = HashMap::new();
         let headers: HeaderMap = HeaderMap::new();
 
-        let mut path = self.path;
+        let mut path = self.path.clone();
 
-        self.path_params.into_iter().for_each(|(key, val)| {
+        self.path_params.iter().for_each(|(key, val)| {
             // replace {id} with the value of the id path param
-            path = path.replace(&format!("{{{}}}", key), &val);
+            path = path.replace(&format!("{{{}}}", key), val);
         });
 
-        self.header_params.into_iter().for_each(|(key, val)| {
-            raw_headers.insert(key, val);
+        self.header_params.iter().for_each(|(key, val)| {
+            raw_headers.insert(key.to_owned(), val.to_owned());
         });
 
         let uri_str = format!("{}{}", api.base_path, path);
 
         let mut url = Url::parse(&uri_str)
-            .map_err(|e| format!("could not parse url: {:?}", e))
-            .unwrap();
+            .map_err(|e| Error::Url(format!("could not parse url: {:?}", e).into()))?;
 
         if !self.query_params.is_empty() {
             let existing: Vec<(String, String)> = url
@@ -124,8 +124,24 @@ impl Request {
                 .extend_pairs(pairs.iter().map(|&(k, v)| (&k[..], &v[..])));
         };
 
+        let cached = api.cache.as_ref().and_then(|cache| {
+            cache.key(
+                &self.method,
+                &self.path,
+                &self.path_params,
+                url.as_str(),
+                self.serialized_body.as_deref(),
+            )
+        });
+
+        if let (Some(cache), Some(key)) = (&api.cache, &cached) {
+            if let Some(body) = cache.get(key) {
+                return self.__decode(body);
+            }
+        }
+
         // create request
-        let builder = api.client.request(self.method, url.as_str()).body(
+        let builder = api.client.request(self.method.clone(), url.as_str()).body(
             self.serialized_body
                 .clone()
                 .unwrap_or_else(|| "".to_owned()),
@@ -133,12 +149,12 @@ impl Request {
 
         let mut req = builder.build()?;
 
-        if let Some(body) = self.serialized_body {
+        if let Some(ref body) = self.serialized_body {
             req.headers_mut().insert(
                 CONTENT_TYPE,
                 "application/json"
                     .parse()
-                    .map_err(|err| Error::from(format_err!("{}", err)))?,
+                    .map_err(|err| Error::decode(err.to_string()))?,
             );
 
             req.headers_mut().insert(CONTENT_LENGTH, body.len().into());
@@ -151,7 +167,7 @@ impl Request {
                     USER_AGENT,
                     user_agent
                         .parse()
-                        .map_err(|err| Error::from(format_err!("{}", err)))?,
+                        .map_err(|err| Error::validation(Some("user_agent"), err.to_string()))?,
                 );
             }
 
@@ -165,18 +181,14 @@ impl Request {
 
             match status {
                 StatusCode::OK | StatusCode::ACCEPTED => {
-                    if self.is_transaction {
-                        let map_dto = map_transaction_dto(body)?;
-                        let res: U = serde_json::from_str(&map_dto)?;
-                        Ok(res)
-                    } else if self.is_transaction_vec {
-                        let map_dto_vec = map_transaction_dto_vec(body)?;
-                        let res: U = serde_json::from_str(&map_dto_vec)?;
-                        Ok(res)
-                    } else {
-                        let res: U = serde_json::from_slice(&body)?;
-                        Ok(res)
+                    let res = self.__decode(body.clone())?;
+
+                    // only store responses that decoded, a bad one would be served again.
+                    if let (Some(cache), Some(key)) = (&api.cache, cached) {
+                        cache.insert(key, body);
                     }
+
+                    Ok(res)
                 }
                 _ => {
                     let err: SiriusError = serde_json::from_slice(&body)?;
@@ -185,4 +197,22 @@ impl Request {
             }
         }
     }
+
+    fn __decode<U>(&self, body: Bytes) -> crate::models::Result<U>
+    where
+        for<'de> U: serde::Deserialize<'de>,
+    {
+        if self.is_transaction {
+            let map_dto = map_transaction_dto(body)?;
+            let res: U = serde_json::from_str(&map_dto)?;
+            Ok(res)
+        } else if self.is_transaction_vec {
+            let map_dto_vec = map_transaction_dto_vec(body)?;
+            let res: U = serde_json::from_str(&map_dto_vec)?;
+            Ok(res)
+        } else {
+            let res: U = serde_json::from_slice(&body)?;
+            Ok(res)
+        }
+    }
 }
@@ -5,7 +5,12 @@
  */
 
 use {
-    ::std::{collections::HashMap, future::Future, sync::Arc},
+    ::std::{
+        collections::{HashMap, VecDeque},
+        future::Future,
+        sync::Arc,
+    },
+    futures_util::stream::{self, Stream},
     reqwest::Method,
 };
 
@@ -14,19 +19,23 @@ use crate::{
     api::{
         internally::{str_to_account_id, valid_vec_len, AccountTransactionsOption},
         request as __internal_request, AccountInfoDto, AccountNamesDto, AccountPropertiesInfoDto,
-        ApiClient, MultisigAccountGraphInfoDto, MultisigAccountInfoDto, TransactionDto,
+        ApiClient, BlockInfoDto, MultisigAccountGraphInfoDto, MultisigAccountInfoDto,
+        TransactionDto,
     },
     errors_const::ERR_EMPTY_ADDRESSES_IDS,
     models::Result,
     multisig::{MultisigAccountGraphInfo, MultisigAccountInfo},
-    transaction::Transactions,
+    transaction::{
+        AbsTransaction, AccountTransactionsQuery, Timestamp, Transaction, TransactionDirection,
+        Transactions, TransactionsPagination,
+    },
 };
 
 use super::{
     ACCOUNTS_PROPERTIES_ROUTE, ACCOUNTS_ROUTE, ACCOUNT_NAMES_ROUTE, ACCOUNT_PROPERTIES_ROUTE,
-    ACCOUNT_ROUTE, AGGREGATE_TRANSACTIONS_ROUTE, INCOMING_TRANSACTIONS_ROUTE,
-    MULTISIG_ACCOUNT_GRAPH_INFO_ROUTE, MULTISIG_ACCOUNT_ROUTE, OUTGOING_TRANSACTIONS_ROUTE,
-    TRANSACTIONS_BY_ACCOUNT_ROUTE, UNCONFIRMED_TRANSACTIONS_ROUTE,
+    ACCOUNT_ROUTE, AGGREGATE_TRANSACTIONS_ROUTE, BLOCK_BY_HEIGHT_ROUTE,
+    INCOMING_TRANSACTIONS_ROUTE, MULTISIG_ACCOUNT_GRAPH_INFO_ROUTE, MULTISIG_ACCOUNT_ROUTE,
+    OUTGOING_TRANSACTIONS_ROUTE, TRANSACTIONS_BY_ACCOUNT_ROUTE, UNCONFIRMED_TRANSACTIONS_ROUTE,
 };
 
 /// Account ApiClient routes.
@@ -321,6 +330,188 @@ impl AccountRoutes {
         .await
     }
 
+    /// Walks every page of the [Transaction]s for which the account is the sender or receiver.
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account` =    The public account.
+    ///
+    /// * `pagination` =    The page size, [TransactionOrdering] and optional [TransactionsStop].
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///
+    ///use futures_util::{pin_mut, StreamExt};
+    ///use xpx_chain_sdk::account::PublicAccount;
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///use xpx_chain_sdk::network::PUBLIC_TEST;
+    ///use xpx_chain_sdk::transaction::{TransactionOrdering, TransactionsPagination, TransactionsStop};
+    ///
+    ///const PUBLIC_KEY: &str = "93C3B9075649F59BD88573ADC55B8915B12390A47C76F0C45F362ED0800BE237";
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let public_account = PublicAccount::from_public_key(PUBLIC_KEY, PUBLIC_TEST).unwrap();
+    ///
+    ///    let pagination = TransactionsPagination::new(Some(100), TransactionOrdering::Desc)
+    ///        .with_stop(TransactionsStop::Count(250));
+    ///
+    ///    let transactions = client.account_api().transactions_stream(&public_account, pagination);
+    ///    pin_mut!(transactions);
+    ///
+    ///    while let Some(tx) = transactions.next().await {
+    ///        match tx {
+    ///            Ok(tx) => println!("{}", tx),
+    ///            Err(err) => eprintln!("{:?}", err),
+    ///        }
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result` whose okay value is a [Transaction] or whose error value
+    /// is an `Error` describing the error that occurred. The stream ends after the first error.
+    pub fn transactions_stream(
+        self,
+        public_account: &PublicAccount,
+        pagination: TransactionsPagination,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__stream_transactions(public_account, TRANSACTIONS_BY_ACCOUNT_ROUTE, pagination)
+    }
+
+    /// Same as [AccountRoutes::transactions_stream] for the incoming transactions.
+    pub fn incoming_transactions_stream(
+        self,
+        public_account: &PublicAccount,
+        pagination: TransactionsPagination,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__stream_transactions(public_account, INCOMING_TRANSACTIONS_ROUTE, pagination)
+    }
+
+    /// Same as [AccountRoutes::transactions_stream] for the outgoing transactions.
+    pub fn outgoing_transactions_stream(
+        self,
+        public_account: &PublicAccount,
+        pagination: TransactionsPagination,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__stream_transactions(public_account, OUTGOING_TRANSACTIONS_ROUTE, pagination)
+    }
+
+    /// Same as [AccountRoutes::transactions_stream] for the unconfirmed transactions.
+    pub fn unconfirmed_transactions_stream(
+        self,
+        public_account: &PublicAccount,
+        pagination: TransactionsPagination,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__stream_transactions(public_account, UNCONFIRMED_TRANSACTIONS_ROUTE, pagination)
+    }
+
+    /// Same as [AccountRoutes::transactions_stream] for the aggregate bonded transactions
+    /// waiting for cosignatures.
+    pub fn partial_transactions_stream(
+        self,
+        public_account: &PublicAccount,
+        pagination: TransactionsPagination,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        self.__stream_transactions(public_account, AGGREGATE_TRANSACTIONS_ROUTE, pagination)
+    }
+
+    /// Walks the confirmed [Transaction]s of an account that match `query`.
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account` =    The public account.
+    ///
+    /// * `query` =    The [AccountTransactionsQuery] with the direction, entity types,
+    /// height and time ranges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///
+    ///use futures_util::{pin_mut, StreamExt};
+    ///use xpx_chain_sdk::account::PublicAccount;
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///use xpx_chain_sdk::network::PUBLIC_TEST;
+    ///use xpx_chain_sdk::transaction::{
+    ///    AccountTransactionsQuery, EntityTypeEnum, TransactionDirection,
+    ///};
+    ///use xpx_chain_sdk::Uint64;
+    ///
+    ///const PUBLIC_KEY: &str = "93C3B9075649F59BD88573ADC55B8915B12390A47C76F0C45F362ED0800BE237";
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let public_account = PublicAccount::from_public_key(PUBLIC_KEY, PUBLIC_TEST).unwrap();
+    ///
+    ///    let query = AccountTransactionsQuery::new()
+    ///        .with_direction(TransactionDirection::Incoming)
+    ///        .with_entity_type(EntityTypeEnum::Transfer)
+    ///        .with_height_range(Some(Uint64::new(1000)), None);
+    ///
+    ///    let transfers = client.account_api().query_transactions(&public_account, query);
+    ///    pin_mut!(transfers);
+    ///
+    ///    while let Some(tx) = transfers.next().await {
+    ///        match tx {
+    ///            Ok(tx) => println!("{}", tx),
+    ///            Err(err) => eprintln!("{:?}", err),
+    ///        }
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Stream` of `Result` whose okay value is a [Transaction] or whose error value
+    /// is an `Error` describing the error that occurred. The stream ends after the first error.
+    pub fn query_transactions(
+        self,
+        public_account: &PublicAccount,
+        query: AccountTransactionsQuery,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        let route = match query.direction {
+            TransactionDirection::All => TRANSACTIONS_BY_ACCOUNT_ROUTE,
+            TransactionDirection::Incoming => INCOMING_TRANSACTIONS_ROUTE,
+            TransactionDirection::Outgoing => OUTGOING_TRANSACTIONS_ROUTE,
+        };
+
+        self.__stream_transactions(public_account, route, query)
+    }
+
+    fn __stream_transactions<Q: Into<AccountTransactionsQuery>>(
+        self,
+        public_account: &PublicAccount,
+        route: &'static str,
+        query: Q,
+    ) -> impl Stream<Item = Result<Box<dyn Transaction>>> {
+        let pager = TransactionsPager {
+            routes: self,
+            public_account: public_account.clone(),
+            route,
+            query: query.into(),
+            cursor: None,
+            buffer: VecDeque::new(),
+            yielded: 0,
+            block_times: HashMap::new(),
+            last_page: false,
+            done: false,
+        };
+
+        stream::unfold(pager, |mut pager| async move {
+            let item = pager.next().await?;
+            Some((item, pager))
+        })
+    }
+
     fn __internal_transactions(
         self,
         public_account: &PublicAccount,
@@ -358,3 +549,331 @@ impl AccountRoutes {
         }
     }
 }
+
+/// Cursor state of an account transactions stream.
+struct TransactionsPager {
+    routes: AccountRoutes,
+    public_account: PublicAccount,
+    route: &'static str,
+    query: AccountTransactionsQuery,
+    cursor: Option<String>,
+    buffer: VecDeque<Box<dyn Transaction>>,
+    yielded: usize,
+    /// Timestamps of the blocks already read for the time range, by height.
+    block_times: HashMap<u64, Timestamp>,
+    last_page: bool,
+    done: bool,
+}
+
+impl TransactionsPager {
+    async fn next(&mut self) -> Option<Result<Box<dyn Transaction>>> {
+        let pagination = self.query.pagination;
+
+        loop {
+            if self.done || pagination.is_past_stop(None, self.yielded) {
+                return None;
+            }
+
+            if self.buffer.is_empty() && !self.last_page {
+                if let Err(err) = self.fetch_page().await {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+
+            let transaction = match self.buffer.pop_front() {
+                Some(tx) => tx,
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            };
+
+            let block_time = match self.block_time(transaction.as_ref()).await {
+                Ok(block_time) => block_time,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+
+            if pagination.is_past_stop(Some(transaction.as_ref()), self.yielded)
+                || self.query.is_past_range(transaction.as_ref(), block_time)
+            {
+                self.done = true;
+                return None;
+            }
+
+            if !self.query.matches(transaction.as_ref())
+                || !block_time.map_or(true, |time| self.query.matches_time(time))
+            {
+                continue;
+            }
+
+            self.yielded += 1;
+            return Some(Ok(transaction));
+        }
+    }
+
+    /// The timestamp of the block of `transaction` when the query has a time range.
+    async fn block_time(&mut self, transaction: &dyn Transaction) -> Result<Option<Timestamp>> {
+        if !self.query.has_time_range() {
+            return Ok(None);
+        }
+
+        let height = transaction.height().as_u64();
+        if let Some(time) = self.block_times.get(&height) {
+            return Ok(Some(*time));
+        }
+
+        let mut req =
+            __internal_request::Request::new(Method::GET, BLOCK_BY_HEIGHT_ROUTE.to_string());
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: BlockInfoDto = req.execute(self.routes.clone().__client()).await?;
+        let time = dto.compact()?.timestamp;
+
+        self.block_times.insert(height, time);
+        Ok(Some(time))
+    }
+
+    async fn fetch_page(&mut self) -> Result<()> {
+        let options = AccountTransactionsOption {
+            page_size: self.query.pagination.page_size,
+            id: self.cursor.take(),
+            ordering: Some(self.query.pagination.ordering.to_string()),
+        };
+
+        let page = self
+            .routes
+            .clone()
+            .__internal_transactions(&self.public_account, self.route, options)
+            .await?;
+
+        self.cursor = page
+            .last()
+            .and_then(|tx| tx.abs_transaction().transaction_info)
+            .map(|info| info.id);
+
+        if page.is_empty() || self.cursor.is_none() {
+            self.last_page = true;
+        }
+
+        self.buffer.extend(page);
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "mock-node"))]
+mod tests {
+    use {::std::time::Duration, futures_util::StreamExt, tokio::time::delay_for};
+
+    use crate::{
+        account::{Account, Address},
+        api::SiriusClient,
+        message::PlainMessage,
+        mock::MockNode,
+        mosaic::Mosaic,
+        transaction::{
+            AccountTransactionsQuery, AggregateTransaction, Deadline, EntityTypeEnum, Transaction,
+            TransactionDirection, TransactionOrdering, TransactionsPagination, TransactionsStop,
+            TransferTransaction,
+        },
+        Result, Uint64,
+    };
+
+    struct Fixture {
+        node: MockNode,
+        client: SiriusClient,
+        sender: Account,
+        recipient: Account,
+    }
+
+    async fn fixture() -> Fixture {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+        let network_type = node.network_type();
+
+        Fixture {
+            node,
+            client,
+            sender: Account::new(network_type),
+            recipient: Account::new(network_type),
+        }
+    }
+
+    impl Fixture {
+        fn transfer(&self, recipient: Address, amount: u64) -> TransferTransaction {
+            TransferTransaction::new(
+                Deadline::default(),
+                recipient,
+                vec![Mosaic::xpx(amount)],
+                PlainMessage::empty(),
+                self.node.network_type(),
+            )
+            .unwrap()
+        }
+
+        async fn announce(&self, signer: &Account, transaction: impl Transaction) -> String {
+            let signed = signer
+                .sign(transaction, &self.node.generation_hash())
+                .unwrap();
+            self.client
+                .transaction_api()
+                .announce(&signed)
+                .await
+                .unwrap();
+            signed.get_hash()
+        }
+
+        /// Harvests one block per transfer of the sender to the recipient, from height 2.
+        async fn transfer_blocks(&self, count: u64) {
+            for amount in 1..=count {
+                let transfer = self.transfer(self.recipient.address(), amount);
+                self.announce(&self.sender, transfer).await;
+                self.node.harvest();
+            }
+        }
+    }
+
+    fn heights(transactions: Vec<Result<Box<dyn Transaction>>>) -> Vec<u64> {
+        transactions
+            .into_iter()
+            .map(|tx| tx.unwrap().height().as_u64())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn streams_walk_every_page_and_end_with_the_last_one() {
+        let fixture = fixture().await;
+
+        let mut hashes = vec![];
+        for amount in 1..=25 {
+            let transfer = fixture.transfer(fixture.recipient.address(), amount);
+            hashes.push(fixture.announce(&fixture.sender, transfer).await);
+        }
+        fixture.node.harvest();
+
+        let pagination = TransactionsPagination::new(Some(10), TransactionOrdering::Asc);
+        let transactions: Vec<_> = fixture
+            .client
+            .account_api()
+            .transactions_stream(&fixture.sender.public_account_to_owned(), pagination)
+            .collect()
+            .await;
+
+        let received: Vec<String> = transactions
+            .into_iter()
+            .map(|tx| tx.unwrap().transaction_hash().to_uppercase())
+            .collect();
+        assert_eq!(received, hashes);
+    }
+
+    #[tokio::test]
+    async fn streams_end_at_the_stop_condition() {
+        let fixture = fixture().await;
+        fixture.transfer_blocks(4).await;
+
+        let account = fixture.sender.public_account_to_owned();
+
+        let by_height = TransactionsPagination::new(Some(10), TransactionOrdering::Desc)
+            .with_stop(TransactionsStop::Height(Uint64::new(4)));
+        let transactions: Vec<_> = fixture
+            .client
+            .account_api()
+            .transactions_stream(&account, by_height)
+            .collect()
+            .await;
+        assert_eq!(heights(transactions), vec![5, 4]);
+
+        let by_count = TransactionsPagination::new(Some(10), TransactionOrdering::Asc)
+            .with_stop(TransactionsStop::Count(2));
+        let transactions: Vec<_> = fixture
+            .client
+            .account_api()
+            .transactions_stream(&account, by_count)
+            .collect()
+            .await;
+        assert_eq!(heights(transactions), vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn queries_filter_by_direction_and_entity_type() {
+        let fixture = fixture().await;
+
+        let sent = fixture.transfer(fixture.recipient.address(), 1);
+        let sent = fixture.announce(&fixture.sender, sent).await;
+
+        let received = fixture.transfer(fixture.sender.address(), 2);
+        fixture.announce(&fixture.recipient, received).await;
+
+        let mut inner = fixture.transfer(fixture.recipient.address(), 3);
+        inner.set_aggregate(fixture.sender.public_account_to_owned());
+        let aggregate = AggregateTransaction::new_complete(
+            Deadline::default(),
+            vec![Box::new(inner)],
+            fixture.node.network_type(),
+        )
+        .unwrap();
+        let aggregate = fixture.announce(&fixture.sender, aggregate).await;
+
+        fixture.node.harvest();
+
+        let account = fixture.sender.public_account_to_owned();
+        let hashes = |query: AccountTransactionsQuery| {
+            fixture
+                .client
+                .account_api()
+                .query_transactions(&account, query)
+                .map(|tx| tx.unwrap().transaction_hash().to_uppercase())
+                .collect::<Vec<_>>()
+        };
+
+        let outgoing_transfers = AccountTransactionsQuery::new()
+            .with_direction(TransactionDirection::Outgoing)
+            .with_entity_type(EntityTypeEnum::Transfer);
+        assert_eq!(hashes(outgoing_transfers).await, vec![sent]);
+
+        let aggregates =
+            AccountTransactionsQuery::new().with_entity_type(EntityTypeEnum::AggregateComplete);
+        assert_eq!(hashes(aggregates).await, vec![aggregate]);
+    }
+
+    #[tokio::test]
+    async fn queries_keep_the_blocks_of_the_time_range() {
+        let fixture = fixture().await;
+
+        // one block per transfer, far enough apart to get distinct timestamps.
+        for amount in 1..=4 {
+            let transfer = fixture.transfer(fixture.recipient.address(), amount);
+            fixture.announce(&fixture.sender, transfer).await;
+            fixture.node.harvest();
+            delay_for(Duration::from_millis(20)).await;
+        }
+
+        let client = &fixture.client;
+        let block_time = |height: u64| async move {
+            client
+                .block_api()
+                .get_block_by_height(height)
+                .await
+                .unwrap()
+                .timestamp
+        };
+        let from = block_time(3).await;
+        let to = block_time(4).await;
+
+        let query = AccountTransactionsQuery::new()
+            .with_time_range(Some(from), Some(to))
+            .with_page_size(10);
+        let transactions: Vec<_> = fixture
+            .client
+            .account_api()
+            .query_transactions(&fixture.sender.public_account_to_owned(), query)
+            .collect()
+            .await;
+
+        assert_eq!(heights(transactions), vec![4, 3]);
+    }
+}
@@ -6,32 +6,44 @@ use {::std::sync::Arc, reqwest::Method};
 
 use crate::{
     api::{
-        dtos::{BlockInfoDto, TransactionDto},
+        dtos::{BlockInfoDto, StatementsDto, TransactionDto},
+        internally::str_to_hash,
         request as __internal_request, ApiClient,
     },
     blockchain::BlockInfo,
+    errors_const::{ERR_ZERO_HEIGHT, ERR_ZERO_LIMIT},
     models::Result,
-    transaction::Transactions,
+    network::NetworkType,
+    receipt::Statements,
+    transaction::{Transaction, Transactions},
+    MerkleProofInfo,
 };
 
-use super::{BLOCK_BY_HEIGHT_ROUTE, BLOCK_GET_TRANSACTION_ROUTE, BLOCK_INFO_ROUTE};
+use super::{
+    BLOCK_BY_HEIGHT_ROUTE, BLOCK_GET_RECEIPTS_ROUTE, BLOCK_GET_TRANSACTION_ROUTE, BLOCK_INFO_ROUTE,
+    BLOCK_RECEIPT_MERKLE_ROUTE, BLOCK_TRANSACTION_MERKLE_ROUTE, TRANSACTION_ROUTE,
+};
 
 /// Block ApiClient routes.
 ///
 #[derive(Clone)]
-pub struct BlockRoutes(Arc<ApiClient>);
+pub struct BlockRoutes(Arc<ApiClient>, NetworkType);
 
 ///  Block related endpoints.
 ///
 impl BlockRoutes {
-    pub(crate) fn new(client: Arc<ApiClient>) -> Self {
-        BlockRoutes(client)
+    pub(crate) fn new(client: Arc<ApiClient>, network_type: NetworkType) -> Self {
+        BlockRoutes(client, network_type)
     }
 
     fn __client(self) -> Arc<ApiClient> {
         self.0
     }
 
+    fn __network_type(&self) -> NetworkType {
+        self.1
+    }
+
     /// Get [BlockInfo] information
     ///
     /// Gets a block from the chain that has the given height.
@@ -64,7 +76,7 @@ impl BlockRoutes {
     /// Returns a Future `Result` whose okay value is an [BlockInfo] the block information or
     /// whose error value is an `Error<Value>` describing the error that occurred.
     pub async fn get_block_by_height(self, height: u64) -> Result<BlockInfo> {
-        assert_ne!(height, 0, "Block height should not be zero.");
+        ensure!(height != 0, "height" => ERR_ZERO_HEIGHT);
 
         let mut req =
             __internal_request::Request::new(Method::GET, BLOCK_BY_HEIGHT_ROUTE.to_string());
@@ -119,9 +131,9 @@ impl BlockRoutes {
         height: u64,
         limit: i32,
     ) -> Result<Vec<BlockInfo>> {
-        assert_ne!(height, 0, "Block height should not be zero.");
+        ensure!(height != 0, "height" => ERR_ZERO_HEIGHT);
 
-        assert_ne!(limit, 0, "Limit should not be zero.");
+        ensure!(limit != 0, "limit" => ERR_ZERO_LIMIT);
 
         let limit = if limit < 25 {
             25
@@ -217,4 +229,181 @@ impl BlockRoutes {
 
         Ok(transactions_info)
     }
+
+    /// Get `Statements` of a block
+    ///
+    /// Gets the receipts triggered for the block with the given height: balance transfers and
+    /// changes (fees, harvest rewards, locks), artifact expiries, inflation and the alias
+    /// resolutions.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the block.
+    ///
+    /// # Example
+    /// ```
+    ///
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let block_receipts = client.block_api().get_block_receipts(1).await;
+    ///
+    ///    match block_receipts {
+    ///        Ok(statements) => println!("{}", statements),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [Statements] of the block or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_block_receipts(self, height: u64) -> Result<Statements> {
+        ensure!(height != 0, "height" => ERR_ZERO_HEIGHT);
+
+        let network_type = self.__network_type();
+
+        let mut req =
+            __internal_request::Request::new(Method::GET, BLOCK_GET_RECEIPTS_ROUTE.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: StatementsDto = req.execute(self.__client()).await?;
+
+        Ok(dto.compact(network_type)?)
+    }
+
+    /// Get the merkle path of a transaction
+    ///
+    /// Returns the merkle path for a transaction included in a block. The path is the
+    /// complementary data needed to calculate the merkle root of the block transactions.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the block.
+    ///
+    /// * `hash` =    The hash of the transaction.
+    ///
+    /// # Example
+    /// ```
+    ///
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///const HASH: &str = "130171141CAE9D9ED6F62FD47CC316631986BBACD6B3D63930A9C46ED1ED764F";
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let merkle_path = client.block_api().get_transaction_merkle_path(2, HASH).await;
+    ///
+    ///    match merkle_path {
+    ///        Ok(path) => println!("{}", path),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [MerkleProofInfo] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_transaction_merkle_path(
+        self,
+        height: u64,
+        hash: &str,
+    ) -> Result<MerkleProofInfo> {
+        self.__merkle_path(BLOCK_TRANSACTION_MERKLE_ROUTE, height, hash)
+            .await
+    }
+
+    /// Get the merkle path of a receipt statement
+    ///
+    /// Same as [BlockRoutes::get_transaction_merkle_path] for the hash of a receipt statement,
+    /// checked against the block receipts hash.
+    pub async fn get_receipt_merkle_path(self, height: u64, hash: &str) -> Result<MerkleProofInfo> {
+        self.__merkle_path(BLOCK_RECEIPT_MERKLE_ROUTE, height, hash)
+            .await
+    }
+
+    /// Verify a transaction is included in a block
+    ///
+    /// Fetches the transaction, the block and the merkle path of the transaction, recomputes
+    /// the merkle root from the `merkle_component_hash` of the transaction and compares it with
+    /// the `block_transactions_hash` of the block.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the block.
+    ///
+    /// * `hash` =    The hash of the transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is `true` if the transaction is in the block or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn verify_transaction_in_block(self, height: u64, hash: &str) -> Result<bool> {
+        let transaction = self.clone().__transaction(hash).await?;
+
+        let info = match transaction.abs_transaction().transaction_info {
+            Some(info) if info.height.as_u64() == height => info,
+            _ => return Ok(false),
+        };
+
+        // Cosignatures are not part of the transaction hash, so the leaf of a cosigned
+        // aggregate is its merkle component hash.
+        let leaf = match info.merkle_component_hash {
+            Some(leaf) => leaf,
+            None => info.transaction_hash(),
+        };
+
+        let block_info = self.clone().get_block_by_height(height).await?;
+
+        let merkle_path = self.get_transaction_merkle_path(height, hash).await?;
+
+        Ok(merkle_path.verify(&leaf, &block_info.block_transactions_hash)?)
+    }
+
+    /// Verify a receipt statement is included in a block
+    ///
+    /// Same as [BlockRoutes::verify_transaction_in_block] against the `block_receipts_hash`.
+    pub async fn verify_receipt_in_block(self, height: u64, hash: &str) -> Result<bool> {
+        let block_info = self.clone().get_block_by_height(height).await?;
+
+        let merkle_path = self.get_receipt_merkle_path(height, hash).await?;
+
+        Ok(merkle_path.verify(hash, &block_info.block_receipts_hash)?)
+    }
+
+    async fn __transaction(self, hash: &str) -> Result<Box<dyn Transaction>> {
+        let mut req = __internal_request::Request::new(Method::GET, TRANSACTION_ROUTE.to_string());
+
+        req = req
+            .with_path_param("transactionId".to_string(), str_to_hash(hash)?)
+            .set_transaction();
+
+        let transaction: Box<dyn TransactionDto> = req.execute(self.__client()).await?;
+
+        transaction.compact()
+    }
+
+    async fn __merkle_path(self, route: &str, height: u64, hash: &str) -> Result<MerkleProofInfo> {
+        ensure!(height != 0, "height" => ERR_ZERO_HEIGHT);
+
+        let hash = str_to_hash(hash)?;
+
+        let mut req = __internal_request::Request::new(Method::GET, route.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        req = req.with_path_param("hash".to_string(), hash);
+
+        req.execute(self.__client()).await
+    }
 }
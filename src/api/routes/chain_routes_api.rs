@@ -60,9 +60,15 @@ impl ChainRoutes {
     /// whose error value is an `Error<Value>` describing the error that occurred.
     pub async fn get_blockchain_height(self) -> Result<HeightInfo> {
         let req = __internal_request::Request::new(Method::GET, CHAIN_HEIGHT_ROUTE.to_string());
-        let dto: Result<HeightInfoDto> = req.execute(self.__client()).await;
+        let client = self.__client();
+        let dto: Result<HeightInfoDto> = req.execute(client.clone()).await;
 
-        Ok(dto?.compact())
+        let height_info = dto?.compact();
+        if let Some(cache) = &client.cache {
+            cache.set_chain_height(height_info.height.as_u64());
+        }
+
+        Ok(height_info)
     }
 
     /// Get the current score of the chain.
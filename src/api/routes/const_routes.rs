@@ -19,6 +19,9 @@ pub const AGGREGATE_TRANSACTIONS_ROUTE: &str = "/account/{publicKey}/transaction
 /// Block routes.
 pub const BLOCK_BY_HEIGHT_ROUTE: &str = "/block/{height}";
 pub const BLOCK_GET_TRANSACTION_ROUTE: &str = "/block/{height}/transactions";
+pub const BLOCK_GET_RECEIPTS_ROUTE: &str = "/block/{height}/receipts";
+pub const BLOCK_TRANSACTION_MERKLE_ROUTE: &str = "/block/{height}/transaction/{hash}/merkle";
+pub const BLOCK_RECEIPT_MERKLE_ROUTE: &str = "/block/{height}/receipt/{hash}/merkle";
 pub const BLOCK_INFO_ROUTE: &str = "/blocks/{height}/limit/{limit}";
 
 /// Chain routes.
@@ -30,6 +33,12 @@ pub const CHAIN_HEIGHT_ROUTE: &str = "/chain/height";
 pub const EXCHANGE_ROUTE: &str = "/account/{account_id}/exchange";
 pub const OFFERS_BY_MOSAIC_ROUTE: &str = "/exchange/{offer_type}/{mosaic_id}";
 
+/// Lock routes.
+pub const HASH_LOCKS_BY_ACCOUNT_ROUTE: &str = "/account/{publicKey}/lock/hash";
+pub const SECRET_LOCKS_BY_ACCOUNT_ROUTE: &str = "/account/{publicKey}/lock/secret";
+pub const HASH_LOCK_ROUTE: &str = "/lock/hash/{hash}";
+pub const SECRET_LOCKS_BY_SECRET_ROUTE: &str = "/lock/secret/{secret}";
+
 /// Mosaic routes.
 pub const MOSAICS_ROUTE: &str = "/mosaic";
 pub const MOSAIC_ROUTE: &str = "/mosaic/{mosaic_id}";
@@ -44,6 +53,13 @@ pub const NAMESPACES_FROM_ACCOUNT_ROUTES: &str = "/account/{accountId}/namespace
 /// Node routes.
 pub const NODE_INFO: &str = "/node/info";
 pub const NODE_TIME: &str = "/node/time";
+pub const NODE_PEERS: &str = "/node/peers";
+pub const SERVER_INFO: &str = "/diagnostic/server";
+
+/// Network routes.
+pub const NETWORK_ROUTE: &str = "/network";
+pub const NETWORK_CONFIG_ROUTE: &str = "/config/{height}";
+pub const NETWORK_UPGRADE_ROUTE: &str = "/upgrade/{height}";
 
 /// Transaction routes.
 pub const TRANSACTIONS_ROUTE: &str = "/transaction";
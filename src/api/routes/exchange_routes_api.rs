@@ -9,7 +9,9 @@ use {::std::sync::Arc, reqwest::Method};
 use crate::api::OfferInfoDTOs;
 use crate::{
     account::PublicAccount,
-    api::{request as __internal_request, sirius_client::ApiClient, ExchangeInfoDto},
+    api::{
+        request as __internal_request, required_field, sirius_client::ApiClient, ExchangeInfoDto,
+    },
     exchange::{OfferInfos, OfferType, UserExchangeInfo},
     models::Result,
     network::NetworkType,
@@ -88,7 +90,7 @@ impl ExchangeRoutes {
         let mut offer_infos: OfferInfos = vec![];
         for offer_info_dto in &dto {
             let owner_account = PublicAccount::from_public_key(
-                &offer_info_dto.owner.as_ref().unwrap(),
+                required_field(offer_info_dto.owner.as_ref(), "owner")?,
                 network_type,
             )?;
 
@@ -0,0 +1,216 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {::std::sync::Arc, reqwest::Method};
+
+use crate::{
+    account::PublicAccount,
+    api::{
+        internally::str_to_hash, request as __internal_request, sirius_client::ApiClient,
+        HashLockInfoDto, SecretLockInfoDto,
+    },
+    lock::{HashLockInfo, SecretLockInfo},
+    models::Result,
+    network::NetworkType,
+};
+
+use super::{
+    HASH_LOCKS_BY_ACCOUNT_ROUTE, HASH_LOCK_ROUTE, SECRET_LOCKS_BY_ACCOUNT_ROUTE,
+    SECRET_LOCKS_BY_SECRET_ROUTE,
+};
+
+/// Lock ApiClient routes.
+///
+#[derive(Clone)]
+pub struct LockRoutes(Arc<ApiClient>, NetworkType);
+
+/// Hash lock and secret lock related endpoints.
+///
+impl LockRoutes {
+    pub(crate) fn new(client: Arc<ApiClient>, network_type: NetworkType) -> Self {
+        LockRoutes(client, network_type)
+    }
+
+    fn __client(self) -> Arc<ApiClient> {
+        self.0
+    }
+
+    fn __network_type(&self) -> NetworkType {
+        self.1
+    }
+
+    /// Get the hash locks created by an account.
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account` =    The account that locked the funds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///use xpx_chain_sdk::account::PublicAccount;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let public_account = PublicAccount::from_public_key(
+    ///        "7681ED5023141D9CDCF184E5A7B60B7D466739918ED5DA30F7E71EA7B86EFF2D",
+    ///        client.network_type(),
+    ///    ).unwrap();
+    ///
+    ///    let locks = client.lock_api().get_hash_locks(&public_account).await;
+    ///
+    ///    match locks {
+    ///        Ok(resp_info) => {
+    ///            for lock in resp_info {
+    ///                println!("{}", lock)
+    ///            }
+    ///        }
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a `Vec<`[HashLockInfo]`>` or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_hash_locks(self, public_account: &PublicAccount) -> Result<Vec<HashLockInfo>> {
+        let mut req =
+            __internal_request::Request::new(Method::GET, HASH_LOCKS_BY_ACCOUNT_ROUTE.to_string());
+
+        req = req.with_path_param(
+            "publicKey".to_string(),
+            public_account.public_key.to_string(),
+        );
+
+        let network_type = self.__network_type();
+
+        let dto: Vec<HashLockInfoDto> = req.execute(self.__client()).await?;
+
+        let mut locks: Vec<HashLockInfo> = vec![];
+        for lock_dto in dto.into_iter() {
+            locks.push(lock_dto.compact(network_type)?);
+        }
+
+        Ok(locks)
+    }
+
+    /// Get the secret locks created by an account.
+    ///
+    /// # Inputs
+    ///
+    /// * `public_account` =    The account that locked the funds.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a `Vec<`[SecretLockInfo]`>` or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_secret_locks(
+        self,
+        public_account: &PublicAccount,
+    ) -> Result<Vec<SecretLockInfo>> {
+        let mut req = __internal_request::Request::new(
+            Method::GET,
+            SECRET_LOCKS_BY_ACCOUNT_ROUTE.to_string(),
+        );
+
+        req = req.with_path_param(
+            "publicKey".to_string(),
+            public_account.public_key.to_string(),
+        );
+
+        let network_type = self.__network_type();
+
+        let dto: Vec<SecretLockInfoDto> = req.execute(self.__client()).await?;
+
+        let mut locks: Vec<SecretLockInfo> = vec![];
+        for lock_dto in dto.into_iter() {
+            locks.push(lock_dto.compact(network_type)?);
+        }
+
+        Ok(locks)
+    }
+
+    /// Get the hash lock of an aggregate bonded transaction.
+    ///
+    /// # Inputs
+    ///
+    /// * `hash` =    The hash of the aggregate bonded transaction the funds are locked for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///const HASH: &str = "130171141CAE9D9ED4E4983B3E4A4E8A2CA1F0BBB90B5F17ACDB44D4C8C8D3BE";
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let lock = client.lock_api().get_hash_lock(HASH).await;
+    ///
+    ///    match lock {
+    ///        Ok(resp_info) => println!("{}", resp_info),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is an [HashLockInfo] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_hash_lock(self, hash: &str) -> Result<HashLockInfo> {
+        let hash = str_to_hash(hash)?;
+
+        let mut req = __internal_request::Request::new(Method::GET, HASH_LOCK_ROUTE.to_string());
+
+        req = req.with_path_param("hash".to_string(), hash);
+
+        let network_type = self.__network_type();
+
+        let dto: Result<HashLockInfoDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact(network_type)?)
+    }
+
+    /// Get the secret locks created with a secret.
+    /// Several recipients can be locked with the same secret.
+    ///
+    /// # Inputs
+    ///
+    /// * `secret` =    The proof hashed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a `Vec<`[SecretLockInfo]`>` or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_secret_locks_by_secret(self, secret: &str) -> Result<Vec<SecretLockInfo>> {
+        let secret = str_to_hash(secret)?;
+
+        let mut req =
+            __internal_request::Request::new(Method::GET, SECRET_LOCKS_BY_SECRET_ROUTE.to_string());
+
+        req = req.with_path_param("secret".to_string(), secret);
+
+        let network_type = self.__network_type();
+
+        let dto: Vec<SecretLockInfoDto> = req.execute(self.__client()).await?;
+
+        let mut locks: Vec<SecretLockInfo> = vec![];
+        for lock_dto in dto.into_iter() {
+            locks.push(lock_dto.compact(network_type)?);
+        }
+
+        Ok(locks)
+    }
+}
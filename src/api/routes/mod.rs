@@ -11,8 +11,10 @@ pub(crate) mod block_routes_api;
 pub(crate) mod chain_routes_api;
 pub(crate) mod const_routes;
 pub(crate) mod exchange_routes_api;
+pub(crate) mod lock_routes_api;
 pub(crate) mod mosaic_routes_api;
 pub(crate) mod namespace_routes_api;
+pub(crate) mod network_routes_api;
 pub(crate) mod node_routes_api;
 pub(crate) mod resolver_routes_api;
 pub(crate) mod transaction_routes_api;
@@ -0,0 +1,153 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {::std::sync::Arc, reqwest::Method};
+
+use crate::{
+    api::{
+        request as __internal_request, sirius_client::ApiClient, BlockchainUpgradeDto,
+        NetworkConfigDto, NetworkTypeDto,
+    },
+    errors_const::ERR_ZERO_HEIGHT,
+    models::Result,
+    network::{BlockchainUpgrade, NetworkConfigInfo, NetworkInfo},
+};
+
+use super::{NETWORK_CONFIG_ROUTE, NETWORK_ROUTE, NETWORK_UPGRADE_ROUTE};
+
+/// Network ApiClient routes.
+///
+#[derive(Clone)]
+pub struct NetworkRoutes(Arc<ApiClient>);
+
+/// Network related endpoints.
+///
+impl NetworkRoutes {
+    pub(crate) fn new(client: Arc<ApiClient>) -> Self {
+        NetworkRoutes(client)
+    }
+
+    fn __client(self) -> Arc<ApiClient> {
+        self.0
+    }
+
+    /// Get the current network type of the chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let network_info = client.network_api().get_network_info().await;
+    ///
+    ///    match network_info {
+    ///        Ok(resp_info) => println!("{}", resp_info),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a [NetworkInfo] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_network_info(self) -> Result<NetworkInfo> {
+        let req = __internal_request::Request::new(Method::GET, NETWORK_ROUTE.to_string());
+
+        let dto: Result<NetworkTypeDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact())
+    }
+
+    /// Get the network configuration at a given height.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the blockchain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let network_config = client.network_api().get_network_config(1).await;
+    ///
+    ///    match network_config {
+    ///        Ok(resp_info) => println!("{}", resp_info),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [NetworkConfigInfo] in force at `height` or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_network_config(self, height: u64) -> Result<NetworkConfigInfo> {
+        ensure!(height != 0, "height" => ERR_ZERO_HEIGHT);
+
+        let mut req =
+            __internal_request::Request::new(Method::GET, NETWORK_CONFIG_ROUTE.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: Result<NetworkConfigDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact()?)
+    }
+
+    /// Get the blockchain version upgrade at a given height.
+    ///
+    /// # Inputs
+    ///
+    /// * `height` =    The height of the blockchain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let upgrade = client.network_api().get_blockchain_upgrade(1).await;
+    ///
+    ///    match upgrade {
+    ///        Ok(resp_info) => println!("{}", resp_info),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [BlockchainUpgrade] in force at `height` or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_blockchain_upgrade(self, height: u64) -> Result<BlockchainUpgrade> {
+        ensure!(height != 0, "height" => ERR_ZERO_HEIGHT);
+
+        let mut req =
+            __internal_request::Request::new(Method::GET, NETWORK_UPGRADE_ROUTE.to_string());
+
+        req = req.with_path_param("height".to_string(), height.to_string());
+
+        let dto: Result<BlockchainUpgradeDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact())
+    }
+}
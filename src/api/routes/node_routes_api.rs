@@ -7,12 +7,12 @@
 use {::std::sync::Arc, reqwest::Method};
 
 use crate::{
-    api::{request as __internal_request, sirius_client::ApiClient, NodeTimeDto},
+    api::{request as __internal_request, sirius_client::ApiClient, NodeTimeDto, ServerDto},
     models::Result,
-    node::{NodeInfo, NodeTime},
+    node::{NodeInfo, NodeTime, ServerInfo},
 };
 
-use super::{NODE_INFO, NODE_TIME};
+use super::{NODE_INFO, NODE_PEERS, NODE_TIME, SERVER_INFO};
 
 /// Node ApiClient routes.
 ///
@@ -95,4 +95,74 @@ impl NodeRoutes {
 
         Ok(dto?.compact())
     }
+
+    /// Get the node peers.
+    /// Gets the list of peers visible by the node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let node_peers = client.node_api().get_node_peers().await;
+    ///
+    ///    match node_peers {
+    ///        Ok(peers) => {
+    ///            for peer in peers {
+    ///                println!("{}", peer)
+    ///            }
+    ///        }
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a vector of [NodeInfo] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_node_peers(self) -> Result<Vec<NodeInfo>> {
+        let req = __internal_request::Request::new(Method::GET, NODE_PEERS.to_string());
+
+        req.execute(self.__client()).await
+    }
+
+    /// Get the server information.
+    /// Gets the versions of the REST gateway and sdk components running on the node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::api::SiriusClient;
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let server_info = client.node_api().get_server_info().await;
+    ///
+    ///    match server_info {
+    ///        Ok(resp_info) => println!("{}", resp_info),
+    ///        Err(err) => eprintln!("{:?}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is a [ServerInfo] or
+    /// whose error value is an `Error<Value>` describing the error that occurred.
+    pub async fn get_server_info(self) -> Result<ServerInfo> {
+        let req = __internal_request::Request::new(Method::GET, SERVER_INFO.to_string());
+
+        let dto: Result<ServerDto> = req.execute(self.__client()).await;
+
+        Ok(dto?.compact())
+    }
 }
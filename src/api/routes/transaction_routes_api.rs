@@ -8,12 +8,15 @@ use {
     ::std::{
         fmt::{Debug, Display},
         future::Future,
-        sync::Arc,
+        sync::{Arc, Mutex},
+        time::Duration,
     },
     reqwest::Method,
+    tokio::time::{delay_for, timeout, Instant},
 };
 
 use crate::{
+    account::Address,
     api::{
         internally::{str_to_hash, valid_vec_hash, valid_vec_len},
         request as __internal_request,
@@ -21,11 +24,19 @@ use crate::{
         TransactionDto, TransactionStatusDto,
     },
     errors_const::{ERR_EMPTY_TRANSACTION_HASHES, ERR_EMPTY_TRANSACTION_IDS},
-    models::Result,
+    models::{
+        consts::{SIGNATURE_SIZE, SIGNER_SIZE, SIZE_SIZE, VERSION_SIZE},
+        error::Error,
+        Result,
+    },
+    network::NetworkType,
     transaction::{
-        CosignatureSignedTransaction, SignedTransaction, Transaction, TransactionHashes,
-        TransactionIds, TransactionStatus, Transactions, TransactionsStatus,
+        AbsTransaction, AggregateBondedOptions, AggregateBondedProgress, ConfirmedTransaction,
+        CosignatureSignedTransaction, Deadline, Hash, LockFundsTransaction, SignedTransaction,
+        Transaction, TransactionHashes, TransactionIds, TransactionStatus, Transactions,
+        TransactionsStatus,
     },
+    websocket::SiriusWebsocketClient,
 };
 
 use super::{
@@ -36,19 +47,23 @@ use super::{
 /// Transaction ApiClient routes.
 ///
 #[derive(Clone)]
-pub struct TransactionRoutes(Arc<ApiClient>);
+pub struct TransactionRoutes(Arc<ApiClient>, Hash);
 
 /// Transaction related endpoints.
 ///
 impl TransactionRoutes {
-    pub(crate) fn new(client: Arc<ApiClient>) -> Self {
-        TransactionRoutes(client)
+    pub(crate) fn new(client: Arc<ApiClient>, generation_hash: Hash) -> Self {
+        TransactionRoutes(client, generation_hash)
     }
 
     fn __client(self) -> Arc<ApiClient> {
         self.0
     }
 
+    fn __generation_hash(&self) -> &str {
+        &self.1
+    }
+
     /// Get transaction status
     ///
     /// # Inputs
@@ -89,7 +104,7 @@ impl TransactionRoutes {
 
         let dto: Result<TransactionStatusDto> = req.execute(self.__client()).await;
 
-        Ok(dto?.compact())
+        dto?.compact()
     }
 
     /// Get transactions status.
@@ -146,12 +161,9 @@ impl TransactionRoutes {
 
         let dto: Vec<TransactionStatusDto> = req.execute(self.__client()).await?;
 
-        let statuses: TransactionsStatus = dto
-            .into_iter()
+        dto.into_iter()
             .map(move |status_dto| status_dto.compact())
-            .collect();
-
-        Ok(statuses)
+            .collect()
     }
 
     /// Get transaction information.
@@ -361,6 +373,328 @@ impl TransactionRoutes {
             .await
     }
 
+    /// Announce a transaction and wait until it is confirmed or rejected by the node.
+    ///
+    /// Listens to the `status` and `confirmedAdded` websocket channels of the signer; if the
+    /// websocket can not be used, the transaction status is polled instead.
+    ///
+    /// # Inputs
+    ///
+    /// * `transaction_signed` =    The signed transaction.
+    /// * `wait` =    How long to wait for the confirmation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use std::time::Duration;
+    ///
+    ///use xpx_chain_sdk::{
+    ///    account::{Account, Address},
+    ///    api::SiriusClient,
+    ///    message::PlainMessage,
+    ///    mosaic::Mosaic,
+    ///    transaction::{Deadline, TransferTransaction}
+    ///};
+    ///
+    ///const PRIVATE_KEY: &str = "5D3E959EB0CD69CC1DB6E9C62CB81EC52747AB56FA740CF18AACB5003429AD2E";
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let network_type = client.network_type();
+    ///
+    ///    let account = Account::from_private_key(PRIVATE_KEY, network_type).unwrap();
+    ///
+    ///    let recipient = Address::from_raw("VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS").unwrap();
+    ///
+    ///    let transfer_transaction = TransferTransaction::new(
+    ///        Deadline::default(),
+    ///        recipient,
+    ///        vec![Mosaic::xpx(1)],
+    ///        PlainMessage::new("Transfer From ProximaX Rust SDK"),
+    ///        network_type,
+    ///    ).unwrap();
+    ///
+    ///    let sig_tx = account.sign(transfer_transaction, client.generation_hash()).unwrap();
+    ///
+    ///    let response = client
+    ///        .transaction_api()
+    ///        .announce_and_wait(&sig_tx, Duration::from_secs(60))
+    ///        .await;
+    ///
+    ///    match response {
+    ///        Ok(confirmed) => println!("confirmed at {}", confirmed.height),
+    ///        Err(err) => eprintln!("{}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the [ConfirmedTransaction] or whose error
+    /// value is an `Error::TransactionFailed` with the status returned by the node, an
+    /// `Error::Timeout` if it was not confirmed within `wait`, or any other `Error` that occurred.
+    pub async fn announce_and_wait(
+        self,
+        transaction_signed: &SignedTransaction,
+        wait: Duration,
+    ) -> Result<ConfirmedTransaction> {
+        self.__announce_and_wait(transaction_signed, TRANSACTIONS_ROUTE, wait, || {})
+            .await
+    }
+
+    /// Announce an aggregate bonded transaction together with its hash lock.
+    ///
+    /// Locks `options.lock_mosaic` for the aggregate, waits for the hash lock to be confirmed,
+    /// announces the aggregate bonded and waits until every cosignature is received and the
+    /// aggregate is confirmed. Every step is reported to `progress`.
+    ///
+    /// # Inputs
+    ///
+    /// * `account` =    The account signing the hash lock, usually the aggregate signer.
+    /// * `aggregate_signed` =    The signed aggregate bonded transaction.
+    /// * `options` =    The lock funds and timeouts, see [AggregateBondedOptions].
+    /// * `progress` =    Called after every step of the workflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use xpx_chain_sdk::{
+    ///    account::{Account, Address},
+    ///    api::SiriusClient,
+    ///    message::PlainMessage,
+    ///    mosaic::Mosaic,
+    ///    transaction::{
+    ///        AggregateBondedOptions, AggregateTransaction, Deadline, Transaction,
+    ///        TransferTransaction,
+    ///    },
+    ///};
+    ///
+    ///const PRIVATE_KEY: &str = "5D3E959EB0CD69CC1DB6E9C62CB81EC52747AB56FA740CF18AACB5003429AD2E";
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+    /// let client = SiriusClient::new(node_url).await.unwrap();
+    ///
+    ///    let network_type = client.network_type();
+    ///
+    ///    let account = Account::from_private_key(PRIVATE_KEY, network_type).unwrap();
+    ///
+    ///    let recipient = Address::from_raw("VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS").unwrap();
+    ///
+    ///    let mut transfer = TransferTransaction::new(
+    ///        Deadline::default(),
+    ///        recipient,
+    ///        vec![Mosaic::xpx(1)],
+    ///        PlainMessage::new("Transfer From ProximaX Rust SDK"),
+    ///        network_type,
+    ///    ).unwrap();
+    ///
+    ///    transfer.set_aggregate(account.public_account_to_owned());
+    ///
+    ///    let aggregate = AggregateTransaction::new_bonded(
+    ///        Deadline::default(),
+    ///        vec![Box::new(transfer)],
+    ///        network_type,
+    ///    ).unwrap();
+    ///
+    ///    let sig_aggregate = account.sign(aggregate, client.generation_hash()).unwrap();
+    ///
+    ///    let response = client
+    ///        .transaction_api()
+    ///        .announce_aggregate_bonded_with_lock(
+    ///            &account,
+    ///            &sig_aggregate,
+    ///            AggregateBondedOptions::default(),
+    ///            |progress| println!("{:?}", progress),
+    ///        )
+    ///        .await;
+    ///
+    ///    match response {
+    ///        Ok(confirmed) => println!("confirmed at {}", confirmed.height),
+    ///        Err(err) => eprintln!("{}", err),
+    ///    }
+    ///}
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns a Future `Result` whose okay value is the confirmed aggregate as a
+    /// [ConfirmedTransaction] or whose error value is the `Error` of the step that failed.
+    pub async fn announce_aggregate_bonded_with_lock<F>(
+        self,
+        account: &Account,
+        aggregate_signed: &SignedTransaction,
+        options: AggregateBondedOptions,
+        progress: F,
+    ) -> Result<ConfirmedTransaction>
+    where
+        F: Fn(AggregateBondedProgress),
+    {
+        let lock_funds = LockFundsTransaction::new(
+            Deadline::default(),
+            options.lock_mosaic,
+            options.lock_duration,
+            aggregate_signed.to_owned(),
+            account.public_account.address.network_type,
+        )?;
+
+        let lock_signed = account.sign(lock_funds, self.__generation_hash())?;
+
+        let lock_confirmed = self
+            .clone()
+            .__announce_and_wait(
+                &lock_signed,
+                TRANSACTIONS_ROUTE,
+                options.lock_timeout,
+                || {
+                    progress(AggregateBondedProgress::LockAnnounced(
+                        lock_signed.get_hash(),
+                    ))
+                },
+            )
+            .await?;
+        progress(AggregateBondedProgress::LockConfirmed(
+            lock_confirmed.height,
+        ));
+
+        let bonded_confirmed = self
+            .__announce_and_wait(
+                aggregate_signed,
+                ANNOUNCE_AGGREGATE_ROUTE,
+                options.cosignatures_timeout,
+                || {
+                    progress(AggregateBondedProgress::BondedAnnounced(
+                        aggregate_signed.get_hash(),
+                    ))
+                },
+            )
+            .await?;
+        progress(AggregateBondedProgress::CosignaturesComplete(
+            bonded_confirmed.height,
+        ));
+
+        Ok(bonded_confirmed)
+    }
+
+    /// Announces on `route`, calls `announced` once the node accepted the transaction and
+    /// waits for the confirmation.
+    async fn __announce_and_wait<A>(
+        self,
+        transaction_signed: &SignedTransaction,
+        route: &str,
+        wait: Duration,
+        announced: A,
+    ) -> Result<ConfirmedTransaction>
+    where
+        A: FnOnce(),
+    {
+        let hash = transaction_signed.get_hash();
+        let deadline = Instant::now() + wait;
+
+        let mut websocket = match signer_address(transaction_signed) {
+            Some(address) => self.clone().__subscribe_outcome(&address, &hash).await.ok(),
+            None => None,
+        };
+
+        self.clone()
+            .__announce_transaction(transaction_signed, route)
+            .await?;
+        announced();
+
+        if let Some((ref mut ws, ref outcome)) = websocket {
+            let listened = timeout(
+                deadline.saturating_duration_since(Instant::now()),
+                ws.listen(),
+            )
+            .await;
+
+            let outcome = outcome.lock().ok().and_then(|mut outcome| outcome.take());
+            match outcome {
+                Some(WaitOutcome::Confirmed) => return self.__confirmed_transaction(&hash).await,
+                Some(WaitOutcome::Failed(status)) => return Err(Error::TransactionFailed(status)),
+                None if listened.is_err() => {
+                    let _ = ws.close().await;
+                }
+                None => {}
+            }
+        }
+
+        self.__poll_outcome(&hash, deadline).await
+    }
+
+    async fn __subscribe_outcome(
+        self,
+        address: &Address,
+        hash: &str,
+    ) -> Result<(SiriusWebsocketClient, Arc<Mutex<Option<WaitOutcome>>>)> {
+        let outcome = Arc::new(Mutex::new(None));
+
+        let mut ws = SiriusWebsocketClient::new(self.0.base_path).await?;
+
+        let status_outcome = outcome.clone();
+        let status_hash = hash.to_string();
+        ws.add_status_handlers(address, move |status: TransactionStatus| {
+            if status.hash.to_uppercase() != status_hash {
+                return false;
+            }
+            if let Ok(mut outcome) = status_outcome.lock() {
+                *outcome = Some(WaitOutcome::Failed(status));
+            }
+            true
+        })
+        .await?
+        .detach();
+
+        let confirmed_outcome = outcome.clone();
+        let confirmed_hash = hash.to_string();
+        ws.add_confirmed_added_handlers(address, move |transaction: Box<dyn Transaction>| {
+            if transaction.transaction_hash().to_uppercase() != confirmed_hash {
+                return false;
+            }
+            if let Ok(mut outcome) = confirmed_outcome.lock() {
+                *outcome = Some(WaitOutcome::Confirmed);
+            }
+            true
+        })
+        .await?
+        .detach();
+
+        Ok((ws, outcome))
+    }
+
+    async fn __poll_outcome(self, hash: &str, deadline: Instant) -> Result<ConfirmedTransaction> {
+        loop {
+            if let Ok(status) = self.clone().get_transaction_status(hash).await {
+                if status.is_confirmed() {
+                    return self.__confirmed_transaction(hash).await;
+                }
+                if !status.is_success() {
+                    return Err(Error::TransactionFailed(status));
+                }
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::from_secs(0) {
+                return Err(Error::Timeout(hash.to_string()));
+            }
+
+            delay_for(STATUS_POLL_INTERVAL.min(remaining)).await;
+        }
+    }
+
+    async fn __confirmed_transaction(self, hash: &str) -> Result<ConfirmedTransaction> {
+        let transaction = self.get_transaction(hash).await?;
+
+        Ok(ConfirmedTransaction {
+            height: transaction.height(),
+            transaction,
+        })
+    }
+
     fn __announce_transaction<T>(
         self,
         tx: T,
@@ -377,6 +711,30 @@ impl TransactionRoutes {
     }
 }
 
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+enum WaitOutcome {
+    Confirmed,
+    Failed(TransactionStatus),
+}
+
+/// Returns the address of the signer written in the payload of `transaction_signed`.
+fn signer_address(transaction_signed: &SignedTransaction) -> Option<Address> {
+    const SIGNER_OFFSET: usize = SIZE_SIZE + SIGNATURE_SIZE;
+    // The network type is the highest byte of the little endian version.
+    const NETWORK_OFFSET: usize = SIGNER_OFFSET + SIGNER_SIZE + VERSION_SIZE - 1;
+
+    let payload = hex::decode(transaction_signed.get_payload()).ok()?;
+    if payload.len() <= NETWORK_OFFSET {
+        return None;
+    }
+
+    let signer = hex::encode_upper(&payload[SIGNER_OFFSET..SIGNER_OFFSET + SIGNER_SIZE]);
+    let network_type = NetworkType::from(payload[NETWORK_OFFSET]);
+
+    Address::from_public_key(&signer, network_type).ok()
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AnnounceTransactionInfo {
     pub message: String,
@@ -387,3 +745,271 @@ impl Display for AnnounceTransactionInfo {
         write!(f, "{}", &self.message)
     }
 }
+
+#[cfg(all(test, feature = "mock-node"))]
+mod tests {
+    use {
+        ::std::{sync::Mutex, time::Duration},
+        tokio::{task::JoinHandle, time::delay_for},
+    };
+
+    use crate::{
+        account::Account,
+        api::{Cosigner, SiriusClient},
+        message::PlainMessage,
+        mock::{MockNode, MockNodeConfig},
+        models::error::Error,
+        mosaic::Mosaic,
+        transaction::{
+            AggregateBondedOptions,
+            AggregateBondedProgress::{
+                BondedAnnounced, CosignaturesComplete, LockAnnounced, LockConfirmed,
+            },
+            AggregateTransaction, Deadline, SignedTransaction, Transaction, TransactionStatusCode,
+            TransferTransaction,
+        },
+    };
+
+    struct Fixture {
+        node: MockNode,
+        client: SiriusClient,
+        sender: Account,
+    }
+
+    async fn fixture() -> Fixture {
+        fixture_with(MockNodeConfig::default()).await
+    }
+
+    async fn fixture_with(config: MockNodeConfig) -> Fixture {
+        let node = MockNode::start_with(config).await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+        let sender = Account::new(node.network_type());
+
+        Fixture {
+            node,
+            client,
+            sender,
+        }
+    }
+
+    impl Fixture {
+        fn transfer(&self) -> TransferTransaction {
+            let network_type = self.node.network_type();
+
+            TransferTransaction::new(
+                Deadline::default(),
+                Account::new(network_type).address(),
+                vec![Mosaic::xpx(1)],
+                PlainMessage::empty(),
+                network_type,
+            )
+            .unwrap()
+        }
+
+        fn signed_transfer(&self) -> SignedTransaction {
+            self.sender
+                .sign(self.transfer(), &self.node.generation_hash())
+                .unwrap()
+        }
+
+        /// An aggregate bonded of the sender with a transfer of `cosigner`.
+        fn signed_bonded(&self, cosigner: &Account) -> SignedTransaction {
+            let mut transfer = self.transfer();
+            transfer.set_aggregate(cosigner.public_account_to_owned());
+
+            let aggregate = AggregateTransaction::new_bonded(
+                Deadline::default(),
+                vec![Box::new(transfer)],
+                self.node.network_type(),
+            )
+            .unwrap();
+
+            self.sender
+                .sign(aggregate, &self.node.generation_hash())
+                .unwrap()
+        }
+
+        /// Harvests one block once the node knows `hash` and returns its height.
+        fn harvest_when_announced(&self, hash: &str) -> JoinHandle<u64> {
+            let node = self.node.clone();
+            let client = self.client.clone();
+            let hash = hash.to_string();
+
+            tokio::spawn(async move {
+                while client
+                    .transaction_api()
+                    .get_transaction_status(&hash)
+                    .await
+                    .is_err()
+                {
+                    delay_for(Duration::from_millis(10)).await;
+                }
+                node.harvest()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn announce_and_wait_returns_the_confirmed_transaction() {
+        let fixture = fixture().await;
+        let signed = fixture.signed_transfer();
+        let harvested = fixture.harvest_when_announced(&signed.get_hash());
+
+        let confirmed = fixture
+            .client
+            .transaction_api()
+            .announce_and_wait(&signed, Duration::from_secs(5))
+            .await
+            .unwrap_or_else(|err| panic!("unexpected error {}", err));
+
+        assert_eq!(confirmed.height.as_u64(), harvested.await.unwrap());
+        assert_eq!(
+            confirmed.transaction.transaction_hash().to_uppercase(),
+            signed.get_hash()
+        );
+    }
+
+    #[tokio::test]
+    async fn announce_and_wait_returns_the_status_of_a_failed_transaction() {
+        let fixture = fixture().await;
+        fixture.node.fail_next("Failure_Core_Insufficient_Balance");
+        let signed = fixture.signed_transfer();
+
+        let result = fixture
+            .client
+            .transaction_api()
+            .announce_and_wait(&signed, Duration::from_secs(5))
+            .await;
+
+        match result {
+            Err(Error::TransactionFailed(status)) => {
+                assert_eq!(
+                    status.status,
+                    TransactionStatusCode::CoreInsufficientBalance
+                );
+                assert_eq!(status.hash.to_uppercase(), signed.get_hash());
+            }
+            Err(err) => panic!("unexpected error {}", err),
+            Ok(_) => panic!("a failed transaction was confirmed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn announce_and_wait_times_out_without_a_block() {
+        let fixture = fixture().await;
+        let signed = fixture.signed_transfer();
+
+        let result = fixture
+            .client
+            .transaction_api()
+            .announce_and_wait(&signed, Duration::from_millis(300))
+            .await;
+
+        match result {
+            Err(Error::Timeout(hash)) => assert_eq!(hash, signed.get_hash()),
+            Err(err) => panic!("unexpected error {}", err),
+            Ok(_) => panic!("a transaction was confirmed without a block"),
+        }
+    }
+
+    #[tokio::test]
+    async fn announce_and_wait_polls_the_status_without_a_websocket() {
+        let fixture = fixture().await;
+        fixture.node.refuse_websockets(true);
+        let signed = fixture.signed_transfer();
+        let harvested = fixture.harvest_when_announced(&signed.get_hash());
+
+        let confirmed = fixture
+            .client
+            .transaction_api()
+            .announce_and_wait(&signed, Duration::from_secs(5))
+            .await
+            .unwrap_or_else(|err| panic!("unexpected error {}", err));
+
+        assert!(fixture.node.subscriptions().is_empty());
+        assert_eq!(confirmed.height.as_u64(), harvested.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn bonded_with_lock_reports_every_step_in_order() {
+        let fixture = fixture_with(MockNodeConfig {
+            block_interval: Some(Duration::from_millis(100)),
+            ..MockNodeConfig::default()
+        })
+        .await;
+        let cosigner = Account::new(fixture.node.network_type());
+        let signed = fixture.signed_bonded(&cosigner);
+
+        let progress = Mutex::new(vec![]);
+        let options = AggregateBondedOptions::new()
+            .with_lock_timeout(Duration::from_secs(5))
+            .with_cosignatures_timeout(Duration::from_secs(5));
+        let workflow = fixture
+            .client
+            .transaction_api()
+            .announce_aggregate_bonded_with_lock(&fixture.sender, &signed, options, |step| {
+                progress.lock().unwrap().push(step)
+            });
+
+        let cosigner = Cosigner::new(&fixture.client, cosigner, |_: &AggregateTransaction| true);
+        let cosign = async {
+            while cosigner.backfill().await.unwrap_or_default() == 0 {
+                delay_for(Duration::from_millis(50)).await;
+            }
+        };
+
+        let (confirmed, _) = tokio::join!(workflow, cosign);
+        let confirmed = confirmed.unwrap_or_else(|err| panic!("unexpected error {}", err));
+
+        match progress.into_inner().unwrap().as_slice() {
+            [LockAnnounced(_), LockConfirmed(lock_height), BondedAnnounced(hash), CosignaturesComplete(height)] =>
+            {
+                assert_eq!(hash, &signed.get_hash());
+                assert!(lock_height.as_u64() < height.as_u64());
+                assert_eq!(height.as_u64(), confirmed.height.as_u64());
+            }
+            progress => panic!("unexpected progress {:?}", progress),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_failed_lock_stops_before_the_bonded_is_announced() {
+        let fixture = fixture().await;
+        fixture.node.fail_next("Failure_Core_Insufficient_Balance");
+        let cosigner = Account::new(fixture.node.network_type());
+        let signed = fixture.signed_bonded(&cosigner);
+
+        let progress = Mutex::new(vec![]);
+        let result = fixture
+            .client
+            .transaction_api()
+            .announce_aggregate_bonded_with_lock(
+                &fixture.sender,
+                &signed,
+                AggregateBondedOptions::default(),
+                |step| progress.lock().unwrap().push(step),
+            )
+            .await;
+
+        match result {
+            Err(Error::TransactionFailed(status)) => {
+                assert_eq!(
+                    status.status,
+                    TransactionStatusCode::CoreInsufficientBalance
+                )
+            }
+            Err(err) => panic!("unexpected error {}", err),
+            Ok(_) => panic!("the aggregate was confirmed without its lock"),
+        }
+        match progress.into_inner().unwrap().as_slice() {
+            [LockAnnounced(hash)] => assert_ne!(hash, &signed.get_hash()),
+            progress => panic!("unexpected progress {:?}", progress),
+        }
+        assert!(fixture
+            .client
+            .transaction_api()
+            .get_transaction_status(&signed.get_hash())
+            .await
+            .is_err());
+    }
+}
@@ -8,12 +8,16 @@ use {::std::sync::Arc, reqwest::Client as ReqwestClient};
 
 use crate::{models::error::Result, network::NetworkType, transaction::Hash};
 
-use super::routes::{
-    account_routes_api::AccountRoutes, block_routes_api::BlockRoutes,
-    chain_routes_api::ChainRoutes, exchange_routes_api::ExchangeRoutes,
-    mosaic_routes_api::MosaicRoutes, namespace_routes_api::NamespaceRoutes,
-    node_routes_api::NodeRoutes, resolver_routes_api::ResolverRoutes,
-    transaction_routes_api::TransactionRoutes,
+use super::{
+    cache::ResponseCache,
+    routes::{
+        account_routes_api::AccountRoutes, block_routes_api::BlockRoutes,
+        chain_routes_api::ChainRoutes, exchange_routes_api::ExchangeRoutes,
+        lock_routes_api::LockRoutes, mosaic_routes_api::MosaicRoutes,
+        namespace_routes_api::NamespaceRoutes, network_routes_api::NetworkRoutes,
+        node_routes_api::NodeRoutes, resolver_routes_api::ResolverRoutes,
+        transaction_routes_api::TransactionRoutes,
+    },
 };
 
 #[derive(Clone, Serialize)]
@@ -30,7 +34,10 @@ impl SiriusClient {
     }
 
     pub fn block_api(&self) -> Box<BlockRoutes> {
-        Box::new(BlockRoutes::new(self.client.to_owned()))
+        Box::new(BlockRoutes::new(
+            self.client.to_owned(),
+            self.network_type(),
+        ))
     }
 
     pub fn chain_api(&self) -> Box<ChainRoutes> {
@@ -45,6 +52,14 @@ impl SiriusClient {
         ))
     }
 
+    pub fn lock_api(&self) -> Box<LockRoutes> {
+        Box::new(LockRoutes::new(self.client.to_owned(), self.network_type()))
+    }
+
+    pub fn network_api(&self) -> Box<NetworkRoutes> {
+        Box::new(NetworkRoutes::new(self.client.to_owned()))
+    }
+
     pub fn node_api(&self) -> Box<NodeRoutes> {
         Box::new(NodeRoutes::new(self.client.to_owned()))
     }
@@ -58,7 +73,10 @@ impl SiriusClient {
     }
 
     pub fn transaction_api(&self) -> Box<TransactionRoutes> {
-        Box::new(TransactionRoutes::new(self.client.to_owned()))
+        Box::new(TransactionRoutes::new(
+            self.client.to_owned(),
+            self.generation_hash.to_owned(),
+        ))
     }
 
     pub fn resolver_api(&self) -> Box<ResolverRoutes> {
@@ -117,6 +135,18 @@ impl SiriusClient {
     pub fn node(&self) -> &str {
         self.client.base_path
     }
+
+    /// Caches the immutable responses of the node with `cache`, replacing any previous cache.
+    ///
+    /// Clones of this client made before the call keep their own cache.
+    pub fn set_cache(&mut self, cache: ResponseCache) {
+        Arc::make_mut(&mut self.client).cache = Some(Arc::new(cache));
+    }
+
+    /// The response cache of this client, for manual invalidation.
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.client.cache.as_deref()
+    }
 }
 
 impl core::fmt::Display for SiriusClient {
@@ -134,6 +164,7 @@ pub(crate) struct ApiClient {
     pub base_path: &'static str,
     pub client: ReqwestClient,
     pub user_agent: Option<String>,
+    pub cache: Option<Arc<ResponseCache>>,
 }
 
 impl ApiClient {
@@ -143,6 +174,7 @@ impl ApiClient {
             base_path: url,
             client,
             user_agent: Some("Sirius/0.0.1/rust".to_owned()),
+            cache: None,
         }
     }
 }
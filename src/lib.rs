@@ -8,8 +8,6 @@
 extern crate downcast_rs;
 #[macro_use]
 extern crate erased_serde;
-#[macro_use]
-extern crate failure;
 extern crate flatbuffers as fb;
 #[macro_use]
 extern crate serde;
@@ -17,9 +15,13 @@ extern crate xpx_chain_crypto as crypto;
 
 pub use self::models::*;
 
+#[macro_use]
+mod macros;
+
 pub mod api;
+#[cfg(feature = "mock-node")]
+pub mod mock;
 mod models;
+pub mod sync;
 mod utils;
 pub mod websocket;
-
-pub type Result<T> = ::std::result::Result<T, failure::Error>;
@@ -0,0 +1,42 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+/// Returns early with an `Error::Validation`.
+///
+/// `bail!("field" => message)` names the offending input. `bail!(decode: message)` returns an
+/// `Error::Decode` instead, for node responses and payloads that can not be read.
+macro_rules! bail {
+    (decode: $fmt:expr, $($arg:tt)+) => {
+        return Err($crate::Error::decode(format!($fmt, $($arg)+)))
+    };
+    (decode: $msg:expr) => {
+        return Err($crate::Error::decode($msg))
+    };
+    ($field:literal => $fmt:expr, $($arg:tt)+) => {
+        return Err($crate::Error::validation(Some($field), format!($fmt, $($arg)+)))
+    };
+    ($field:literal => $msg:expr) => {
+        return Err($crate::Error::validation(Some($field), $msg))
+    };
+    ($msg:expr) => {
+        return Err($crate::Error::validation(None, $msg))
+    };
+    ($fmt:expr, $($arg:tt)+) => {
+        return Err($crate::Error::validation(None, format!($fmt, $($arg)+)))
+    };
+}
+
+/// Returns early with an `Error::Validation` if the condition does not hold.
+///
+/// `ensure!(cond, "field" => message)` names the offending input and
+/// `ensure!(cond, decode: message)` returns an `Error::Decode`, like [bail!].
+macro_rules! ensure {
+    ($cond:expr, $($arg:tt)+) => {
+        if !($cond) {
+            bail!($($arg)+);
+        }
+    };
+}
@@ -0,0 +1,824 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::{BTreeMap, HashMap, VecDeque},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    serde_json::{json, Value},
+    sha3::{Digest, Sha3_256},
+    tokio::sync::broadcast,
+};
+
+use crate::{
+    merkle_root,
+    models::{errors_const::ERR_INVALID_AGGREGATE_TRANSACTION, Result},
+    transaction::{internal::create_transaction_hash, EntityTypeEnum, Hash},
+};
+
+use super::{
+    payload::{address_of, decode_payload, encoded_address, uint64_json, DecodedPayload},
+    MockNodeConfig,
+};
+
+const TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS: u64 = 1_459_468_800 * 1_000;
+const BLOCK_VERSION: u32 = 3;
+const BLOCK_DIFFICULTY: u64 = 100_000_000_000_000;
+const EMPTY_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+pub(crate) const GROUP_UNCONFIRMED: &str = "unconfirmed";
+pub(crate) const GROUP_CONFIRMED: &str = "confirmed";
+pub(crate) const GROUP_PARTIAL: &str = "partial";
+pub(crate) const GROUP_FAILED: &str = "failed";
+
+pub(crate) const STATUS_SUCCESS: &str = "Success";
+pub(crate) const STATUS_PAST_DEADLINE: &str = "Failure_Core_Past_Deadline";
+pub(crate) const STATUS_HASH_EXISTS: &str = "Failure_Hash_Exists";
+pub(crate) const STATUS_INSUFFICIENT_BALANCE: &str = "Failure_Core_Insufficient_Balance";
+pub(crate) const STATUS_MISSING_COSIGNERS: &str = "Failure_Aggregate_Missing_Cosigners";
+pub(crate) const STATUS_INACTIVE_HASH_LOCK: &str = "Failure_LockHash_Inactive_Hash";
+pub(crate) const STATUS_INELIGIBLE_COSIGNER: &str = "Failure_Aggregate_Ineligible_Cosigners";
+
+/// A websocket notification produced by the ledger.
+///
+/// The `meta.address` field is filled in by the websocket server for every subscriber.
+#[derive(Debug, Clone)]
+pub(crate) struct MockEvent {
+    pub channel: &'static str,
+    pub addresses: Vec<String>,
+    pub message: Value,
+}
+
+/// Where an announced payload was sent to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum AnnounceRoute {
+    Transaction,
+    Partial,
+}
+
+/// Account transaction listings served by `/account/{publicKey}/transactions/*`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum AccountListing {
+    All,
+    Incoming,
+    Outgoing,
+    Unconfirmed,
+    Partial,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MockTransaction {
+    pub id: String,
+    pub hash: Hash,
+    pub payload: DecodedPayload,
+    pub height: u64,
+    pub index: u32,
+}
+
+impl MockTransaction {
+    fn signer_address(&self, ledger: &Ledger) -> String {
+        address_of(&self.payload.signer, ledger.config.network_type).unwrap_or_default()
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut transaction = self.payload.json.clone();
+
+        if let Some(inner) = transaction["transactions"].as_array_mut() {
+            for (index, item) in inner.iter_mut().enumerate() {
+                item["meta"] = json!({
+                    "height": uint64_json(self.height),
+                    "aggregateHash": self.hash,
+                    "aggregateId": self.id,
+                    "index": index,
+                    "id": format!("{}{:04X}", &self.id[..20], index),
+                });
+            }
+        }
+
+        json!({
+            "meta": {
+                "height": uint64_json(self.height),
+                "hash": self.hash,
+                "merkleComponentHash": self.hash,
+                "index": self.index,
+                "id": self.id,
+            },
+            "transaction": transaction,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MockStatus {
+    group: &'static str,
+    status: String,
+    deadline: u64,
+    height: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MockBlock {
+    pub hash: Hash,
+    pub transactions: Vec<Hash>,
+    pub json: Value,
+}
+
+#[derive(Debug, Clone)]
+struct MockLock {
+    owner: String,
+    signer: String,
+    mosaic_id: u64,
+    amount: u64,
+    expiration_height: u64,
+    used: bool,
+}
+
+/// In-memory chain state of the mock node.
+pub(crate) struct Ledger {
+    pub config: MockNodeConfig,
+    blocks: Vec<MockBlock>,
+    confirmed: Vec<MockTransaction>,
+    unconfirmed: Vec<MockTransaction>,
+    partial: Vec<MockTransaction>,
+    statuses: HashMap<Hash, MockStatus>,
+    balances: HashMap<String, BTreeMap<u64, u64>>,
+    public_keys: HashMap<String, (String, u64)>,
+    locks: HashMap<Hash, MockLock>,
+    pending_failures: VecDeque<String>,
+    next_id: u64,
+    /// Number of forks, hashed into the blocks pushed after the first one.
+    forks: u64,
+    events: broadcast::Sender<MockEvent>,
+}
+
+impl Ledger {
+    pub fn new(config: MockNodeConfig, events: broadcast::Sender<MockEvent>) -> Self {
+        let mut ledger = Ledger {
+            config,
+            blocks: vec![],
+            confirmed: vec![],
+            unconfirmed: vec![],
+            partial: vec![],
+            statuses: HashMap::new(),
+            balances: HashMap::new(),
+            public_keys: HashMap::new(),
+            locks: HashMap::new(),
+            pending_failures: VecDeque::new(),
+            next_id: 1,
+            forks: 0,
+            events,
+        };
+
+        ledger.push_block(vec![]);
+        ledger
+    }
+
+    pub fn height(&self) -> u64 {
+        self.blocks.len() as u64
+    }
+
+    pub fn credit(&mut self, address: &str, mosaic_id: u64, amount: u64) {
+        let balance = self
+            .balances
+            .entry(address.to_string())
+            .or_insert_with(BTreeMap::new)
+            .entry(mosaic_id)
+            .or_insert(0);
+        *balance = balance.saturating_add(amount);
+    }
+
+    pub fn balance(&self, address: &str, mosaic_id: u64) -> u64 {
+        self.balances
+            .get(address)
+            .and_then(|mosaics| mosaics.get(&mosaic_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn fail_next(&mut self, status: &str) {
+        self.pending_failures.push_back(status.to_string())
+    }
+
+    /// Accepts a signed payload. Validation failures are reported through the
+    /// transaction status, like a real node does.
+    pub fn announce(&mut self, payload: &str, route: AnnounceRoute) -> Result<Hash> {
+        let bytes = hex::decode(payload)?;
+
+        let decoded = decode_payload(&bytes, self.config.network_type)?;
+
+        if route == AnnounceRoute::Partial && decoded.entity_type != EntityTypeEnum::AggregateBonded
+        {
+            return Err(ERR_INVALID_AGGREGATE_TRANSACTION.into());
+        }
+
+        let hash = create_transaction_hash(
+            hex::encode(&bytes[..decoded.hashed_size]),
+            &self.config.generation_hash,
+        )?;
+
+        let mut transaction = MockTransaction {
+            id: self.next_object_id(),
+            hash: hash.clone(),
+            payload: decoded,
+            height: 0,
+            index: 0,
+        };
+
+        if let Some(status) = self.validate(&transaction, route) {
+            self.set_status(&transaction, GROUP_FAILED, &status, None);
+            self.emit_status(&transaction, &status);
+            return Ok(hash);
+        }
+
+        if route == AnnounceRoute::Partial {
+            self.set_status(&transaction, GROUP_PARTIAL, STATUS_SUCCESS, None);
+            self.emit("partialAdded", &transaction, transaction.to_json());
+            self.partial.push(transaction);
+        } else {
+            transaction.payload.cosigners.clear();
+            self.add_unconfirmed(transaction);
+        }
+
+        Ok(hash)
+    }
+
+    /// Adds a cosignature to a partial aggregate bonded transaction.
+    pub fn cosign(&mut self, parent_hash: &str, signature: &str, signer: &str) -> Result<()> {
+        let parent_hash = parent_hash.to_uppercase();
+        let signer = signer.to_uppercase();
+
+        let position = match self.partial.iter().position(|tx| tx.hash == parent_hash) {
+            Some(position) => position,
+            None => return Err(STATUS_INACTIVE_HASH_LOCK.into()),
+        };
+
+        if !self.partial[position].payload.cosigners.contains(&signer) {
+            let transaction = self.partial[position].clone();
+            self.emit_status(&transaction, STATUS_INELIGIBLE_COSIGNER);
+            return Ok(());
+        }
+
+        {
+            let transaction = &mut self.partial[position];
+            transaction
+                .payload
+                .cosigners
+                .retain(|cosigner| cosigner != &signer);
+            if let Some(cosignatures) = transaction.payload.json["cosignatures"].as_array_mut() {
+                cosignatures.push(json!({ "signer": signer, "signature": signature }));
+            }
+        }
+
+        let transaction = self.partial[position].clone();
+        self.emit(
+            "cosignature",
+            &transaction,
+            json!({
+                "parentHash": parent_hash,
+                "signature": signature,
+                "signer": signer,
+            }),
+        );
+
+        if transaction.payload.cosigners.is_empty() {
+            let transaction = self.partial.remove(position);
+            self.emit(
+                "partialRemoved",
+                &transaction,
+                json!({ "meta": { "hash": transaction.hash } }),
+            );
+            self.add_unconfirmed(transaction);
+        }
+
+        Ok(())
+    }
+
+    /// Includes every unconfirmed transaction into a new block and returns its height.
+    pub fn harvest(&mut self) -> u64 {
+        let mut transactions: Vec<MockTransaction> = self.unconfirmed.drain(..).collect();
+        let height = self.height() + 1;
+
+        for (index, transaction) in transactions.iter_mut().enumerate() {
+            transaction.height = height;
+            transaction.index = index as u32;
+
+            self.apply(transaction);
+
+            let signer_address = transaction.signer_address(self);
+            self.public_keys
+                .entry(signer_address)
+                .or_insert_with(|| (transaction.payload.signer.clone(), height));
+
+            self.set_status(transaction, GROUP_CONFIRMED, STATUS_SUCCESS, Some(height));
+            self.emit(
+                "unconfirmedRemoved",
+                transaction,
+                json!({ "meta": { "hash": transaction.hash } }),
+            );
+            self.emit("confirmedAdded", transaction, transaction.to_json());
+        }
+
+        let hashes = transactions.iter().map(|tx| tx.hash.clone()).collect();
+        self.confirmed.append(&mut transactions);
+        self.push_block(hashes);
+
+        height
+    }
+
+    /// Replaces the last `depth` blocks, the nemesis block aside, with blocks of the same
+    /// transactions but other hashes and returns the height.
+    pub fn fork(&mut self, depth: u64) -> u64 {
+        let depth = depth.min(self.height() - 1) as usize;
+        let replaced = self.blocks.split_off(self.blocks.len() - depth);
+
+        self.forks += 1;
+        for block in replaced {
+            self.push_block(block.transactions);
+        }
+
+        self.height()
+    }
+
+    /// Serves the block at `height` with a previous block hash that is not the hash of the
+    /// block before it.
+    pub fn break_link(&mut self, height: u64) {
+        if let Some(block) = height
+            .checked_sub(1)
+            .and_then(|index| self.blocks.get_mut(index as usize))
+        {
+            block.json["block"]["previousBlockHash"] = json!(EMPTY_HASH);
+        }
+    }
+
+    pub fn block(&self, height: u64) -> Option<&MockBlock> {
+        if height == 0 {
+            return None;
+        }
+        self.blocks.get(height as usize - 1)
+    }
+
+    pub fn transaction_merkle_path(&self, height: u64, hash: &str) -> Option<Result<Value>> {
+        let block = self.block(height)?;
+        let index = block
+            .transactions
+            .iter()
+            .position(|tx| tx.eq_ignore_ascii_case(hash))?;
+
+        Some(merkle_path(&block.transactions, index))
+    }
+
+    pub fn blocks(&self, height: u64, limit: usize) -> Vec<Value> {
+        self.blocks
+            .iter()
+            .skip(height.saturating_sub(1) as usize)
+            .take(limit)
+            .map(|block| block.json.clone())
+            .collect()
+    }
+
+    pub fn block_transactions(
+        &self,
+        height: u64,
+        page_size: usize,
+        id: Option<&str>,
+    ) -> Option<Vec<Value>> {
+        self.block(height)?;
+
+        let transactions = self
+            .confirmed
+            .iter()
+            .filter(|tx| tx.height == height)
+            .collect::<Vec<_>>();
+
+        Some(page(transactions, page_size, id, false))
+    }
+
+    pub fn transaction(&self, id: &str) -> Option<Value> {
+        let id = id.to_uppercase();
+
+        self.confirmed
+            .iter()
+            .chain(self.unconfirmed.iter())
+            .chain(self.partial.iter())
+            .find(|tx| tx.hash == id || tx.id == id)
+            .map(|tx| tx.to_json())
+    }
+
+    pub fn status(&self, hash: &str) -> Option<Value> {
+        let hash = hash.to_uppercase();
+
+        self.statuses.get(&hash).map(|status| {
+            let mut value = json!({
+                "group": status.group,
+                "status": status.status,
+                "hash": hash,
+                "deadline": uint64_json(status.deadline),
+            });
+            if let Some(height) = status.height {
+                value["height"] = uint64_json(height);
+            }
+            value
+        })
+    }
+
+    pub fn hash_lock(&self, hash: &str) -> Option<Value> {
+        let hash = hash.to_uppercase();
+        self.locks
+            .get(&hash)
+            .and_then(|lock| hash_lock_json(&hash, lock).ok())
+    }
+
+    pub fn hash_locks(&self, account_id: &str) -> Result<Vec<Value>> {
+        let (address, _) = self.resolve_account(account_id)?;
+
+        let mut locks = vec![];
+        for (hash, lock) in self.locks.iter().filter(|(_, lock)| lock.owner == address) {
+            locks.push(hash_lock_json(hash, lock)?);
+        }
+
+        Ok(locks)
+    }
+
+    pub fn account_info(&self, account_id: &str) -> Result<Option<Value>> {
+        let (address, public_key) = self.resolve_account(account_id)?;
+
+        let (public_key, public_key_height) = match self.public_keys.get(&address) {
+            Some((key, height)) => (key.clone(), *height),
+            None => (public_key.unwrap_or_else(|| EMPTY_HASH.to_string()), 0),
+        };
+
+        let mosaics = match self.balances.get(&address) {
+            Some(mosaics) => mosaics
+                .iter()
+                .filter(|(_, amount)| **amount > 0)
+                .map(|(id, amount)| json!({ "id": uint64_json(*id), "amount": uint64_json(*amount) }))
+                .collect::<Vec<_>>(),
+            None if public_key_height == 0 => return Ok(None),
+            None => vec![],
+        };
+
+        Ok(Some(json!({
+            "meta": {},
+            "account": {
+                "address": encoded_address(&address)?,
+                "addressHeight": uint64_json(1),
+                "publicKey": public_key,
+                "publicKeyHeight": uint64_json(public_key_height),
+                "mosaics": mosaics,
+                "accountType": 0,
+                "linkedAccountKey": EMPTY_HASH,
+            }
+        })))
+    }
+
+    pub fn account_transactions(
+        &self,
+        public_key: &str,
+        listing: AccountListing,
+        page_size: usize,
+        id: Option<&str>,
+        ordering: Option<&str>,
+    ) -> Result<Vec<Value>> {
+        let address = address_of(public_key, self.config.network_type)?;
+
+        let source = match listing {
+            AccountListing::Unconfirmed => &self.unconfirmed,
+            AccountListing::Partial => &self.partial,
+            _ => &self.confirmed,
+        };
+
+        let transactions = source
+            .iter()
+            .filter(|tx| tx.payload.addresses.contains(&address))
+            .filter(|tx| {
+                let outgoing = tx.payload.signer == public_key.to_uppercase();
+                match listing {
+                    AccountListing::Incoming => !outgoing,
+                    AccountListing::Outgoing => outgoing,
+                    _ => true,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let descending = ordering.map(|o| o != "id").unwrap_or(true);
+
+        Ok(page(transactions, page_size, id, descending))
+    }
+
+    fn resolve_account(&self, account_id: &str) -> Result<(String, Option<String>)> {
+        let account_id = account_id.trim().to_uppercase().replace("-", "");
+        if account_id.len() == 64 {
+            let address = address_of(&account_id, self.config.network_type)?;
+            Ok((address, Some(account_id)))
+        } else {
+            Ok((account_id, None))
+        }
+    }
+
+    fn validate(&mut self, transaction: &MockTransaction, route: AnnounceRoute) -> Option<String> {
+        if let Some(status) = self.pending_failures.pop_front() {
+            return Some(status);
+        }
+
+        if self.statuses.contains_key(&transaction.hash) {
+            return Some(STATUS_HASH_EXISTS.to_string());
+        }
+
+        if transaction.payload.deadline < now_blockchain_timestamp() {
+            return Some(STATUS_PAST_DEADLINE.to_string());
+        }
+
+        match route {
+            AnnounceRoute::Partial
+                if !self
+                    .locks
+                    .get(&transaction.hash)
+                    .map_or(false, |lock| !lock.used) =>
+            {
+                return Some(STATUS_INACTIVE_HASH_LOCK.to_string())
+            }
+            AnnounceRoute::Transaction if !transaction.payload.cosigners.is_empty() => {
+                return Some(STATUS_MISSING_COSIGNERS.to_string())
+            }
+            _ => {}
+        }
+
+        if self.config.enforce_balances {
+            let mut required: HashMap<(&str, u64), u64> = HashMap::new();
+            for movement in &transaction.payload.transfers {
+                *required
+                    .entry((movement.from.as_str(), movement.mosaic_id))
+                    .or_insert(0) += movement.amount;
+            }
+
+            let insufficient = required
+                .iter()
+                .any(|((address, mosaic_id), amount)| self.balance(address, *mosaic_id) < *amount);
+
+            if insufficient {
+                return Some(STATUS_INSUFFICIENT_BALANCE.to_string());
+            }
+        }
+
+        None
+    }
+
+    fn add_unconfirmed(&mut self, transaction: MockTransaction) {
+        self.set_status(&transaction, GROUP_UNCONFIRMED, STATUS_SUCCESS, None);
+        self.emit("unconfirmedAdded", &transaction, transaction.to_json());
+        self.unconfirmed.push(transaction);
+    }
+
+    fn apply(&mut self, transaction: &MockTransaction) {
+        for movement in &transaction.payload.transfers {
+            if let Some(mosaics) = self.balances.get_mut(&movement.from) {
+                if let Some(balance) = mosaics.get_mut(&movement.mosaic_id) {
+                    *balance = balance.saturating_sub(movement.amount);
+                }
+            }
+
+            if let Some(to) = &movement.to {
+                self.credit(to, movement.mosaic_id, movement.amount);
+            }
+        }
+
+        if let Some(lock_hash) = &transaction.payload.lock_hash {
+            if let Some(movement) = transaction.payload.transfers.first() {
+                self.locks.insert(
+                    lock_hash.clone(),
+                    MockLock {
+                        owner: movement.from.clone(),
+                        signer: transaction.payload.signer.clone(),
+                        mosaic_id: movement.mosaic_id,
+                        amount: movement.amount,
+                        expiration_height: transaction.height + transaction.payload.lock_duration,
+                        used: false,
+                    },
+                );
+            }
+        }
+
+        if transaction.payload.entity_type == EntityTypeEnum::AggregateBonded {
+            let released = match self.locks.get_mut(&transaction.hash) {
+                Some(lock) if !lock.used => {
+                    lock.used = true;
+                    Some((lock.owner.clone(), lock.mosaic_id, lock.amount))
+                }
+                _ => None,
+            };
+
+            if let Some((owner, mosaic_id, amount)) = released {
+                self.credit(&owner, mosaic_id, amount);
+            }
+        }
+    }
+
+    fn set_status(
+        &mut self,
+        transaction: &MockTransaction,
+        group: &'static str,
+        status: &str,
+        height: Option<u64>,
+    ) {
+        self.statuses.insert(
+            transaction.hash.clone(),
+            MockStatus {
+                group,
+                status: status.to_string(),
+                deadline: transaction.payload.deadline,
+                height,
+            },
+        );
+    }
+
+    fn emit_status(&self, transaction: &MockTransaction, status: &str) {
+        let message = json!({
+            "meta": { "channelName": "status" },
+            "status": status,
+            "hash": transaction.hash,
+            "deadline": uint64_json(transaction.payload.deadline),
+        });
+
+        let _ = self.events.send(MockEvent {
+            channel: "status",
+            addresses: vec![transaction.signer_address(self)],
+            message,
+        });
+    }
+
+    fn emit(&self, channel: &'static str, transaction: &MockTransaction, mut message: Value) {
+        if message["meta"].is_null() {
+            message["meta"] = json!({});
+        }
+        message["meta"]["channelName"] = json!(channel);
+
+        // Nobody listening is not an error for the ledger.
+        let _ = self.events.send(MockEvent {
+            channel,
+            addresses: transaction.payload.addresses.clone(),
+            message,
+        });
+    }
+
+    fn push_block(&mut self, transactions: Vec<Hash>) {
+        let height = self.height() + 1;
+
+        let previous_block_hash = self
+            .blocks
+            .last()
+            .map(|block| block.hash.clone())
+            .unwrap_or_else(|| EMPTY_HASH.to_string());
+
+        let transactions_hash =
+            merkle_root(&transactions).unwrap_or_else(|_| EMPTY_HASH.to_string());
+
+        let mut hasher = Sha3_256::default();
+        hasher.input(height.to_le_bytes());
+        hasher.input(hex::decode(&previous_block_hash).unwrap_or_default());
+        hasher.input(hex::decode(&transactions_hash).unwrap_or_default());
+        if self.forks > 0 {
+            hasher.input(self.forks.to_le_bytes());
+        }
+        let hash = hex::encode_upper(hasher.result());
+
+        let entity_type: u16 = if height == 1 {
+            EntityTypeEnum::NemesisBlock.value()
+        } else {
+            EntityTypeEnum::Block.value()
+        };
+
+        let version = ((*self.config.network_type as u32) << 24) + BLOCK_VERSION;
+
+        let json = json!({
+            "meta": {
+                "hash": hash,
+                "generationHash": self.config.generation_hash,
+                "totalFee": uint64_json(0),
+                "numTransactions": transactions.len(),
+            },
+            "block": {
+                "signature": format!("{}{}", EMPTY_HASH, EMPTY_HASH),
+                "signer": self.config.harvester,
+                "version": version,
+                "type": entity_type,
+                "height": uint64_json(height),
+                "timestamp": uint64_json(now_blockchain_timestamp()),
+                "difficulty": uint64_json(BLOCK_DIFFICULTY),
+                "feeMultiplier": 0,
+                "previousBlockHash": previous_block_hash,
+                "blockTransactionsHash": transactions_hash,
+                "blockReceiptsHash": EMPTY_HASH,
+                "stateHash": EMPTY_HASH,
+                "beneficiary": self.config.harvester,
+                "feeInterest": 1,
+                "feeInterestDenominator": 1,
+            }
+        });
+
+        let mut message = json.clone();
+        message["meta"] = json!({
+            "channelName": "block",
+            "hash": hash,
+            "generationHash": self.config.generation_hash,
+        });
+        let _ = self.events.send(MockEvent {
+            channel: "block",
+            addresses: vec![],
+            message,
+        });
+
+        self.blocks.push(MockBlock {
+            hash,
+            transactions,
+            json,
+        });
+    }
+
+    fn next_object_id(&mut self) -> String {
+        let id = format!("{:024X}", self.next_id);
+        self.next_id += 1;
+        id
+    }
+}
+
+fn page(
+    mut transactions: Vec<&MockTransaction>,
+    page_size: usize,
+    id: Option<&str>,
+    descending: bool,
+) -> Vec<Value> {
+    if descending {
+        transactions.reverse();
+    }
+
+    let start = match id {
+        Some(id) => transactions
+            .iter()
+            .position(|tx| tx.id == id.to_uppercase())
+            .map(|position| position + 1)
+            .unwrap_or(0),
+        None => 0,
+    };
+
+    transactions
+        .into_iter()
+        .skip(start)
+        .take(page_size)
+        .map(|tx| tx.to_json())
+        .collect()
+}
+
+/// Returns the merkle path of the leaf at `index`, shaped as the REST `merklePath`.
+fn merkle_path(hashes: &[Hash], mut index: usize) -> Result<Value> {
+    let mut level = hashes.to_vec();
+    let mut path = vec![];
+
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(level[level.len() - 1].clone());
+        }
+
+        let (sibling, position) = if index % 2 == 0 {
+            (index + 1, "right")
+        } else {
+            (index - 1, "left")
+        };
+        path.push(json!({ "hash": level[sibling], "position": position }));
+
+        let mut next = vec![];
+        for pair in level.chunks(2) {
+            next.push(merkle_root(pair)?);
+        }
+        level = next;
+        index /= 2;
+    }
+
+    Ok(json!({ "merklePath": path }))
+}
+
+fn hash_lock_json(hash: &str, lock: &MockLock) -> Result<Value> {
+    Ok(json!({
+        "lock": {
+            "account": lock.signer,
+            "accountAddress": encoded_address(&lock.owner)?,
+            "mosaicId": uint64_json(lock.mosaic_id),
+            "amount": uint64_json(lock.amount),
+            "height": uint64_json(lock.expiration_height),
+            "status": if lock.used { 1 } else { 0 },
+            "hash": hash,
+        }
+    }))
+}
+
+pub(crate) fn now_blockchain_timestamp() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+
+    now.saturating_sub(TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+}
@@ -0,0 +1,15 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+//! An in-process Sirius node for integration tests, enabled with the `mock-node` feature.
+
+pub use self::node::*;
+
+mod ledger;
+mod node;
+mod payload;
+mod rest;
+mod ws;
@@ -0,0 +1,282 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::BTreeSet,
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    },
+    tokio::{
+        net::{TcpListener, TcpStream},
+        sync::broadcast,
+    },
+};
+
+use crate::{
+    account::{Account, Address},
+    models::Result,
+    mosaic::Mosaic,
+    network::{NetworkType, PUBLIC_TEST},
+    transaction::Hash,
+    AssetId,
+};
+
+use super::{
+    ledger::{Ledger, MockEvent},
+    rest,
+    ws::{self, Sessions, WsControl},
+};
+
+const DEFAULT_GENERATION_HASH: &str =
+    "56D112C98F7A7E34D1AEDC4BD01BC06CA2276DD546A93E36690B785E82439CA9";
+const EVENTS_CAPACITY: usize = 1024;
+const CONTROL_CAPACITY: usize = 16;
+
+/// Settings of a [MockNode].
+#[derive(Debug, Clone)]
+pub struct MockNodeConfig {
+    /// The network type reported by the nemesis block.
+    pub network_type: NetworkType,
+    /// The generation hash used to compute transaction hashes.
+    pub generation_hash: Hash,
+    /// Public key of the account harvesting every block.
+    pub harvester: String,
+    /// Produce a block on this interval. `None` means blocks are only produced by
+    /// calling [MockNode::harvest].
+    pub block_interval: Option<Duration>,
+    /// Reject transfers whose signer has not enough balance in the ledger.
+    pub enforce_balances: bool,
+}
+
+impl Default for MockNodeConfig {
+    fn default() -> Self {
+        MockNodeConfig {
+            network_type: PUBLIC_TEST,
+            generation_hash: DEFAULT_GENERATION_HASH.to_string(),
+            harvester: Account::new(PUBLIC_TEST).public_key_string(),
+            block_interval: None,
+            enforce_balances: false,
+        }
+    }
+}
+
+/// A local Sirius node backed by an in-memory ledger.
+///
+/// Serves the REST routes used by [crate::api::SiriusClient] and the `/ws` protocol used by
+/// [crate::websocket::SiriusWebsocketClient] on a loopback port. Announced payloads are
+/// decoded, validated and kept unconfirmed until the next [MockNode::harvest] (or the
+/// configured block interval), which confirms them and pushes the matching websocket events.
+///
+/// Transfer, hash lock and aggregate (complete and bonded) transactions are supported.
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::{api::SiriusClient, mock::MockNode};
+///
+///#[tokio::main]
+///async fn main() {
+///    let node = MockNode::start().await.unwrap();
+///
+///    let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+///
+///    node.harvest();
+///
+///    let height = client.chain_api().get_blockchain_height().await.unwrap();
+///    assert_eq!(*height.height, 2);
+///}
+/// ```
+#[derive(Clone)]
+pub struct MockNode {
+    url: &'static str,
+    ledger: Arc<Mutex<Ledger>>,
+    sessions: Sessions,
+    control: broadcast::Sender<WsControl>,
+    refuse_websockets: Arc<AtomicBool>,
+}
+
+impl MockNode {
+    /// Starts a mock node with the default [MockNodeConfig].
+    pub async fn start() -> Result<Self> {
+        Self::start_with(MockNodeConfig::default()).await
+    }
+
+    /// Starts a mock node listening on a random loopback port.
+    pub async fn start_with(config: MockNodeConfig) -> Result<Self> {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await?;
+        let local_addr: SocketAddr = listener.local_addr()?;
+
+        let (events, _) = broadcast::channel::<MockEvent>(EVENTS_CAPACITY);
+        let (control, _) = broadcast::channel::<WsControl>(CONTROL_CAPACITY);
+
+        let block_interval = config.block_interval;
+        let ledger = Arc::new(Mutex::new(Ledger::new(config, events.clone())));
+
+        // The api client keeps a `&'static str`; mock nodes live for the whole test run.
+        let url: &'static str = Box::leak(format!("http://{}", local_addr).into_boxed_str());
+
+        let node = MockNode {
+            url,
+            ledger,
+            sessions: Sessions::default(),
+            control,
+            refuse_websockets: Arc::new(AtomicBool::new(false)),
+        };
+
+        let accept_node = node.clone();
+        tokio::spawn(async move {
+            let mut connections: u64 = 0;
+            while let Ok((stream, _)) = listener.accept().await {
+                connections += 1;
+                let node = accept_node.clone();
+                let events = events.subscribe();
+                let uid = format!("{:024X}", connections);
+                tokio::spawn(async move {
+                    let _ = handle_connection(stream, node, events, uid).await;
+                });
+            }
+        });
+
+        if let Some(interval) = block_interval {
+            let harvester = node.clone();
+            tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(interval);
+                ticker.tick().await;
+                loop {
+                    ticker.tick().await;
+                    harvester.harvest();
+                }
+            });
+        }
+
+        Ok(node)
+    }
+
+    /// The base url to hand to [crate::api::SiriusClient::new] and
+    /// [crate::websocket::SiriusWebsocketClient::new].
+    pub fn url(&self) -> &'static str {
+        self.url
+    }
+
+    pub fn generation_hash(&self) -> Hash {
+        self.lock().config.generation_hash.clone()
+    }
+
+    pub fn network_type(&self) -> NetworkType {
+        self.lock().config.network_type
+    }
+
+    /// The current chain height.
+    pub fn height(&self) -> u64 {
+        self.lock().height()
+    }
+
+    /// Confirms every unconfirmed transaction in a new block and returns its height.
+    pub fn harvest(&self) -> u64 {
+        self.lock().harvest()
+    }
+
+    /// Replaces the last `depth` blocks with blocks of the same transactions but other hashes,
+    /// announced again on the `block` channel, as a fork of the chain would; returns the
+    /// height, which is unchanged.
+    pub fn fork(&self, depth: u64) -> u64 {
+        self.lock().fork(depth)
+    }
+
+    /// Makes the block at `height` no longer follow the block before it, while both keep their
+    /// hash, as a node serving a corrupted chain would.
+    pub fn break_link(&self, height: u64) {
+        self.lock().break_link(height)
+    }
+
+    /// Adds `mosaic` to the balance of `address`.
+    pub fn credit(&self, address: &Address, mosaic: Mosaic) {
+        self.lock().credit(
+            &address.address,
+            mosaic.asset_id.to_u64(),
+            mosaic.amount.as_u64(),
+        )
+    }
+
+    /// Returns the ledger balance of `address` for the given mosaic id.
+    pub fn balance(&self, address: &Address, mosaic_id: u64) -> u64 {
+        self.lock().balance(&address.address, mosaic_id)
+    }
+
+    /// The channels subscribed to by the open websocket connections, as the paths sent by
+    /// the clients, e.g. `confirmedAdded/VC4A3Z...`.
+    pub fn subscriptions(&self) -> Vec<String> {
+        let sessions = self.sessions.lock().expect("mock sessions poisoned");
+
+        sessions
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Drops every open websocket connection, as a restart of the node or a network failure
+    /// would.
+    pub fn disconnect_websockets(&self) {
+        // no open connection is nothing to drop.
+        let _ = self.control.send(WsControl::Disconnect);
+    }
+
+    /// Stops reading from and writing to the open websocket connections without closing them,
+    /// as a connection silently dropped by a proxy; pings are no longer answered.
+    pub fn stall_websockets(&self) {
+        let _ = self.control.send(WsControl::Stall);
+    }
+
+    /// Fails the handshake of every new websocket connection while `refuse` is set, e.g. to
+    /// exhaust the reconnect policy of a client.
+    pub fn refuse_websockets(&self, refuse: bool) {
+        self.refuse_websockets.store(refuse, Ordering::SeqCst);
+    }
+
+    /// Makes the next announced transaction fail with the given node status,
+    /// e.g. `Failure_Core_Insufficient_Balance`.
+    pub fn fail_next(&self, status: &str) {
+        self.lock().fail_next(status)
+    }
+
+    fn lock(&self) -> ::std::sync::MutexGuard<Ledger> {
+        self.ledger.lock().expect("mock ledger poisoned")
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    node: MockNode,
+    events: broadcast::Receiver<MockEvent>,
+    uid: String,
+) -> Result<()> {
+    let mut head = [0u8; 16];
+    let read = stream.peek(&mut head).await?;
+
+    if head[..read].starts_with(b"GET /ws") {
+        if node.refuse_websockets.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let control = node.control.subscribe();
+        let result = ws::serve(stream, uid.clone(), events, control, node.sessions.clone()).await;
+
+        if let Ok(mut sessions) = node.sessions.lock() {
+            sessions.remove(&uid);
+        }
+        result
+    } else {
+        rest::serve(stream, node.ledger.clone()).await
+    }
+}
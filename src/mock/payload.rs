@@ -0,0 +1,314 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    byteorder::{ByteOrder, LittleEndian},
+    serde_json::{json, Value},
+};
+
+use crate::{
+    account::Address,
+    models::{
+        consts::{
+            ADDRESS_SIZE, HASH256, KEY_SIZE, SIGNATURE_SIZE, SIZE_SIZE, TRANSACTION_HEADER_SIZE,
+            TYPE_SIZE, VERSION_SIZE,
+        },
+        errors_const::{ERR_INVALID_DATA_LENGTH, ERR_UNKNOWN_BLOCKCHAIN_TYPE},
+        network::NetworkType,
+        Result,
+    },
+    transaction::{EntityTypeEnum, Hash},
+};
+
+const EMBEDDED_HEADER_SIZE: usize = SIZE_SIZE + KEY_SIZE + VERSION_SIZE + TYPE_SIZE;
+
+/// A transaction payload decoded into the json shape served by a Sirius REST node.
+#[derive(Debug, Clone)]
+pub(crate) struct DecodedPayload {
+    pub signer: String,
+    pub entity_type: EntityTypeEnum,
+    pub deadline: u64,
+    pub max_fee: u64,
+    /// Every address touched by the transaction (signers and recipients).
+    pub addresses: Vec<String>,
+    /// Inner transaction signers that still have to cosign, for aggregate transactions.
+    pub cosigners: Vec<String>,
+    /// Hash referenced by a hash lock transaction.
+    pub lock_hash: Option<Hash>,
+    /// Number of blocks a hash lock stays active.
+    pub lock_duration: u64,
+    /// Balance movements applied when the transaction gets confirmed.
+    pub transfers: Vec<BalanceMovement>,
+    /// Number of leading payload bytes covered by the transaction hash; trailing
+    /// aggregate cosignatures are excluded.
+    pub hashed_size: usize,
+    pub json: Value,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BalanceMovement {
+    pub from: String,
+    pub to: Option<String>,
+    pub mosaic_id: u64,
+    pub amount: u64,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, offset: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.offset + len > self.bytes.len() {
+            return Err(ERR_INVALID_DATA_LENGTH.into());
+        }
+        let slice = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(LittleEndian::read_u16(self.take(2)?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(LittleEndian::read_u32(self.take(4)?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(LittleEndian::read_u64(self.take(8)?))
+    }
+
+    fn hex(&mut self, len: usize) -> Result<String> {
+        Ok(hex::encode_upper(self.take(len)?))
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+}
+
+/// Converts a u64 into the `[lower, higher]` pair used by the REST json.
+pub(crate) fn uint64_json(value: u64) -> Value {
+    json!([value as u32, (value >> 32) as u32])
+}
+
+pub(crate) fn decode_payload(bytes: &[u8], network_type: NetworkType) -> Result<DecodedPayload> {
+    if bytes.len() < TRANSACTION_HEADER_SIZE {
+        return Err(ERR_INVALID_DATA_LENGTH.into());
+    }
+
+    let mut reader = Reader::new(bytes);
+    let size = reader.u32()? as usize;
+    if size != bytes.len() {
+        return Err(ERR_INVALID_DATA_LENGTH.into());
+    }
+
+    let signature = reader.hex(SIGNATURE_SIZE)?;
+    let signer = reader.hex(KEY_SIZE)?;
+    let version = reader.u32()?;
+    let entity_type = reader.u16()?;
+    let max_fee = reader.u64()?;
+    let deadline = reader.u64()?;
+
+    let mut json = json!({
+        "signature": signature,
+        "signer": signer,
+        "version": version,
+        "type": entity_type,
+        "maxFee": uint64_json(max_fee),
+        "deadline": uint64_json(deadline),
+    });
+
+    let mut decoded = DecodedPayload {
+        signer: signer.clone(),
+        entity_type: EntityTypeEnum::from(entity_type),
+        deadline,
+        max_fee,
+        addresses: vec![address_of(&signer, network_type)?],
+        cosigners: vec![],
+        lock_hash: None,
+        lock_duration: 0,
+        transfers: vec![],
+        hashed_size: bytes.len(),
+        json: Value::Null,
+    };
+
+    match decoded.entity_type {
+        EntityTypeEnum::AggregateComplete | EntityTypeEnum::AggregateBonded => {
+            decode_aggregate(&mut reader, &mut json, &mut decoded, network_type)?
+        }
+        _ => decode_body(&mut reader, &mut json, &mut decoded, network_type)?,
+    }
+
+    decoded.addresses.sort();
+    decoded.addresses.dedup();
+    decoded.json = json;
+
+    Ok(decoded)
+}
+
+fn decode_body(
+    reader: &mut Reader,
+    json: &mut Value,
+    decoded: &mut DecodedPayload,
+    network_type: NetworkType,
+) -> Result<()> {
+    let signer_address = address_of(&decoded.signer, network_type)?;
+
+    match EntityTypeEnum::from(json["type"].as_u64().unwrap_or_default() as u16) {
+        EntityTypeEnum::Transfer => {
+            let recipient = reader.hex(ADDRESS_SIZE)?;
+            let message_size = reader.u16()? as usize;
+            let num_mosaics = reader.u8()? as usize;
+
+            let (message_type, payload) = if message_size > 0 {
+                (reader.u8()?, reader.hex(message_size - 1)?)
+            } else {
+                (0, String::new())
+            };
+
+            let recipient_address = Address::from_encoded(&recipient)?.address;
+
+            let mut mosaics = Vec::with_capacity(num_mosaics);
+            for _ in 0..num_mosaics {
+                let id = reader.u64()?;
+                let amount = reader.u64()?;
+
+                decoded.transfers.push(BalanceMovement {
+                    from: signer_address.clone(),
+                    to: Some(recipient_address.clone()),
+                    mosaic_id: id,
+                    amount,
+                });
+
+                mosaics.push(json!({ "id": uint64_json(id), "amount": uint64_json(amount) }));
+            }
+
+            json["recipient"] = json!(recipient);
+            json["message"] = json!({ "type": message_type, "payload": payload });
+            json["mosaics"] = Value::Array(mosaics);
+
+            decoded.addresses.push(recipient_address);
+        }
+        EntityTypeEnum::Lock => {
+            let mosaic_id = reader.u64()?;
+            let amount = reader.u64()?;
+            let duration = reader.u64()?;
+            let hash = reader.hex(HASH256)?;
+
+            decoded.transfers.push(BalanceMovement {
+                from: signer_address,
+                to: None,
+                mosaic_id,
+                amount,
+            });
+            decoded.lock_hash = Some(hash.clone());
+            decoded.lock_duration = duration;
+
+            json["mosaicId"] = uint64_json(mosaic_id);
+            json["amount"] = uint64_json(amount);
+            json["duration"] = uint64_json(duration);
+            json["hash"] = json!(hash);
+        }
+        _ => return Err(ERR_UNKNOWN_BLOCKCHAIN_TYPE.into()),
+    }
+
+    Ok(())
+}
+
+fn decode_aggregate(
+    reader: &mut Reader,
+    json: &mut Value,
+    decoded: &mut DecodedPayload,
+    network_type: NetworkType,
+) -> Result<()> {
+    let transactions_size = reader.u32()? as usize;
+    let inner_bytes = reader.take(transactions_size)?;
+    decoded.hashed_size = reader.offset;
+
+    let mut inner = Reader::new(inner_bytes);
+    let mut transactions = vec![];
+
+    while inner.remaining() > 0 {
+        let size = inner.u32()? as usize;
+        if size < EMBEDDED_HEADER_SIZE {
+            return Err(ERR_INVALID_DATA_LENGTH.into());
+        }
+
+        let signer = inner.hex(KEY_SIZE)?;
+        let version = inner.u32()?;
+        let entity_type = inner.u16()?;
+        let body = inner.take(size - EMBEDDED_HEADER_SIZE)?;
+
+        let mut inner_json = json!({
+            "signer": signer,
+            "version": version,
+            "type": entity_type,
+        });
+
+        let mut inner_decoded = DecodedPayload {
+            signer: signer.clone(),
+            entity_type: EntityTypeEnum::from(entity_type),
+            deadline: decoded.deadline,
+            max_fee: 0,
+            addresses: vec![address_of(&signer, network_type)?],
+            cosigners: vec![],
+            lock_hash: None,
+            lock_duration: 0,
+            transfers: vec![],
+            hashed_size: size,
+            json: Value::Null,
+        };
+
+        decode_body(
+            &mut Reader::new(body),
+            &mut inner_json,
+            &mut inner_decoded,
+            network_type,
+        )?;
+
+        if signer != decoded.signer && !decoded.cosigners.contains(&signer) {
+            decoded.cosigners.push(signer);
+        }
+        decoded.addresses.append(&mut inner_decoded.addresses);
+        decoded.transfers.append(&mut inner_decoded.transfers);
+
+        transactions.push(json!({ "meta": {}, "transaction": inner_json }));
+    }
+
+    let mut cosignatures = vec![];
+    while reader.remaining() >= KEY_SIZE + SIGNATURE_SIZE {
+        let signer = reader.hex(KEY_SIZE)?;
+        let signature = reader.hex(SIGNATURE_SIZE)?;
+
+        decoded.cosigners.retain(|cosigner| cosigner != &signer);
+        cosignatures.push(json!({ "signer": signer, "signature": signature }));
+    }
+
+    json["transactions"] = Value::Array(transactions);
+    json["cosignatures"] = Value::Array(cosignatures);
+
+    Ok(())
+}
+
+pub(crate) fn address_of(public_key: &str, network_type: NetworkType) -> Result<String> {
+    Ok(Address::from_public_key(public_key, network_type)?.address)
+}
+
+pub(crate) fn encoded_address(address: &str) -> Result<String> {
+    let address = Address::from_raw(address)?;
+    Ok(hex::encode_upper(address.to_decode()))
+}
@@ -0,0 +1,434 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    serde_json::{json, Value},
+    tokio::{io::AsyncReadExt, io::AsyncWriteExt, net::TcpStream},
+};
+
+use crate::{
+    models::Result,
+    network::{NetworkType, MIJIN, MIJIN_TEST, PRIVATE, PRIVATE_TEST, PUBLIC},
+};
+
+use super::{
+    ledger::{AccountListing, AnnounceRoute, Ledger},
+    payload::uint64_json,
+};
+
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+const DEFAULT_PAGE_SIZE: usize = 10;
+const MAX_PAGE_SIZE: usize = 100;
+
+pub(crate) struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+struct HttpResponse {
+    status: u16,
+    body: Value,
+}
+
+impl HttpResponse {
+    fn ok(body: Value) -> Self {
+        HttpResponse { status: 200, body }
+    }
+
+    fn accepted(body: Value) -> Self {
+        HttpResponse { status: 202, body }
+    }
+
+    fn not_found(message: &str) -> Self {
+        HttpResponse {
+            status: 404,
+            body: json!({ "code": "ResourceNotFound", "message": message }),
+        }
+    }
+
+    fn invalid(message: &str) -> Self {
+        HttpResponse {
+            status: 409,
+            body: json!({ "code": "InvalidArgument", "message": message }),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            404 => "Not Found",
+            _ => "Conflict",
+        }
+    }
+}
+
+pub(crate) async fn read_request(stream: &mut TcpStream) -> Result<Option<HttpRequest>> {
+    let mut buffer: Vec<u8> = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+
+    let head_end = loop {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+
+        if let Some(position) = find_head_end(&buffer) {
+            break position;
+        }
+        if buffer.len() > MAX_HEAD_SIZE {
+            return Ok(None);
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_uppercase();
+    let target = request_line.next().unwrap_or_default().to_string();
+
+    let content_length = lines
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some(name), Some(value)) if name.trim().eq_ignore_ascii_case("content-length") => {
+                    value.trim().parse::<usize>().ok()
+                }
+                _ => None,
+            }
+        })
+        .next()
+        .unwrap_or_default();
+
+    let mut body = buffer[head_end + 4..].to_vec();
+    while body.len() < content_length {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    let mut parts = target.splitn(2, '?');
+    let path = parts.next().unwrap_or_default().to_string();
+    let query = parts
+        .next()
+        .map(|query| {
+            query
+                .split('&')
+                .filter_map(|pair| {
+                    let mut kv = pair.splitn(2, '=');
+                    Some((
+                        kv.next()?.to_string(),
+                        kv.next().unwrap_or_default().to_string(),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(Some(HttpRequest {
+        method,
+        path,
+        query,
+        body,
+    }))
+}
+
+pub(crate) async fn serve(mut stream: TcpStream, ledger: Arc<Mutex<Ledger>>) -> Result<()> {
+    let request = match read_request(&mut stream).await? {
+        Some(request) => request,
+        None => return Ok(()),
+    };
+
+    let response = {
+        let mut ledger = ledger.lock().expect("mock ledger poisoned");
+        route(&request, &mut ledger)
+    };
+
+    let body = serde_json::to_vec(&response.body)?;
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.reason(),
+        body.len()
+    );
+
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.flush().await?;
+
+    Ok(())
+}
+
+fn route(request: &HttpRequest, ledger: &mut Ledger) -> HttpResponse {
+    let segments: Vec<&str> = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    let body: Value = serde_json::from_slice(&request.body).unwrap_or(Value::Null);
+
+    match (request.method.as_str(), segments.as_slice()) {
+        // Chain routes.
+        ("GET", ["chain", "height"]) => {
+            HttpResponse::ok(json!({ "height": uint64_json(ledger.height()) }))
+        }
+        ("GET", ["chain", "score"]) => HttpResponse::ok(json!({
+            "scoreHigh": uint64_json(0),
+            "scoreLow": uint64_json(ledger.height()),
+        })),
+        ("GET", ["diagnostic", "storage"]) => HttpResponse::ok(json!({
+            "numBlocks": ledger.height(),
+            "numTransactions": 0,
+            "numAccounts": 0,
+        })),
+
+        // Block routes.
+        ("GET", ["block", height]) => match parse_height(height).and_then(|h| ledger.block(h)) {
+            Some(block) => HttpResponse::ok(block.json.clone()),
+            None => HttpResponse::not_found("no resource exists with id"),
+        },
+        ("GET", ["block", height, "transactions"]) => {
+            let page_size = page_size(request);
+            let id = request.query.get("id").map(String::as_str);
+            match parse_height(height).and_then(|h| ledger.block_transactions(h, page_size, id)) {
+                Some(transactions) => HttpResponse::ok(Value::Array(transactions)),
+                None => HttpResponse::not_found("no resource exists with id"),
+            }
+        }
+        ("GET", ["block", height, "receipts"]) => {
+            match parse_height(height).and_then(|h| ledger.block(h)) {
+                Some(_) => HttpResponse::ok(json!({
+                    "transactionStatements": [],
+                    "addressResolutionStatements": [],
+                    "mosaicResolutionStatements": [],
+                })),
+                None => HttpResponse::not_found("no resource exists with id"),
+            }
+        }
+        ("GET", ["block", height, "transaction", hash, "merkle"]) => {
+            match parse_height(height).and_then(|h| ledger.transaction_merkle_path(h, hash)) {
+                Some(Ok(path)) => HttpResponse::ok(path),
+                Some(Err(err)) => HttpResponse::invalid(&err.to_string()),
+                None => HttpResponse::not_found("no resource exists with id"),
+            }
+        }
+        ("GET", ["blocks", height, "limit", limit]) => {
+            match (parse_height(height), limit.parse::<usize>().ok()) {
+                (Some(height), Some(limit)) => {
+                    HttpResponse::ok(Value::Array(ledger.blocks(height, limit)))
+                }
+                _ => HttpResponse::invalid("height and limit must be numbers"),
+            }
+        }
+
+        // Node routes.
+        ("GET", ["node", "info"]) => HttpResponse::ok(json!({
+            "publicKey": ledger.config.harvester,
+            "port": 7900,
+            "networkIdentifier": *ledger.config.network_type,
+            "version": 0,
+            "roles": 2,
+            "host": "127.0.0.1",
+            "friendlyName": "mock-node",
+        })),
+        ("GET", ["node", "peers"]) => HttpResponse::ok(json!([])),
+        ("GET", ["diagnostic", "server"]) => HttpResponse::ok(json!({
+            "serverInfo": { "restVersion": "mock", "sdkVersion": "mock" }
+        })),
+        ("GET", ["network"]) => HttpResponse::ok(json!({
+            "name": network_name(ledger.config.network_type),
+            "description": "mock network",
+        })),
+        ("GET", ["node", "time"]) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default();
+            HttpResponse::ok(json!({
+                "communicationTimestamps": {
+                    "sendTimestamp": uint64_json(now),
+                    "receiveTimestamp": uint64_json(now),
+                }
+            }))
+        }
+
+        // Transaction routes.
+        ("PUT", ["transaction"]) => announce(ledger, &body, AnnounceRoute::Transaction),
+        ("PUT", ["transaction", "partial"]) => announce(ledger, &body, AnnounceRoute::Partial),
+        ("PUT", ["transaction", "cosignature"]) => {
+            let field = |name: &str| body[name].as_str().unwrap_or_default().to_string();
+            match ledger.cosign(&field("parentHash"), &field("signature"), &field("signer")) {
+                Ok(()) => HttpResponse::accepted(json!({
+                    "message": "packet 501 was pushed to the network via /transaction/cosignature"
+                })),
+                Err(err) => HttpResponse::invalid(&err.to_string()),
+            }
+        }
+        ("GET", ["transaction", hash, "status"]) => match ledger.status(hash) {
+            Some(status) => HttpResponse::ok(status),
+            None => HttpResponse::not_found("no resource exists with id"),
+        },
+        ("POST", ["transaction", "statuses"]) => {
+            let statuses = string_array(&body["hashes"])
+                .iter()
+                .filter_map(|hash| ledger.status(hash))
+                .collect();
+            HttpResponse::ok(Value::Array(statuses))
+        }
+        ("GET", ["transaction", id]) => match ledger.transaction(id) {
+            Some(transaction) => HttpResponse::ok(transaction),
+            None => HttpResponse::not_found("no resource exists with id"),
+        },
+        ("POST", ["transaction"]) => {
+            let transactions = string_array(&body["transactionIds"])
+                .iter()
+                .filter_map(|id| ledger.transaction(id))
+                .collect();
+            HttpResponse::ok(Value::Array(transactions))
+        }
+
+        // Account routes.
+        ("GET", ["account", account_id]) => match ledger.account_info(account_id) {
+            Ok(Some(info)) => HttpResponse::ok(info),
+            Ok(None) => HttpResponse::not_found("no resource exists with id"),
+            Err(err) => HttpResponse::invalid(&err.to_string()),
+        },
+        ("POST", ["account"]) => {
+            let ids = string_array(&body["publicKeys"])
+                .into_iter()
+                .chain(string_array(&body["addresses"]));
+            let accounts = ids
+                .filter_map(|id| ledger.account_info(&id).ok().and_then(|info| info))
+                .collect();
+            HttpResponse::ok(Value::Array(accounts))
+        }
+        ("GET", ["account", public_key, "transactions"]) => {
+            account_transactions(request, ledger, public_key, AccountListing::All)
+        }
+        ("GET", ["account", public_key, "transactions", listing]) => {
+            let listing = match *listing {
+                "incoming" => AccountListing::Incoming,
+                "outgoing" => AccountListing::Outgoing,
+                "unconfirmed" => AccountListing::Unconfirmed,
+                "partial" => AccountListing::Partial,
+                _ => return HttpResponse::not_found("unknown transaction listing"),
+            };
+            account_transactions(request, ledger, public_key, listing)
+        }
+        ("POST", ["account", "names"])
+        | ("POST", ["account", "properties"])
+        | ("POST", ["account", "namespaces"])
+        | ("POST", ["mosaic"])
+        | ("POST", ["mosaic", "names"])
+        | ("POST", ["namespace", "names"]) => HttpResponse::ok(json!([])),
+        ("GET", ["account", _, "namespaces"]) | ("GET", ["account", _, "exchange"]) => {
+            HttpResponse::ok(json!([]))
+        }
+        ("GET", ["exchange", _, _]) => HttpResponse::ok(json!([])),
+
+        // Lock routes.
+        ("GET", ["account", account_id, "lock", "hash"]) => match ledger.hash_locks(account_id) {
+            Ok(locks) => HttpResponse::ok(Value::Array(locks)),
+            Err(err) => HttpResponse::invalid(&err.to_string()),
+        },
+        ("GET", ["lock", "hash", hash]) => match ledger.hash_lock(hash) {
+            Some(lock) => HttpResponse::ok(lock),
+            None => HttpResponse::not_found("no resource exists with id"),
+        },
+        ("GET", ["account", _, "lock", "secret"]) | ("GET", ["lock", "secret", _]) => {
+            HttpResponse::ok(json!([]))
+        }
+
+        _ => HttpResponse::not_found("no resource exists with id"),
+    }
+}
+
+fn announce(ledger: &mut Ledger, body: &Value, route: AnnounceRoute) -> HttpResponse {
+    let payload = match body["payload"].as_str() {
+        Some(payload) => payload,
+        None => return HttpResponse::invalid("payload is required"),
+    };
+
+    let path = match route {
+        AnnounceRoute::Transaction => "/transaction",
+        AnnounceRoute::Partial => "/transaction/partial",
+    };
+
+    match ledger.announce(payload, route) {
+        Ok(_) => HttpResponse::accepted(json!({
+            "message": format!("packet 9 was pushed to the network via {}", path)
+        })),
+        Err(err) => HttpResponse::invalid(&err.to_string()),
+    }
+}
+
+fn account_transactions(
+    request: &HttpRequest,
+    ledger: &Ledger,
+    public_key: &str,
+    listing: AccountListing,
+) -> HttpResponse {
+    let id = request.query.get("id").map(String::as_str);
+    let ordering = request.query.get("ordering").map(String::as_str);
+
+    match ledger.account_transactions(public_key, listing, page_size(request), id, ordering) {
+        Ok(transactions) => HttpResponse::ok(Value::Array(transactions)),
+        Err(err) => HttpResponse::invalid(&err.to_string()),
+    }
+}
+
+fn page_size(request: &HttpRequest) -> usize {
+    request
+        .query
+        .get("pageSize")
+        .and_then(|size| size.parse::<usize>().ok())
+        .map(|size| size.max(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE))
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+}
+
+fn network_name(network_type: NetworkType) -> &'static str {
+    match network_type {
+        MIJIN => "mijin",
+        MIJIN_TEST => "mijinTest",
+        PUBLIC => "public",
+        PRIVATE => "private",
+        PRIVATE_TEST => "privateTest",
+        _ => "publicTest",
+    }
+}
+
+fn parse_height(height: &str) -> Option<u64> {
+    height.parse::<u64>().ok()
+}
+
+fn string_array(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn find_head_end(buffer: &[u8]) -> Option<usize> {
+    buffer.windows(4).position(|window| window == b"\r\n\r\n")
+}
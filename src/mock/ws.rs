@@ -0,0 +1,149 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::{HashMap, HashSet},
+        sync::{Arc, Mutex},
+    },
+    futures_util::{SinkExt, StreamExt},
+    serde_json::{json, Value},
+    tokio::{net::TcpStream, sync::broadcast},
+    tokio_tungstenite::{accept_async, tungstenite::Message},
+};
+
+use crate::models::Result;
+
+use super::ledger::MockEvent;
+
+/// The subscriptions of every open connection, by `uid`.
+pub(crate) type Sessions = Arc<Mutex<HashMap<String, HashSet<String>>>>;
+
+/// Failures injected into the open connections by the [MockNode].
+///
+/// [MockNode]: struct.MockNode.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum WsControl {
+    /// Drops the connection without a closing handshake.
+    Disconnect,
+    /// Stops reading and writing, so pings are not answered, until a `Disconnect`.
+    Stall,
+}
+
+/// Serves one `/ws` connection using the Sirius subscription protocol.
+///
+/// The server greets with `{"uid": ...}` and then accepts
+/// `{"uid": ..., "subscribe": "<channel>[/<address>]"}` and
+/// `{"uid": ..., "unsubscribe": "<channel>[/<address>]"}` messages, publishing the
+/// subscriptions of the connection to `sessions`.
+pub(crate) async fn serve(
+    stream: TcpStream,
+    uid: String,
+    mut events: broadcast::Receiver<MockEvent>,
+    mut control: broadcast::Receiver<WsControl>,
+    sessions: Sessions,
+) -> Result<()> {
+    let ws = accept_async(stream).await?;
+    let (mut sink, mut source) = ws.split();
+
+    sink.send(Message::text(json!({ "uid": uid }).to_string()))
+        .await?;
+
+    let mut subscriptions: HashSet<String> = HashSet::new();
+
+    loop {
+        tokio::select! {
+            incoming = source.next() => {
+                let msg = match incoming {
+                    Some(msg) => msg?,
+                    None => break,
+                };
+
+                if msg.is_close() {
+                    break;
+                }
+                if msg.is_ping() {
+                    sink.send(Message::Pong(msg.into_data())).await?;
+                    continue;
+                }
+                if !msg.is_text() {
+                    continue;
+                }
+
+                let request: Value = match serde_json::from_str(&msg.to_string()) {
+                    Ok(request) => request,
+                    Err(_) => continue,
+                };
+
+                if request["uid"].as_str() != Some(uid.as_str()) {
+                    continue;
+                }
+
+                if let Some(path) = request["subscribe"].as_str() {
+                    subscriptions.insert(path.to_string());
+                } else if let Some(path) = request["unsubscribe"].as_str() {
+                    subscriptions.remove(path);
+                }
+
+                if let Ok(mut sessions) = sessions.lock() {
+                    sessions.insert(uid.clone(), subscriptions.clone());
+                }
+            }
+            event = events.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(broadcast::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::RecvError::Closed) => break,
+                };
+
+                for message in deliveries(&event, &subscriptions) {
+                    sink.send(Message::text(message.to_string())).await?;
+                }
+            }
+            request = control.recv() => {
+                match request {
+                    Ok(WsControl::Disconnect) | Err(broadcast::RecvError::Closed) => break,
+                    Ok(WsControl::Stall) => return stall(&mut control).await,
+                    Err(broadcast::RecvError::Lagged(_)) => continue,
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Holds a connection open without reading from it until it is disconnected.
+async fn stall(control: &mut broadcast::Receiver<WsControl>) -> Result<()> {
+    loop {
+        match control.recv().await {
+            Ok(WsControl::Disconnect) | Err(broadcast::RecvError::Closed) => return Ok(()),
+            Ok(WsControl::Stall) | Err(broadcast::RecvError::Lagged(_)) => continue,
+        }
+    }
+}
+
+/// Returns one message per matching subscription, with `meta.address` filled in.
+fn deliveries(event: &MockEvent, subscriptions: &HashSet<String>) -> Vec<Value> {
+    if event.addresses.is_empty() {
+        return if subscriptions.contains(event.channel) {
+            vec![event.message.clone()]
+        } else {
+            vec![]
+        };
+    }
+
+    event
+        .addresses
+        .iter()
+        .filter(|address| subscriptions.contains(&format!("{}/{}", event.channel, address)))
+        .map(|address| {
+            let mut message = event.message.clone();
+            message["meta"]["address"] = json!(address);
+            message
+        })
+        .collect()
+}
@@ -19,7 +19,7 @@ use crate::{
         },
     },
     utils::{is_hex, vec_u8_to_hex},
-    Result,
+    Error, Result,
 };
 
 use super::{Address, PublicAccount};
@@ -76,19 +76,23 @@ impl Account {
     pub fn from_private_key(private_key: &str, network_type: NetworkType) -> Result<Self> {
         ensure!(
             !private_key.is_empty(),
-            errors_const::ERR_INVALID_PRIVATE_KEY_LENGTH
+            "private_key" => errors_const::ERR_INVALID_PRIVATE_KEY_LENGTH
         );
 
         ensure!(
             private_key.len() == 64,
-            errors_const::ERR_INVALID_KEY_LENGTH
+            "private_key" => errors_const::ERR_INVALID_KEY_LENGTH
         );
 
-        ensure!(is_hex(private_key), errors_const::ERR_INVALID_KEY_HEX);
+        ensure!(
+            is_hex(private_key),
+            "private_key" => errors_const::ERR_INVALID_KEY_HEX
+        );
 
         let sk_hex = hex::decode(private_key)?;
 
-        let secret_key = SecretKey::from_bytes(&sk_hex)?;
+        let secret_key =
+            SecretKey::from_bytes(&sk_hex).map_err(|e| Error::crypto(e.to_string()))?;
 
         let key_pair = Keypair::from_private_key(secret_key);
 
@@ -116,7 +120,7 @@ impl Account {
     ) -> crate::Result<SignedTransaction> {
         ensure!(
             !generation_hash.is_empty(),
-            errors_const::ERR_EMPTY_GENERATION_HASH
+            "generation_hash" => errors_const::ERR_EMPTY_GENERATION_HASH
         );
 
         tx.sign_transaction_with(self.to_owned(), generation_hash.parse()?)
@@ -149,7 +153,7 @@ impl Account {
     ) -> crate::Result<SignedTransaction> {
         ensure!(
             !generation_hash.is_empty(),
-            errors_const::ERR_EMPTY_GENERATION_HASH
+            "generation_hash" => errors_const::ERR_EMPTY_GENERATION_HASH
         );
 
         tx.sign_with_cosignatories(self.to_owned(), cosignatories, generation_hash.parse()?)
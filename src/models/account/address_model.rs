@@ -40,12 +40,15 @@ impl Address {
     pub fn from_public_key(public_key: &str, network_type: NetworkType) -> Result<Self> {
         ensure!(
             !public_key.is_empty(),
-            errors_const::ERR_INVALID_PUBLIC_KEY_LENGTH
+            "public_key" => errors_const::ERR_INVALID_PUBLIC_KEY_LENGTH
         );
 
-        ensure!(is_hex(public_key), errors_const::ERR_INVALID_KEY_HEX);
+        ensure!(is_hex(public_key), "public_key" => errors_const::ERR_INVALID_KEY_HEX);
 
-        ensure!(public_key.len() == 64, errors_const::ERR_INVALID_KEY_LENGTH);
+        ensure!(
+            public_key.len() == 64,
+            "public_key" => errors_const::ERR_INVALID_KEY_LENGTH
+        );
 
         let address = super::public_key_to_address(public_key, network_type)?;
 
@@ -60,7 +63,10 @@ impl Address {
     /// A raw address string looks like:
     /// VAWOEOWTABXR7O3ZAK2XNA5GIBNE6PZIXDAFDWBU or VAWOEO-WTABXR-7O3ZAK-2XNA5G-IBNE6P-ZIXDAF-DWBU
     pub fn from_raw(raw_address: &str) -> Result<Self> {
-        ensure!(!raw_address.is_empty(), errors_const::ERR_EMPTY_ADDRESSES);
+        ensure!(
+            !raw_address.is_empty(),
+            "address" => errors_const::ERR_EMPTY_ADDRESSES
+        );
 
         let address = raw_address
             .trim()
@@ -68,7 +74,7 @@ impl Address {
             .replace(REGEX_DASH, EMPTY_STRING);
         ensure!(
             address.len() == ADDRESS_DECODE_SIZE,
-            errors_const::ERR_INVALID_ADDRESSES_LEN
+            "address" => errors_const::ERR_INVALID_ADDRESSES_LEN
         );
 
         match address.chars().next().unwrap() {
@@ -96,20 +102,26 @@ impl Address {
                 address,
                 network_type: PRIVATE_TEST,
             }),
-            _ => bail!("Wrong address"),
+            _ => bail!("address" => "Wrong address"),
         }
     }
 
     /// Create an `Address` from the given encoded address.
     pub fn from_encoded(encoded: &str) -> Result<Self> {
-        ensure!(!encoded.is_empty(), errors_const::ERR_EMPTY_ADDRESSES);
+        ensure!(
+            !encoded.is_empty(),
+            "encoded" => errors_const::ERR_EMPTY_ADDRESSES
+        );
 
         ensure!(
             encoded.len() == ADDRESS_ENCODE_SIZE,
-            errors_const::ERR_INVALID_ADDRESSES_LEN
+            "encoded" => errors_const::ERR_INVALID_ADDRESSES_LEN
         );
 
-        ensure!(is_hex(encoded), errors_const::ERR_INVALID_ADDRESSES_HEX);
+        ensure!(
+            is_hex(encoded),
+            "encoded" => errors_const::ERR_INVALID_ADDRESSES_HEX
+        );
 
         let encoded_to_bytes = hex::decode(encoded)?;
 
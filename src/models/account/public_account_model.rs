@@ -7,7 +7,7 @@
 use crate::{
     models::{consts::PUBLIC_KEY_BYTES_SIZE, errors_const},
     utils::is_hex,
-    Result,
+    Error, Result,
 };
 
 use super::Address;
@@ -30,12 +30,15 @@ impl PublicAccount {
     ) -> Result<PublicAccount> {
         ensure!(
             !public_key.is_empty(),
-            errors_const::ERR_INVALID_PUBLIC_KEY_LENGTH
+            "public_key" => errors_const::ERR_INVALID_PUBLIC_KEY_LENGTH
         );
 
-        ensure!(is_hex(public_key), errors_const::ERR_INVALID_KEY_HEX);
+        ensure!(is_hex(public_key), "public_key" => errors_const::ERR_INVALID_KEY_HEX);
 
-        ensure!(public_key.len() == 64, errors_const::ERR_INVALID_KEY_LENGTH);
+        ensure!(
+            public_key.len() == 64,
+            "public_key" => errors_const::ERR_INVALID_KEY_LENGTH
+        );
 
         Ok(PublicAccount {
             address: Address::from_public_key(public_key, network_type)?,
@@ -51,26 +54,26 @@ impl PublicAccount {
     pub fn verify_sign(&self, data: &str, signature: &str) -> Result<()> {
         ensure!(
             super::HASH512_LENGTH == (signature.len() / 2),
-            errors_const::ERR_INVALID_SIGNATURE_LENGTH
+            "signature" => errors_const::ERR_INVALID_SIGNATURE_LENGTH
         );
 
-        ensure!(is_hex(signature), errors_const::ERR_INVALID_SIGNATURE_HEX);
+        ensure!(
+            is_hex(signature),
+            "signature" => errors_const::ERR_INVALID_SIGNATURE_HEX
+        );
 
         let sig_byte: Vec<u8> = hex::decode(signature)?;
 
         let pk_byte: Vec<u8> = hex::decode(&self.public_key)?;
 
-        let pk = crypto::PublicKey::from_bytes(&pk_byte)?;
-
-        let signature = crypto::Signature::from_bytes(&sig_byte)?;
+        let pk =
+            crypto::PublicKey::from_bytes(&pk_byte).map_err(|e| Error::crypto(e.to_string()))?;
 
-        let verify = pk.verify(&data.as_bytes(), &signature);
+        let signature =
+            crypto::Signature::from_bytes(&sig_byte).map_err(|e| Error::crypto(e.to_string()))?;
 
-        if verify.is_ok() {
-            Ok(())
-        } else {
-            bail!(verify.unwrap_err())
-        }
+        pk.verify(&data.as_bytes(), &signature)
+            .map_err(|e| Error::crypto(e.to_string()))
     }
 
     /// Convert this public key to a byte array.
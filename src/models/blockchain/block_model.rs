@@ -11,7 +11,7 @@ use crate::models::{
     Uint64,
 };
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockInfo {
     /// The network type.
@@ -55,6 +55,9 @@ pub struct BlockInfo {
     /// The fee multiplier applied to transactions contained in block.
     pub fee_multiplier: i32,
 
+    /// The hash of the block.
+    pub hash: Hash,
+
     /// The generation hash.
     pub generation_hash: Hash,
 
@@ -7,15 +7,21 @@
 use {
     std::{
         borrow::Cow,
-        fmt::{Display, Formatter},
+        convert::Infallible,
+        fmt::{self, Display, Formatter},
+        num::ParseIntError,
         result,
+        time::Duration,
     },
     tokio_tungstenite::tungstenite::Error as WsError,
 };
 
-/// Result type of all Websocket library calls.
+use super::transaction::{Hash, TransactionStatus};
+
+/// Result type of all library calls.
 pub type Result<T> = result::Result<T, Error>;
 
+/// Error returned by the node REST API.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SiriusError {
@@ -23,65 +29,213 @@ pub struct SiriusError {
     pub message: String,
 }
 
+/// The error type of every public function of the SDK.
 #[derive(Debug)]
 pub enum Error {
-    Serde(serde_json::Error),
-    SiriusError(SiriusError),
-    Reqwest(reqwest::Error),
-    Tungsten(WsError),
-    Failure(failure::Error),
+    /// An input or model value is not valid; `field` names the offending input when known.
+    Validation {
+        field: Option<&'static str>,
+        message: Cow<'static, str>,
+    },
+    /// The node answered the request with an error.
+    Api(SiriusError),
+    /// The request could not be sent or the response could not be received.
+    Transport(reqwest::Error),
+    /// A response, websocket message or payload could not be decoded.
+    Decode(Cow<'static, str>),
+    /// The websocket connection failed.
+    Websocket(WsError),
+    /// A key or signature could not be built or verified.
+    Crypto(Cow<'static, str>),
+    /// The node url is not valid.
     Url(Cow<'static, str>),
+    Io(::std::io::Error),
+    /// The node rejected the transaction with the given status.
+    TransactionFailed(TransactionStatus),
+    /// The transaction with the given hash was not confirmed in time.
+    Timeout(Hash),
+    /// Nothing was received on the websocket connection for the given time.
+    Stale(Duration),
+    /// A fork replaced every block hash kept by a [crate::sync::ChainSync]; the height is the
+    /// oldest of them.
+    ForkTooDeep(u64),
+    /// The SDK reached a state it should never be in, e.g. a poisoned lock.
+    Internal(Cow<'static, str>),
+}
+
+impl Error {
+    pub fn validation<M: Into<Cow<'static, str>>>(field: Option<&'static str>, message: M) -> Self {
+        Error::Validation {
+            field,
+            message: message.into(),
+        }
+    }
+
+    pub fn decode<M: Into<Cow<'static, str>>>(message: M) -> Self {
+        Error::Decode(message.into())
+    }
+
+    pub fn crypto<M: Into<Cow<'static, str>>>(message: M) -> Self {
+        Error::Crypto(message.into())
+    }
+
+    pub fn internal<M: Into<Cow<'static, str>>>(message: M) -> Self {
+        Error::Internal(message.into())
+    }
+
+    /// A copy of the error of the same kind, for another receiver. Transport and websocket
+    /// errors cannot be copied and become `Internal` with their message, connection closures
+    /// aside.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Error::Validation { field, message } => Error::Validation {
+                field: *field,
+                message: message.clone(),
+            },
+            Error::Api(e) => Error::Api(SiriusError {
+                code: e.code.clone(),
+                message: e.message.clone(),
+            }),
+            Error::Transport(e) => Error::internal(e.to_string()),
+            Error::Decode(msg) => Error::Decode(msg.clone()),
+            Error::Websocket(WsError::ConnectionClosed) => {
+                Error::Websocket(WsError::ConnectionClosed)
+            }
+            Error::Websocket(WsError::AlreadyClosed) => Error::Websocket(WsError::AlreadyClosed),
+            Error::Websocket(e) => Error::internal(e.to_string()),
+            Error::Crypto(msg) => Error::Crypto(msg.clone()),
+            Error::Url(msg) => Error::Url(msg.clone()),
+            Error::Io(e) => Error::Io(::std::io::Error::new(e.kind(), e.to_string())),
+            Error::TransactionFailed(status) => Error::TransactionFailed(status.clone()),
+            Error::Timeout(hash) => Error::Timeout(hash.clone()),
+            Error::Stale(idle) => Error::Stale(*idle),
+            Error::ForkTooDeep(height) => Error::ForkTooDeep(*height),
+            Error::Internal(msg) => Error::Internal(msg.clone()),
+        }
+    }
+
+    /// The input named by a `Validation` error, if any.
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Error::Validation { field, .. } => *field,
+            _ => None,
+        }
+    }
+
+    /// The error code returned by the node for an `Api` error.
+    pub fn api_code(&self) -> Option<&str> {
+        match self {
+            Error::Api(e) => Some(&e.code),
+            _ => None,
+        }
+    }
 }
 
-impl ::failure::Fail for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) => Some(e),
+            Error::Websocket(e) => Some(e),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<reqwest::Error> for Error {
     fn from(reqwest: reqwest::Error) -> Self {
-        Error::Reqwest(reqwest)
+        Error::Transport(reqwest)
     }
 }
 
 impl From<SiriusError> for Error {
     fn from(sirius: SiriusError) -> Self {
-        Error::SiriusError(sirius)
+        Error::Api(sirius)
     }
 }
 
 impl From<WsError> for Error {
     fn from(ws: WsError) -> Self {
-        Error::Tungsten(ws)
+        Error::Websocket(ws)
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(serde: serde_json::Error) -> Self {
-        Error::Serde(serde)
+        Error::Decode(Cow::from(serde.to_string()))
     }
 }
 
-impl From<failure::Error> for Error {
-    fn from(failure: failure::Error) -> Self {
-        Error::Failure(failure)
+impl From<hex::FromHexError> for Error {
+    fn from(hex: hex::FromHexError) -> Self {
+        Error::Decode(Cow::from(hex.to_string()))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(parse: ParseIntError) -> Self {
+        Error::Decode(Cow::from(parse.to_string()))
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(fmt: fmt::Error) -> Self {
+        Error::Decode(Cow::from(fmt.to_string()))
+    }
+}
+
+impl From<::std::io::Error> for Error {
+    fn from(io: ::std::io::Error) -> Self {
+        Error::Io(io)
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
     }
 }
 
 impl From<&'static str> for Error {
     fn from(msg: &'static str) -> Self {
-        Error::Failure(failure::err_msg(msg))
+        Error::validation(None, msg)
     }
 }
 
 impl Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
-        match self.to_owned() {
-            Error::SiriusError(e) => {
-                write!(f, "{{ code: \"{}\", message: \"{}\" }}", e.code, e.message)
-            }
-            Error::Reqwest(e) => write!(f, "{}", e),
-            Error::Serde(e) => write!(f, "{}", e),
-            Error::Tungsten(e) => write!(f, "{}", e),
-            Error::Failure(e) => write!(f, "{}", e),
-            Error::Url(ref msg) => write!(f, "{}", msg),
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Validation {
+                field: Some(field),
+                message,
+            } => write!(f, "invalid {}: {}", field, message),
+            Error::Validation {
+                field: None,
+                message,
+            } => write!(f, "{}", message),
+            Error::Api(e) => write!(f, "{{ code: \"{}\", message: \"{}\" }}", e.code, e.message),
+            Error::Transport(e) => write!(f, "{}", e),
+            Error::Decode(msg) => write!(f, "{}", msg),
+            Error::Websocket(e) => write!(f, "{}", e),
+            Error::Crypto(msg) => write!(f, "{}", msg),
+            Error::Url(msg) => write!(f, "{}", msg),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::TransactionFailed(status) => write!(
+                f,
+                "transaction {} failed: {} ({})",
+                status.hash,
+                status.description(),
+                status.status
+            ),
+            Error::Timeout(hash) => write!(f, "transaction {} not confirmed in time", hash),
+            Error::Stale(idle) => write!(f, "no websocket message received for {:?}", idle),
+            Error::ForkTooDeep(height) => write!(
+                f,
+                "{} (height {})",
+                crate::errors_const::ERR_SYNC_FORK_TOO_DEEP,
+                height
+            ),
+            Error::Internal(msg) => write!(f, "{}", msg),
         }
     }
 }
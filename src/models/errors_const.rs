@@ -45,6 +45,17 @@ pub const ERR_INVALID_AGGREGATE_TRANSACTION: &str =
 pub const ERR_UNKNOWN_BLOCKCHAIN_TYPE: &str = "Not supported Blockchain Type.";
 pub const ERR_INVALID_HASH_LENGTH: &str = "The length of Hash is invalid.";
 pub const ERR_INVALID_HASH_HEX: &str = "Invalid Hash it's not hex.";
+pub const ERR_INVALID_MERKLE_PATH: &str = "Merkle path item without hash or position.";
+pub const ERR_INVALID_NETWORK_CONFIG: &str = "Invalid network config value";
+pub const ERR_MESSAGE_TOO_LARGE: &str = "Message size exceeds the network maximum of";
+pub const ERR_TOO_MANY_MOSAICS: &str = "Mosaics count exceeds the network maximum of";
+pub const ERR_ROOT_NAMESPACE_NAME_TOO_LONG: &str = "Root namespace name is longer than";
+pub const ERR_RESERVED_NAMESPACE_NAME: &str = "Namespace name is reserved by the network:";
+pub const ERR_INVALID_DIVISIBILITY: &str = "Divisibility exceeds the network maximum of";
+pub const ERR_TOO_MANY_COSIGNATORIES: &str = "Cosignatories count exceeds the network maximum of";
+pub const ERR_TOO_MANY_INNER_TRANSACTIONS: &str =
+    "Inner transactions count exceeds the network maximum of";
+pub const ERR_BONDED_AGGREGATE_DISABLED: &str = "Aggregate bonded transactions are disabled.";
 //pub const ERR_EMPTY_HASH: &str = "Hash must not by empty.";
 pub const ERR_EMPTY_NETWORK_TYPE: &str = "NetworkType string is empty.";
 pub const ERR_INVALID_PRIVATE_KEY_LENGTH: &str = "The private key string is empty.";
@@ -55,3 +66,18 @@ pub const ERR_INVALID_SIGNATURE_LENGTH: &str = "The length of Signature is inval
 pub const ERR_INVALID_SIGNATURE_HEX: &str = "Signature must be hexadecimal.";
 pub const ERR_UNKNOWN_TYPE: &str = "Not supported value Type.";
 pub const ERR_INVALID_DATA_LENGTH: &str = "The length of data is invalid.";
+pub const ERR_INVALID_TRANSACTION_DTO: &str = "Transaction response without a transaction type.";
+pub const ERR_INVALID_TRANSACTIONS_DTO: &str = "Transactions response is not an array.";
+pub const ERR_MISSING_RESPONSE_FIELD: &str = "Response is missing the field";
+pub const ERR_INVALID_WS_MESSAGE: &str = "Websocket message without a channel name.";
+pub const ERR_WS_CLOSED: &str = "Websocket connection closed before the uid was received.";
+pub const ERR_ZERO_HEIGHT: &str = "Block height should not be zero.";
+pub const ERR_ZERO_LIMIT: &str = "Limit should not be zero.";
+pub const ERR_SYNC_FORK_TOO_DEEP: &str =
+    "The chain was reorganized below the oldest block known to the sync.";
+pub const ERR_SYNC_BROKEN_CHAIN: &str =
+    "The node keeps serving a block that does not follow the last block known to the sync.";
+pub const ERR_INVALID_BALANCE: &str = "The balance history left the range of an amount";
+pub const ERR_COSIGNER_LOCK: &str = "The cosigner state lock is poisoned.";
+pub const ERR_BALANCE_HISTORY_LOCK: &str = "The balance history lock is poisoned.";
+pub const ERR_UNRESOLVED_MOSAIC_ALIAS: &str = "No mosaic resolution for the alias";
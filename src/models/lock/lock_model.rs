@@ -0,0 +1,156 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use num_enum::IntoPrimitive;
+
+use crate::models::{
+    account::{Address, PublicAccount},
+    mosaic::Mosaic,
+    transaction::{Hash, Height},
+};
+
+/// The state of a hash lock or a secret lock.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, IntoPrimitive)]
+#[repr(u8)]
+pub enum LockStatus {
+    /// The lock still holds the funds.
+    Unused,
+    /// The lock was released: the aggregate bonded was confirmed or the proof was revealed.
+    Used,
+    Unknown,
+}
+
+impl LockStatus {
+    pub fn value(self) -> u8 {
+        self.into()
+    }
+}
+
+impl From<u8> for LockStatus {
+    fn from(num: u8) -> Self {
+        match num {
+            0 => LockStatus::Unused,
+            1 => LockStatus::Used,
+            _ => LockStatus::Unknown,
+        }
+    }
+}
+
+impl core::fmt::Display for LockStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The algorithm used to hash the proof of a secret lock.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, IntoPrimitive)]
+#[repr(u8)]
+pub enum LockHashAlgorithm {
+    /// The proof is hashed using sha3 256.
+    Sha3,
+    /// The proof is hashed using Keccak (ETH compatibility).
+    Keccak,
+    /// The proof is hashed twice: first with Sha-256 and then with RIPEMD-160
+    /// (bitcoin’s OP_HASH160).
+    Hash160,
+    /// The proof is hashed twice with Sha-256 (bitcoin’s OP_HASH256).
+    Hash256,
+    Unknown,
+}
+
+impl LockHashAlgorithm {
+    pub fn value(self) -> u8 {
+        self.into()
+    }
+}
+
+impl From<u8> for LockHashAlgorithm {
+    fn from(num: u8) -> Self {
+        match num {
+            0 => LockHashAlgorithm::Sha3,
+            1 => LockHashAlgorithm::Keccak,
+            2 => LockHashAlgorithm::Hash160,
+            3 => LockHashAlgorithm::Hash256,
+            _ => LockHashAlgorithm::Unknown,
+        }
+    }
+}
+
+/// The funds locked by a `LockFundsTransaction` until the aggregate bonded
+/// with `hash` is confirmed.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HashLockInfo {
+    /// The account that locked the funds.
+    pub account: PublicAccount,
+    pub mosaic: Mosaic,
+    /// The height the lock expires at and the funds go to the harvester.
+    pub expiration_height: Height,
+    pub status: LockStatus,
+    /// The hash of the aggregate bonded transaction.
+    pub hash: Hash,
+}
+
+impl HashLockInfo {
+    pub fn is_used(&self) -> bool {
+        self.status == LockStatus::Used
+    }
+
+    /// Returns `true` if the lock was not used before `height`.
+    pub fn is_expired(&self, height: Height) -> bool {
+        !self.is_used() && height.as_u64() >= self.expiration_height.as_u64()
+    }
+}
+
+impl core::fmt::Display for HashLockInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+/// The funds locked by a secret lock transaction until the proof of `secret` is revealed.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecretLockInfo {
+    /// The account that locked the funds.
+    pub account: PublicAccount,
+    pub mosaic: Mosaic,
+    /// The height the lock expires at and the funds go back to `account`.
+    pub expiration_height: Height,
+    pub status: LockStatus,
+    pub hash_algorithm: LockHashAlgorithm,
+    /// The proof hashed.
+    pub secret: Hash,
+    /// The account receiving the funds once the proof is revealed.
+    pub recipient: Address,
+    /// The hash of the secret and the recipient, unique for every lock.
+    pub composite_hash: Hash,
+}
+
+impl SecretLockInfo {
+    pub fn is_used(&self) -> bool {
+        self.status == LockStatus::Used
+    }
+
+    /// Returns `true` if the proof was not revealed before `height`.
+    pub fn is_expired(&self, height: Height) -> bool {
+        !self.is_used() && height.as_u64() >= self.expiration_height.as_u64()
+    }
+}
+
+impl core::fmt::Display for SecretLockInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
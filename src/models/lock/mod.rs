@@ -0,0 +1,9 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+pub use self::lock_model::*;
+
+mod lock_model;
@@ -2,18 +2,230 @@
 // Use of this source code is governed by the Apache 2.0
 // license that can be found in the LICENSE file.
 
-#[derive(Debug, Serialize, Deserialize)]
+use {
+    serde::{de, Deserialize, Deserializer, Serialize, Serializer},
+    sha3::{Digest, Sha3_256},
+};
+
+use crate::{models::transaction::Hash, utils::is_hex};
+
+use super::{consts::HASH256, errors_const};
+
+/// The side of the sibling hash in a merkle path step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MerklePosition {
+    Left,
+    Right,
+}
+
+impl Serialize for MerklePosition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            MerklePosition::Left => serializer.serialize_str("left"),
+            MerklePosition::Right => serializer.serialize_str("right"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MerklePosition {
+    /// Accepts both encodings used by the nodes: `1`/`2` and `"left"`/`"right"`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Number(n) if n.as_u64() == Some(1) => Ok(MerklePosition::Left),
+            serde_json::Value::Number(n) if n.as_u64() == Some(2) => Ok(MerklePosition::Right),
+            serde_json::Value::String(ref s) if s == "left" => Ok(MerklePosition::Left),
+            serde_json::Value::String(ref s) if s == "right" => Ok(MerklePosition::Right),
+            other => Err(de::Error::custom(format!(
+                "invalid merkle position {}",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MerklePathItem {
     #[serde(rename = "position", skip_serializing_if = "Option::is_none")]
-    pub position: Option<i32>,
+    pub position: Option<MerklePosition>,
     #[serde(rename = "hash", skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MerkleProofInfo {
     /// The complementary data needed to calculate the merkle root.
     #[serde(rename = "merklePath", skip_serializing_if = "Option::is_none")]
     pub merkle_path: Option<Vec<MerklePathItem>>,
 }
 
+impl MerkleProofInfo {
+    /// Recomputes the merkle root from `leaf` by hashing it with every step of the path.
+    ///
+    /// For transactions the leaf is the `merkle_component_hash` of the [TransactionInfo], which
+    /// equals the transaction hash unless the transaction is an aggregate with cosignatures.
+    pub fn calculate_root(&self, leaf: &str) -> crate::Result<Hash> {
+        let mut root = hash_to_bytes(leaf)?;
+
+        for item in self.merkle_path.iter().flatten() {
+            let sibling = match item.hash {
+                Some(ref h) => hash_to_bytes(h)?,
+                None => bail!(decode: errors_const::ERR_INVALID_MERKLE_PATH),
+            };
+
+            root = match item.position {
+                Some(MerklePosition::Left) => hash_pair(&sibling, &root),
+                Some(MerklePosition::Right) => hash_pair(&root, &sibling),
+                None => bail!(decode: errors_const::ERR_INVALID_MERKLE_PATH),
+            };
+        }
+
+        Ok(hex::encode_upper(root))
+    }
+
+    /// Returns `true` if the root recomputed from `leaf` equals `root`, e.g. the
+    /// `block_transactions_hash` of the [BlockInfo] the transaction was included in.
+    pub fn verify(&self, leaf: &str, root: &str) -> crate::Result<bool> {
+        Ok(self.calculate_root(leaf)? == root.trim().to_uppercase())
+    }
+}
+
+impl core::fmt::Display for MerkleProofInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+/// Computes the merkle root of `hashes` the way the node does: every level hashes pairs of
+/// nodes with sha3-256 and duplicates the last node of odd levels. An empty list gives a zero
+/// hash.
+pub fn merkle_root(hashes: &[Hash]) -> crate::Result<Hash> {
+    if hashes.is_empty() {
+        return Ok(hex::encode_upper([0u8; HASH256]));
+    }
+
+    let mut level = vec![];
+    for hash in hashes {
+        level.push(hash_to_bytes(hash)?);
+    }
+
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            let last = level[level.len() - 1].clone();
+            level.push(last);
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+
+    Ok(hex::encode_upper(&level[0]))
+}
+
+fn hash_to_bytes(hash: &str) -> crate::Result<Vec<u8>> {
+    let hash = hash.trim();
+
+    ensure!(
+        hash.len() == HASH256 * 2 && is_hex(hash),
+        "hash" => "{} {}.",
+        errors_const::ERR_INVALID_HASH_HEX,
+        hash
+    );
+
+    Ok(hex::decode(hash)?)
+}
+
+fn hash_pair(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha3_256::default();
+    hasher.input(left);
+    hasher.input(right);
+    hasher.result().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        serde_json::json,
+        sha3::{Digest, Sha3_256},
+    };
+
+    use crate::models::error::Error;
+
+    use super::{merkle_root, MerklePathItem, MerklePosition, MerkleProofInfo};
+
+    fn leaf(n: u8) -> String {
+        format!("{:02X}", n).repeat(32)
+    }
+
+    fn sha3(left: &str, right: &str) -> String {
+        let mut hasher = Sha3_256::default();
+        hasher.input(hex::decode(left).unwrap());
+        hasher.input(hex::decode(right).unwrap());
+        hex::encode_upper(hasher.result())
+    }
+
+    #[test]
+    fn a_path_recomputes_the_root_of_the_block() {
+        // three transactions: the last one is paired with itself.
+        let (a, b, c) = (leaf(0xA1), leaf(0xB2), leaf(0xC3));
+        let ab = sha3(&a, &b);
+        let cc = sha3(&c, &c);
+        let root = sha3(&ab, &cc);
+
+        assert_eq!(merkle_root(&[a, b, c.clone()]).unwrap(), root);
+
+        // both encodings of the position served by the nodes.
+        let proof: MerkleProofInfo = serde_json::from_value(json!({
+            "merklePath": [
+                { "position": "right", "hash": c },
+                { "position": 1, "hash": ab },
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(proof.calculate_root(&c).unwrap(), root);
+        assert!(proof.verify(&c, &root.to_lowercase()).unwrap());
+        assert!(!proof.verify(&leaf(0xD4), &root).unwrap());
+    }
+
+    #[test]
+    fn an_empty_path_returns_the_leaf() {
+        let leaf = leaf(0x42);
+
+        let no_path = MerkleProofInfo { merkle_path: None };
+        assert_eq!(no_path.calculate_root(&leaf).unwrap(), leaf);
+
+        let empty_path = MerkleProofInfo {
+            merkle_path: Some(vec![]),
+        };
+        assert_eq!(empty_path.calculate_root(&leaf).unwrap(), leaf);
+    }
+
+    #[test]
+    fn an_incomplete_item_is_a_decode_error() {
+        let without_hash = MerklePathItem {
+            position: Some(MerklePosition::Left),
+            hash: None,
+        };
+        let without_position = MerklePathItem {
+            position: None,
+            hash: Some(leaf(0x01)),
+        };
+
+        for item in vec![without_hash, without_position] {
+            let proof = MerkleProofInfo {
+                merkle_path: Some(vec![item]),
+            };
+
+            match proof.calculate_root(&leaf(0x02)) {
+                Err(Error::Decode(_)) => {}
+                result => panic!("unexpected result {:?}", result),
+            }
+        }
+    }
+}
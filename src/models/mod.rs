@@ -5,7 +5,8 @@
  */
 
 pub use self::asset_id_model::*;
-pub use self::error::Result;
+pub use self::error::{Error, Result, SiriusError};
+pub use self::merkle_model::*;
 pub use self::uint_64::Uint64;
 
 pub mod account;
@@ -14,16 +15,18 @@ pub mod blockchain;
 pub(crate) mod error;
 pub mod errors_const;
 pub mod exchange;
+pub mod lock;
 pub mod message;
 pub mod mosaic;
 pub mod multisig;
 pub mod namespace;
 pub mod network;
 pub mod node;
+pub mod receipt;
 pub mod transaction;
 
 mod asset_id_model;
-mod consts;
+pub(crate) mod consts;
 mod merkle_model;
 mod roles_type_enum;
 mod uint_64;
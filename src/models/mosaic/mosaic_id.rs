@@ -31,9 +31,12 @@ impl MosaicId {
 
     /// Creates a new `MosaicId` from a hex string.
     pub fn from_hex(string_hex: &str) -> crate::Result<Self> {
-        ensure!(!string_hex.is_empty(), "The hex string must not be empty.");
+        ensure!(
+            !string_hex.is_empty(),
+            "mosaic_id" => "The hex string must not be empty."
+        );
 
-        ensure!(is_hex(string_hex), "Invalid hex string.");
+        ensure!(is_hex(string_hex), "mosaic_id" => "Invalid hex string.");
 
         Ok(Self(Uint64::from_hex(string_hex)?))
     }
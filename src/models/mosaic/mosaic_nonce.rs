@@ -28,11 +28,14 @@ impl MosaicNonce {
 
     /// Creates a new `mosaic_nonce` from a hex string.
     pub fn from_hex(string_hex: &str) -> crate::Result<MosaicNonce> {
-        ensure!(!string_hex.is_empty(), "The hex string must not be empty.");
+        ensure!(
+            !string_hex.is_empty(),
+            "nonce" => "The hex string must not be empty."
+        );
 
-        ensure!(is_hex(string_hex), "Invalid hex string.");
+        ensure!(is_hex(string_hex), "nonce" => "Invalid hex string.");
 
-        let mut decoded = <[u8; NONCE_SIZE]>::from_hex(string_hex).unwrap();
+        let mut decoded = <[u8; NONCE_SIZE]>::from_hex(string_hex)?;
 
         decoded.reverse();
 
@@ -43,7 +43,7 @@ impl MosaicProperties {
         divisibility: u8,
         duration: Uint64,
     ) -> crate::Result<Self> {
-        ensure!(!0 > divisibility, "Divisibility must not be negative.");
+        ensure!(!0 > divisibility, "divisibility" => "Divisibility must not be negative.");
 
         let mut properties = vec![];
         if *duration != 0 {
@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {crypto::Keypair, failure};
+use crypto::Keypair;
 
 use crate::models::{
     account::Account,
@@ -24,7 +24,7 @@ impl CosignatureTransaction {
     pub fn new(tx: Box<dyn Transaction>) -> crate::Result<Self> {
         let aggregate = tx
             .downcast::<AggregateTransaction>()
-            .map_err(|_| failure::err_msg(errors_const::ERR_INVALID_AGGREGATE_TRANSACTION))?;
+            .map_err(|_| crate::Error::internal(errors_const::ERR_INVALID_AGGREGATE_TRANSACTION))?;
 
         Ok(Self(*aggregate))
     }
@@ -35,7 +35,7 @@ impl CosignatureTransaction {
     ) -> crate::Result<CosignatureSignedTransaction> {
         ensure!(
             !self.0.transaction_hash().is_empty(),
-            errors_const::ERR_EMPTY_COSIGNATURE_HASH
+            "transaction" => errors_const::ERR_EMPTY_COSIGNATURE_HASH
         );
 
         let key_pair: Keypair = Keypair::from_private_key(account.key_pair.secret);
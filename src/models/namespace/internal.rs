@@ -12,7 +12,7 @@ use {
 use crate::{
     account::Address,
     models::{asset_id_model::AssetId, errors_const},
-    network::ALIAS_ADDRESS,
+    network::{NamespacePluginConfig, ALIAS_ADDRESS},
     utils::{array_u8_to_u64, u64_to_array_u8, vec_u8_to_hex},
 };
 
@@ -25,13 +25,24 @@ fn is_valid_namespace_name(name: &str) -> bool {
     reg_valid_namespace.is_match(name)
 }
 
-/// Generates a `NamespaceId` from a namespaceFullName.
+/// Generates a `NamespaceId` from a namespaceFullName, within the default namespace limits.
 pub(crate) fn generate_namespace_path(name: &str) -> crate::Result<Vec<NamespaceId>> {
+    generate_namespace_path_with(name, &NamespacePluginConfig::default())
+}
+
+/// Generates a `NamespaceId` from a namespaceFullName, within the namespace limits of `config`.
+pub(crate) fn generate_namespace_path_with(
+    name: &str,
+    config: &NamespacePluginConfig,
+) -> crate::Result<Vec<NamespaceId>> {
     let parts: Vec<&str> = name.split('.').collect();
 
-    ensure!(!parts.is_empty(), errors_const::ERR_INVALID_NAMESPACE_NAME);
+    ensure!(!parts.is_empty(), "name" => errors_const::ERR_INVALID_NAMESPACE_NAME);
 
-    ensure!(parts.len() <= 3, errors_const::ERR_NAMESPACE_TOO_MANY_PART);
+    ensure!(
+        parts.len() as u64 <= config.max_namespace_depth,
+        "name" => errors_const::ERR_NAMESPACE_TOO_MANY_PART
+    );
 
     let mut namespace_id = NamespaceId::default();
 
@@ -39,8 +50,8 @@ pub(crate) fn generate_namespace_path(name: &str) -> crate::Result<Vec<Namespace
 
     for part in parts {
         ensure!(
-            is_valid_namespace_name(part),
-            errors_const::ERR_INVALID_NAMESPACE_NAME
+            is_valid_namespace_name(part) && part.len() as u64 <= config.max_name_size,
+            "name" => errors_const::ERR_INVALID_NAMESPACE_NAME
         );
 
         namespace_id = generate_namespace_id(part, namespace_id)?;
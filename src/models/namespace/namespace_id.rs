@@ -38,12 +38,12 @@ impl NamespaceId {
     pub fn from_name(string_name: &str) -> crate::Result<NamespaceId> {
         ensure!(
             !string_name.is_empty(),
-            errors_const::ERR_EMPTY_NAMESPACE_NAME
+            "name" => errors_const::ERR_EMPTY_NAMESPACE_NAME
         );
 
         let list = generate_namespace_path(string_name)?;
 
-        ensure!(!list.is_empty(), errors_const::ERR_INVALID_NAMESPACE_NAME);
+        ensure!(!list.is_empty(), "name" => errors_const::ERR_INVALID_NAMESPACE_NAME);
 
         Ok(list[list.len() - 1])
     }
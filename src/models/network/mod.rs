@@ -4,8 +4,12 @@
  * license that can be found in the LICENSE file.
  */
 
+pub use self::network_config_model::*;
+pub use self::network_info_model::*;
 pub(crate) use self::network_internal::*;
 pub use self::network_type_model::*;
 
+mod network_config_model;
+mod network_info_model;
 mod network_internal;
 mod network_type_model;
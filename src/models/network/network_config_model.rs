@@ -0,0 +1,722 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{collections::BTreeMap, convert::TryFrom, str::FromStr, time::Duration};
+
+use crate::models::{
+    errors_const,
+    multisig::MultisigModificationType,
+    namespace::{generate_namespace_path_with, NamespaceId, NamespaceType},
+    transaction::{
+        AbsTransaction, AggregateTransaction, EntityTypeEnum, ModifyMultisigAccountTransaction,
+        MosaicDefinitionTransaction, RegisterNamespaceTransaction, Transaction,
+        TransferTransaction,
+    },
+};
+
+const TRANSFER_PLUGIN: &str = "plugin:catapult.plugins.transfer";
+const NAMESPACE_PLUGIN: &str = "plugin:catapult.plugins.namespace";
+const MOSAIC_PLUGIN: &str = "plugin:catapult.plugins.mosaic";
+const MULTISIG_PLUGIN: &str = "plugin:catapult.plugins.multisig";
+const AGGREGATE_PLUGIN: &str = "plugin:catapult.plugins.aggregate";
+
+/// The key/value pairs of one `[section]` of the network configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSection(BTreeMap<String, String>);
+
+impl ConfigSection {
+    /// Returns the raw value of `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(|value| value.as_str())
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.0.keys()
+    }
+
+    /// Parses `key` as an unsigned number, ignoring the `'` digit separators of the node
+    /// format (`1'000`).
+    pub fn get_u64(&self, key: &str) -> crate::Result<Option<u64>> {
+        self.get_parsed(key, |value| value.replace('\'', "").parse::<u64>().ok())
+    }
+
+    pub fn get_bool(&self, key: &str) -> crate::Result<Option<bool>> {
+        self.get_parsed(key, |value| bool::from_str(value).ok())
+    }
+
+    /// Parses `key` as a duration with a `ms`, `s`, `m`, `h` or `d` suffix, e.g. `15s`.
+    pub fn get_duration(&self, key: &str) -> crate::Result<Option<Duration>> {
+        self.get_parsed(key, parse_duration)
+    }
+
+    /// Parses `key` as a comma separated list.
+    pub fn get_list(&self, key: &str) -> Option<Vec<String>> {
+        self.get(key).map(|value| {
+            value
+                .split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+    }
+
+    fn get_parsed<T, F>(&self, key: &str, parse: F) -> crate::Result<Option<T>>
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        match self.get(key) {
+            Some(value) => match parse(value) {
+                Some(parsed) => Ok(Some(parsed)),
+                None => bail!(
+                    decode: "{} {} = {}",
+                    errors_const::ERR_INVALID_NETWORK_CONFIG,
+                    key,
+                    value
+                ),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+/// The `[plugin:catapult.plugins.transfer]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferPluginConfig {
+    /// The maximum size of a message, in bytes.
+    pub max_message_size: u64,
+    /// The maximum number of mosaics in a transfer.
+    pub max_mosaics_size: u64,
+}
+
+impl Default for TransferPluginConfig {
+    fn default() -> Self {
+        TransferPluginConfig {
+            max_message_size: 1024,
+            max_mosaics_size: 256,
+        }
+    }
+}
+
+impl TransferPluginConfig {
+    fn from_section(section: &ConfigSection) -> crate::Result<Self> {
+        let default = Self::default();
+
+        Ok(TransferPluginConfig {
+            max_message_size: section
+                .get_u64("maxMessageSize")?
+                .unwrap_or(default.max_message_size),
+            max_mosaics_size: section
+                .get_u64("maxMosaicsSize")?
+                .unwrap_or(default.max_mosaics_size),
+        })
+    }
+}
+
+/// The `[plugin:catapult.plugins.namespace]` section.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespacePluginConfig {
+    /// The maximum size of a namespace name part.
+    pub max_name_size: u64,
+    /// The maximum size of a root namespace name. The node configuration has no key for it,
+    /// so it always keeps its default of 16 bytes.
+    pub max_root_name_size: u64,
+    /// The maximum number of parts of a namespace name, e.g. 3 for `a.b.c`.
+    pub max_namespace_depth: u64,
+    /// The maximum duration of a root namespace.
+    pub max_namespace_duration: Duration,
+    /// The root names nobody can register.
+    pub reserved_root_namespace_names: Vec<String>,
+    /// The rental fee of a root namespace, per block of duration.
+    pub root_namespace_rental_fee_per_block: u64,
+    /// The rental fee of a sub namespace.
+    pub child_namespace_rental_fee: u64,
+}
+
+impl Default for NamespacePluginConfig {
+    fn default() -> Self {
+        NamespacePluginConfig {
+            max_name_size: 64,
+            max_root_name_size: 16,
+            max_namespace_depth: 3,
+            max_namespace_duration: Duration::from_secs(365 * 24 * 60 * 60),
+            reserved_root_namespace_names: vec![],
+            root_namespace_rental_fee_per_block: 1,
+            child_namespace_rental_fee: 100,
+        }
+    }
+}
+
+impl NamespacePluginConfig {
+    fn from_section(section: &ConfigSection) -> crate::Result<Self> {
+        let default = Self::default();
+
+        Ok(NamespacePluginConfig {
+            max_name_size: section
+                .get_u64("maxNameSize")?
+                .unwrap_or(default.max_name_size),
+            max_root_name_size: default.max_root_name_size,
+            max_namespace_depth: section
+                .get_u64("maxNamespaceDepth")?
+                .unwrap_or(default.max_namespace_depth),
+            max_namespace_duration: section
+                .get_duration("maxNamespaceDuration")?
+                .unwrap_or(default.max_namespace_duration),
+            reserved_root_namespace_names: section
+                .get_list("reservedRootNamespaceNames")
+                .unwrap_or(default.reserved_root_namespace_names),
+            root_namespace_rental_fee_per_block: section
+                .get_u64("rootNamespaceRentalFeePerBlock")?
+                .unwrap_or(default.root_namespace_rental_fee_per_block),
+            child_namespace_rental_fee: section
+                .get_u64("childNamespaceRentalFee")?
+                .unwrap_or(default.child_namespace_rental_fee),
+        })
+    }
+
+    /// Returns the rental fee charged by the network for registering `namespace`.
+    pub fn rental_fee(&self, namespace: &RegisterNamespaceTransaction) -> u64 {
+        match namespace.namespace_type {
+            NamespaceType::Root => namespace.duration.map_or(0, |duration| {
+                duration
+                    .as_u64()
+                    .saturating_mul(self.root_namespace_rental_fee_per_block)
+            }),
+            NamespaceType::Sub => self.child_namespace_rental_fee,
+        }
+    }
+}
+
+/// The `[plugin:catapult.plugins.mosaic]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicPluginConfig {
+    /// The maximum number of mosaics an account can own.
+    pub max_mosaics_per_account: u64,
+    /// The maximum duration of a mosaic.
+    pub max_mosaic_duration: Duration,
+    /// The maximum divisibility of a mosaic.
+    pub max_mosaic_divisibility: u8,
+    /// The rental fee of a mosaic definition.
+    pub mosaic_rental_fee: u64,
+}
+
+impl Default for MosaicPluginConfig {
+    fn default() -> Self {
+        MosaicPluginConfig {
+            max_mosaics_per_account: 10_000,
+            max_mosaic_duration: Duration::from_secs(3650 * 24 * 60 * 60),
+            max_mosaic_divisibility: 6,
+            mosaic_rental_fee: 500,
+        }
+    }
+}
+
+impl MosaicPluginConfig {
+    fn from_section(section: &ConfigSection) -> crate::Result<Self> {
+        let default = Self::default();
+
+        Ok(MosaicPluginConfig {
+            max_mosaics_per_account: section
+                .get_u64("maxMosaicsPerAccount")?
+                .unwrap_or(default.max_mosaics_per_account),
+            max_mosaic_duration: section
+                .get_duration("maxMosaicDuration")?
+                .unwrap_or(default.max_mosaic_duration),
+            max_mosaic_divisibility: match section.get_u64("maxMosaicDivisibility")? {
+                Some(value) => match u8::try_from(value) {
+                    Ok(divisibility) => divisibility,
+                    Err(_) => bail!(
+                        decode: "{} maxMosaicDivisibility = {}",
+                        errors_const::ERR_INVALID_NETWORK_CONFIG,
+                        value
+                    ),
+                },
+                None => default.max_mosaic_divisibility,
+            },
+            mosaic_rental_fee: section
+                .get_u64("mosaicRentalFee")?
+                .unwrap_or(default.mosaic_rental_fee),
+        })
+    }
+}
+
+/// The `[plugin:catapult.plugins.multisig]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigPluginConfig {
+    /// The maximum number of multisig levels.
+    pub max_multisig_depth: u64,
+    /// The maximum number of cosignatories of a multisig account.
+    pub max_cosigners_per_account: u64,
+    /// The maximum number of multisig accounts an account can cosign.
+    pub max_cosigned_accounts_per_account: u64,
+}
+
+impl Default for MultisigPluginConfig {
+    fn default() -> Self {
+        MultisigPluginConfig {
+            max_multisig_depth: 3,
+            max_cosigners_per_account: 10,
+            max_cosigned_accounts_per_account: 5,
+        }
+    }
+}
+
+impl MultisigPluginConfig {
+    fn from_section(section: &ConfigSection) -> crate::Result<Self> {
+        let default = Self::default();
+
+        Ok(MultisigPluginConfig {
+            max_multisig_depth: section
+                .get_u64("maxMultisigDepth")?
+                .unwrap_or(default.max_multisig_depth),
+            max_cosigners_per_account: section
+                .get_u64("maxCosignersPerAccount")?
+                .unwrap_or(default.max_cosigners_per_account),
+            max_cosigned_accounts_per_account: section
+                .get_u64("maxCosignedAccountsPerAccount")?
+                .unwrap_or(default.max_cosigned_accounts_per_account),
+        })
+    }
+}
+
+/// The `[plugin:catapult.plugins.aggregate]` section.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregatePluginConfig {
+    /// The maximum number of inner transactions of an aggregate.
+    pub max_transactions_per_aggregate: u64,
+    /// The maximum number of cosignatures of an aggregate.
+    pub max_cosignatures_per_aggregate: u64,
+    /// Whether aggregate bonded transactions are accepted.
+    pub enable_bonded_aggregate_support: bool,
+    /// The maximum lifetime of an aggregate bonded transaction.
+    pub max_bonded_transaction_lifetime: Duration,
+}
+
+impl Default for AggregatePluginConfig {
+    fn default() -> Self {
+        AggregatePluginConfig {
+            max_transactions_per_aggregate: 1_000,
+            max_cosignatures_per_aggregate: 15,
+            enable_bonded_aggregate_support: true,
+            max_bonded_transaction_lifetime: Duration::from_secs(48 * 60 * 60),
+        }
+    }
+}
+
+impl AggregatePluginConfig {
+    fn from_section(section: &ConfigSection) -> crate::Result<Self> {
+        let default = Self::default();
+
+        Ok(AggregatePluginConfig {
+            max_transactions_per_aggregate: section
+                .get_u64("maxTransactionsPerAggregate")?
+                .unwrap_or(default.max_transactions_per_aggregate),
+            max_cosignatures_per_aggregate: section
+                .get_u64("maxCosignaturesPerAggregate")?
+                .unwrap_or(default.max_cosignatures_per_aggregate),
+            enable_bonded_aggregate_support: section
+                .get_bool("enableBondedAggregateSupport")?
+                .unwrap_or(default.enable_bonded_aggregate_support),
+            max_bonded_transaction_lifetime: section
+                .get_duration("maxBondedTransactionLifetime")?
+                .unwrap_or(default.max_bonded_transaction_lifetime),
+        })
+    }
+}
+
+/// The blockchain configuration of a network, parsed from the node `.properties` text.
+///
+/// Every `[section]` is kept in `sections`; the plugin values used by the sdk are also parsed
+/// into typed sections. Plugins missing from the text keep the catapult defaults, so
+/// `NetworkConfig::default()` describes a stock network.
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::network::NetworkConfig;
+///
+///fn main() {
+///    let config = NetworkConfig::parse(
+///        "[plugin:catapult.plugins.transfer]\nmaxMessageSize = 2'048\nmaxMosaicsSize = 512",
+///    )
+///    .unwrap();
+///
+///    assert_eq!(config.transfer.max_message_size, 2048);
+///}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkConfig {
+    pub sections: BTreeMap<String, ConfigSection>,
+    pub transfer: TransferPluginConfig,
+    pub namespace: NamespacePluginConfig,
+    pub mosaic: MosaicPluginConfig,
+    pub multisig: MultisigPluginConfig,
+    pub aggregate: AggregatePluginConfig,
+}
+
+impl NetworkConfig {
+    /// Parses the `key = value` lines of `text` grouped by `[section]` headers. Empty lines and
+    /// `#` comments are skipped.
+    pub fn parse(text: &str) -> crate::Result<Self> {
+        let mut sections: BTreeMap<String, ConfigSection> = BTreeMap::new();
+        let mut current: Option<String> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                let name = line[1..line.len() - 1].trim().to_string();
+                sections.entry(name.clone()).or_default();
+                current = Some(name);
+                continue;
+            }
+
+            let (pos, section) = match (
+                line.find('='),
+                current.as_ref().and_then(|name| sections.get_mut(name)),
+            ) {
+                (Some(pos), Some(section)) => (pos, section),
+                _ => bail!(
+                    decode: "{} line {}: {}",
+                    errors_const::ERR_INVALID_NETWORK_CONFIG,
+                    index + 1,
+                    line
+                ),
+            };
+
+            section.0.insert(
+                line[..pos].trim().to_string(),
+                line[pos + 1..].trim().to_string(),
+            );
+        }
+
+        let empty = ConfigSection::default();
+        let section = |name: &str| sections.get(name).unwrap_or(&empty);
+
+        Ok(NetworkConfig {
+            transfer: TransferPluginConfig::from_section(section(TRANSFER_PLUGIN))?,
+            namespace: NamespacePluginConfig::from_section(section(NAMESPACE_PLUGIN))?,
+            mosaic: MosaicPluginConfig::from_section(section(MOSAIC_PLUGIN))?,
+            multisig: MultisigPluginConfig::from_section(section(MULTISIG_PLUGIN))?,
+            aggregate: AggregatePluginConfig::from_section(section(AGGREGATE_PLUGIN))?,
+            sections,
+        })
+    }
+
+    /// Returns the section named `name`, e.g. `chain` or `plugin:catapult.plugins.transfer`.
+    pub fn section(&self, name: &str) -> Option<&ConfigSection> {
+        self.sections.get(name)
+    }
+
+    /// Returns the id of the namespace `full_name`, e.g. `foo.bar`, checking the name against
+    /// the namespace limits of the network.
+    pub fn namespace_id(&self, full_name: &str) -> crate::Result<NamespaceId> {
+        ensure!(
+            !full_name.is_empty(),
+            "name" => errors_const::ERR_EMPTY_NAMESPACE_NAME
+        );
+
+        let path = generate_namespace_path_with(full_name, &self.namespace)?;
+
+        match path.last() {
+            Some(namespace_id) => Ok(*namespace_id),
+            None => bail!("name" => errors_const::ERR_INVALID_NAMESPACE_NAME),
+        }
+    }
+
+    /// Returns the rental fee the network charges for `transaction`: namespace registrations
+    /// and mosaic definitions, including inside aggregates. The fee is paid in the network
+    /// currency on top of the transaction fee.
+    pub fn rental_fee(&self, transaction: &dyn Transaction) -> u64 {
+        let any = transaction.as_any();
+
+        if let Some(namespace) = any.downcast_ref::<RegisterNamespaceTransaction>() {
+            self.namespace.rental_fee(namespace)
+        } else if any.is::<MosaicDefinitionTransaction>() {
+            self.mosaic.mosaic_rental_fee
+        } else if let Some(aggregate) = any.downcast_ref::<AggregateTransaction>() {
+            aggregate
+                .inner_transactions
+                .iter()
+                .fold(0u64, |fee, inner| {
+                    fee.saturating_add(self.rental_fee(inner.as_ref()))
+                })
+        } else {
+            0
+        }
+    }
+
+    /// Checks `transaction` against the limits of the network, so it can be rejected before
+    /// being announced. Aggregates are checked with their inner transactions.
+    pub fn validate(&self, transaction: &dyn Transaction) -> crate::Result<()> {
+        let any = transaction.as_any();
+
+        if let Some(transfer) = any.downcast_ref::<TransferTransaction>() {
+            ensure!(
+                transfer.message_size() as u64 <= self.transfer.max_message_size,
+                "message" => "{} {}.",
+                errors_const::ERR_MESSAGE_TOO_LARGE,
+                self.transfer.max_message_size
+            );
+            ensure!(
+                transfer.mosaics.len() as u64 <= self.transfer.max_mosaics_size,
+                "mosaics" => "{} {}.",
+                errors_const::ERR_TOO_MANY_MOSAICS,
+                self.transfer.max_mosaics_size
+            );
+        } else if let Some(namespace) = any.downcast_ref::<RegisterNamespaceTransaction>() {
+            ensure!(
+                !namespace.name.is_empty()
+                    && namespace.name.len() as u64 <= self.namespace.max_name_size,
+                "name" => errors_const::ERR_INVALID_NAMESPACE_NAME
+            );
+            ensure!(
+                namespace.namespace_type != NamespaceType::Root
+                    || namespace.name.len() as u64 <= self.namespace.max_root_name_size,
+                "name" => "{} {}.",
+                errors_const::ERR_ROOT_NAMESPACE_NAME_TOO_LONG,
+                self.namespace.max_root_name_size
+            );
+            ensure!(
+                namespace.namespace_type != NamespaceType::Root
+                    || !self
+                        .namespace
+                        .reserved_root_namespace_names
+                        .contains(&namespace.name),
+                "name" => "{} {}.",
+                errors_const::ERR_RESERVED_NAMESPACE_NAME,
+                namespace.name
+            );
+        } else if let Some(mosaic) = any.downcast_ref::<MosaicDefinitionTransaction>() {
+            ensure!(
+                mosaic.properties.divisibility <= self.mosaic.max_mosaic_divisibility,
+                "divisibility" => "{} {}.",
+                errors_const::ERR_INVALID_DIVISIBILITY,
+                self.mosaic.max_mosaic_divisibility
+            );
+        } else if let Some(multisig) = any.downcast_ref::<ModifyMultisigAccountTransaction>() {
+            let additions = multisig
+                .modifications
+                .iter()
+                .filter(|modification| {
+                    modification.modification_type == MultisigModificationType::Add
+                })
+                .count();
+
+            ensure!(
+                additions as u64 <= self.multisig.max_cosigners_per_account,
+                "modifications" => "{} {}.",
+                errors_const::ERR_TOO_MANY_COSIGNATORIES,
+                self.multisig.max_cosigners_per_account
+            );
+        } else if let Some(aggregate) = any.downcast_ref::<AggregateTransaction>() {
+            ensure!(
+                aggregate.inner_transactions.len() as u64
+                    <= self.aggregate.max_transactions_per_aggregate,
+                "inner_transactions" => "{} {}.",
+                errors_const::ERR_TOO_MANY_INNER_TRANSACTIONS,
+                self.aggregate.max_transactions_per_aggregate
+            );
+            ensure!(
+                aggregate.entity_type() != EntityTypeEnum::AggregateBonded
+                    || self.aggregate.enable_bonded_aggregate_support,
+                "entity_type" => errors_const::ERR_BONDED_AGGREGATE_DISABLED
+            );
+
+            for inner in aggregate.inner_transactions.iter() {
+                self.validate(inner.as_ref())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for NetworkConfig {
+    type Err = crate::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        NetworkConfig::parse(text)
+    }
+}
+
+impl core::fmt::Display for NetworkConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.replace('\'', "");
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount = amount.parse::<u64>().ok()?;
+
+    match unit {
+        "ms" => Some(Duration::from_millis(amount)),
+        "s" => Some(Duration::from_secs(amount)),
+        "m" => amount.checked_mul(60).map(Duration::from_secs),
+        "h" => amount.checked_mul(60 * 60).map(Duration::from_secs),
+        "d" => amount.checked_mul(24 * 60 * 60).map(Duration::from_secs),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{
+        account::PublicAccount,
+        multisig::{CosignatoryModification, MultisigModificationType},
+        network::PUBLIC_TEST,
+        transaction::{Deadline, ModifyMultisigAccountTransaction, RegisterNamespaceTransaction},
+        Uint64,
+    };
+
+    use super::{parse_duration, NetworkConfig};
+
+    const PUBLIC_KEY: &str = "C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2";
+
+    fn modifications(add: usize, remove: usize) -> Vec<CosignatoryModification> {
+        let account = PublicAccount::from_public_key(PUBLIC_KEY, PUBLIC_TEST).unwrap();
+
+        (0..add)
+            .map(|_| MultisigModificationType::Add)
+            .chain((0..remove).map(|_| MultisigModificationType::Remove))
+            .map(|modification_type| {
+                CosignatoryModification::new(modification_type, account.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn out_of_range_divisibility_is_rejected() {
+        let text = "[plugin:catapult.plugins.mosaic]\nmaxMosaicDivisibility = 300";
+
+        assert!(NetworkConfig::parse(text).is_err());
+    }
+
+    #[test]
+    fn namespace_limits_come_from_the_config() {
+        let config = NetworkConfig::parse(
+            "[plugin:catapult.plugins.namespace]\nmaxNameSize = 4\nmaxNamespaceDepth = 4",
+        )
+        .unwrap();
+
+        assert!(config.namespace_id("a.b.c.d").is_ok());
+        assert!(NetworkConfig::default().namespace_id("a.b.c.d").is_err());
+
+        assert!(RegisterNamespaceTransaction::create_root_with_config(
+            Deadline::default(),
+            "long",
+            Uint64::new(10),
+            &config,
+            PUBLIC_TEST,
+        )
+        .is_ok());
+        assert!(RegisterNamespaceTransaction::create_root_with_config(
+            Deadline::default(),
+            "longer",
+            Uint64::new(10),
+            &config,
+            PUBLIC_TEST,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn root_names_keep_their_own_limit() {
+        let root = |name| {
+            RegisterNamespaceTransaction::create_root(
+                Deadline::default(),
+                name,
+                Uint64::new(10),
+                PUBLIC_TEST,
+            )
+        };
+
+        assert!(root("sixteen-byte-nam").is_ok());
+        assert!(root("seventeen-byte-na").is_err());
+        assert!(RegisterNamespaceTransaction::create_sub(
+            Deadline::default(),
+            "seventeen-byte-na",
+            root("root").unwrap().namespace_id,
+            PUBLIC_TEST,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn overflowing_durations_are_unparseable() {
+        assert_eq!(
+            parse_duration("1'000m"),
+            Some(std::time::Duration::from_secs(60_000))
+        );
+        assert_eq!(parse_duration("18446744073709551615d"), None);
+        assert!(NetworkConfig::parse(
+            "[plugin:catapult.plugins.namespace]\nmaxNamespaceDuration = 18446744073709551615h"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn only_added_cosigners_count_towards_the_limit() {
+        let config =
+            NetworkConfig::parse("[plugin:catapult.plugins.multisig]\nmaxCosignersPerAccount = 2")
+                .unwrap();
+
+        let modify = |add, remove| {
+            ModifyMultisigAccountTransaction::new_with_config(
+                Deadline::default(),
+                1,
+                1,
+                modifications(add, remove),
+                &config,
+                PUBLIC_TEST,
+            )
+        };
+
+        assert!(modify(2, 3).is_ok());
+        assert!(modify(3, 0).is_err());
+    }
+
+    #[test]
+    fn rental_fee_follows_the_namespace_type() {
+        let config = NetworkConfig::parse(
+            "[plugin:catapult.plugins.namespace]\nrootNamespaceRentalFeePerBlock = 2\nchildNamespaceRentalFee = 7",
+        )
+        .unwrap();
+
+        let root = RegisterNamespaceTransaction::create_root(
+            Deadline::default(),
+            "root",
+            Uint64::new(10),
+            PUBLIC_TEST,
+        )
+        .unwrap();
+        let sub = RegisterNamespaceTransaction::create_sub(
+            Deadline::default(),
+            "sub",
+            root.namespace_id,
+            PUBLIC_TEST,
+        )
+        .unwrap();
+
+        assert_eq!(config.rental_fee(&root), 20);
+        assert_eq!(config.rental_fee(&sub), 7);
+    }
+}
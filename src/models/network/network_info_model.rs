@@ -0,0 +1,84 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::models::{transaction::Height, Uint64};
+
+use super::{NetworkConfig, NetworkType};
+
+/// The network a node is connected to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInfo {
+    pub network_type: NetworkType,
+    /// The name of the network as reported by the node, e.g. `publicTest`.
+    pub name: String,
+    /// A short text describing the network.
+    pub description: String,
+}
+
+impl core::fmt::Display for NetworkInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+/// The network configuration in force from `started_height`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkConfigInfo {
+    /// The height the configuration applies from.
+    pub started_height: Height,
+    /// The blockchain configuration, parsed from the node `.properties` format.
+    pub network_config: NetworkConfig,
+    /// The raw json list of the supported entity versions.
+    pub supported_entity_versions: String,
+}
+
+impl core::fmt::Display for NetworkConfigInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+/// A blockchain version upgrade scheduled at `height`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockchainUpgrade {
+    pub height: Height,
+    /// The catapult version packed as `major.minor.revision.build`, 16 bits each.
+    pub block_chain_version: Uint64,
+}
+
+impl BlockchainUpgrade {
+    /// Returns the `(major, minor, revision, build)` parts of the version.
+    pub fn version_parts(&self) -> (u16, u16, u16, u16) {
+        let version = self.block_chain_version.as_u64();
+        (
+            (version >> 48) as u16,
+            (version >> 32) as u16,
+            (version >> 16) as u16,
+            version as u16,
+        )
+    }
+}
+
+impl core::fmt::Display for BlockchainUpgrade {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
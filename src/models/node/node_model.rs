@@ -50,3 +50,23 @@ impl core::fmt::Display for NodeTime {
         )
     }
 }
+
+/// The versions of the REST gateway a node is running.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    /// The catapult-rest component version.
+    pub rest_version: String,
+    /// The catapult-sdk component version.
+    pub sdk_version: String,
+}
+
+impl core::fmt::Display for ServerInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
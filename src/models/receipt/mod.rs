@@ -0,0 +1,13 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+pub use self::receipt_model::*;
+pub use self::receipt_type::*;
+pub use self::statement_model::*;
+
+mod receipt_model;
+mod receipt_type;
+mod statement_model;
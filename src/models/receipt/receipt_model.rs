@@ -0,0 +1,118 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use serde_json::Value;
+
+use crate::models::{
+    account::{Address, PublicAccount},
+    mosaic::Mosaic,
+    Uint64,
+};
+
+use super::ReceiptType;
+
+/// The invisible state change triggered a mosaic transfer.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceTransferReceipt {
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+    /// The account sending the mosaic.
+    pub sender: PublicAccount,
+    /// The address receiving the mosaic.
+    pub recipient: Address,
+    pub mosaic: Mosaic,
+}
+
+/// The invisible state change changed an account balance.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChangeReceipt {
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+    /// The account whose balance changed.
+    pub account: PublicAccount,
+    pub mosaic: Mosaic,
+}
+
+impl BalanceChangeReceipt {
+    /// Returns `true` if the receipt credits the account, `false` if it debits it.
+    pub fn is_credit(&self) -> bool {
+        self.receipt_type.basic_type() == 0x2
+    }
+}
+
+/// An artifact namespace or mosaic expired.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactExpiryReceipt {
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+    /// The `MosaicId` or `NamespaceId` of the expired artifact.
+    pub artifact_id: Uint64,
+}
+
+/// Native currency mosaics were created due to inflation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InflationReceipt {
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub receipt_type: ReceiptType,
+    pub mosaic: Mosaic,
+}
+
+/// A receipt attached to a transaction statement.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Receipt {
+    BalanceTransfer(BalanceTransferReceipt),
+    BalanceChange(BalanceChangeReceipt),
+    ArtifactExpiry(ArtifactExpiryReceipt),
+    Inflation(InflationReceipt),
+    /// A receipt of a type this SDK does not decode, kept as the json served by the node.
+    Unknown {
+        version: u32,
+        #[serde(rename = "type")]
+        receipt_type: ReceiptType,
+        raw: Value,
+    },
+}
+
+impl Receipt {
+    pub fn receipt_type(&self) -> ReceiptType {
+        match self {
+            Receipt::BalanceTransfer(r) => r.receipt_type,
+            Receipt::BalanceChange(r) => r.receipt_type,
+            Receipt::ArtifactExpiry(r) => r.receipt_type,
+            Receipt::Inflation(r) => r.receipt_type,
+            Receipt::Unknown { receipt_type, .. } => *receipt_type,
+        }
+    }
+
+    pub fn version(&self) -> u32 {
+        match self {
+            Receipt::BalanceTransfer(r) => r.version,
+            Receipt::BalanceChange(r) => r.version,
+            Receipt::ArtifactExpiry(r) => r.version,
+            Receipt::Inflation(r) => r.version,
+            Receipt::Unknown { version, .. } => *version,
+        }
+    }
+}
+
+impl core::fmt::Display for Receipt {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
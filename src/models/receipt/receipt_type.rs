@@ -0,0 +1,94 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use num_enum::IntoPrimitive;
+
+/// The type of the receipt:
+/// * 0x124D (4685 decimal) - Mosaic_Levy.
+/// * 0x134D (4941 decimal) - Mosaic_Rental_Fee.
+/// * 0x124E (4686 decimal) - Namespace_Rental_Fee.
+/// * 0x2143 (8515 decimal) - Harvest_Fee.
+/// * 0x2248 (8776 decimal) - LockHash_Completed.
+/// * 0x2348 (9032 decimal) - LockHash_Expired.
+/// * 0x2252 (8786 decimal) - LockSecret_Completed.
+/// * 0x2352 (9042 decimal) - LockSecret_Expired.
+/// * 0x3148 (12616 decimal) - LockHash_Created.
+/// * 0x3152 (12626 decimal) - LockSecret_Created.
+/// * 0x414D (16717 decimal) - Mosaic_Expired.
+/// * 0x414E (16718 decimal) - Namespace_Expired.
+/// * 0x5143 (20803 decimal) - Inflation.
+/// * 0xE134 (57652 decimal) - Transaction_Group.
+/// * 0xF143 (61763 decimal) - Address_Alias_Resolution.
+/// * 0xF243 (62019 decimal) - Mosaic_Alias_Resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, IntoPrimitive)]
+#[repr(u16)]
+pub enum ReceiptType {
+    MosaicLevy = 0x124D,
+    MosaicRentalFee = 0x134D,
+    NamespaceRentalFee = 0x124E,
+    HarvestFee = 0x2143,
+    LockHashCompleted = 0x2248,
+    LockHashExpired = 0x2348,
+    LockSecretCompleted = 0x2252,
+    LockSecretExpired = 0x2352,
+    LockHashCreated = 0x3148,
+    LockSecretCreated = 0x3152,
+    MosaicExpired = 0x414D,
+    NamespaceExpired = 0x414E,
+    Inflation = 0x5143,
+    TransactionGroup = 0xE134,
+    AddressAliasResolution = 0xF143,
+    MosaicAliasResolution = 0xF243,
+    ReceiptTypeUnknown,
+}
+
+impl ReceiptType {
+    pub fn value(self) -> u16 {
+        self.into()
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("{:#X}", self.value())
+    }
+
+    /// The receipt kind, given by the higher nibble of the type:
+    /// 1 balance transfer, 2 balance credit, 3 balance debit, 4 artifact expiry,
+    /// 5 inflation, E aggregate and F alias resolution.
+    pub(crate) fn basic_type(self) -> u8 {
+        (self.value() >> 12) as u8
+    }
+}
+
+impl From<u16> for ReceiptType {
+    fn from(num: u16) -> Self {
+        match num {
+            0x124D => ReceiptType::MosaicLevy,
+            0x134D => ReceiptType::MosaicRentalFee,
+            0x124E => ReceiptType::NamespaceRentalFee,
+            0x2143 => ReceiptType::HarvestFee,
+            0x2248 => ReceiptType::LockHashCompleted,
+            0x2348 => ReceiptType::LockHashExpired,
+            0x2252 => ReceiptType::LockSecretCompleted,
+            0x2352 => ReceiptType::LockSecretExpired,
+            0x3148 => ReceiptType::LockHashCreated,
+            0x3152 => ReceiptType::LockSecretCreated,
+            0x414D => ReceiptType::MosaicExpired,
+            0x414E => ReceiptType::NamespaceExpired,
+            0x5143 => ReceiptType::Inflation,
+            0xE134 => ReceiptType::TransactionGroup,
+            0xF143 => ReceiptType::AddressAliasResolution,
+            0xF243 => ReceiptType::MosaicAliasResolution,
+
+            _ => ReceiptType::ReceiptTypeUnknown,
+        }
+    }
+}
+
+impl core::fmt::Display for ReceiptType {
+    fn fmt(&self, e: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(e, "{}", serde_json::to_string(&self).unwrap_or_default())
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::models::{
+    account::Address, error::Error, errors_const::ERR_UNRESOLVED_MOSAIC_ALIAS, mosaic::MosaicId,
+    transaction::Height, AssetId, AssetIdType, Result, Uint64,
+};
+
+use super::Receipt;
+
+/// The transaction that triggered the receipt.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptSource {
+    /// The transaction index within the block.
+    pub primary_id: u32,
+    /// The transaction index inside within the aggregate transaction. If the transaction is not
+    /// an inner transaction, then the secondary id is set to 0.
+    pub secondary_id: u32,
+}
+
+/// The collection of receipts related to a transaction.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatement {
+    pub height: Height,
+    pub source: ReceiptSource,
+    pub receipts: Vec<Receipt>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressResolutionEntry {
+    pub source: ReceiptSource,
+    pub resolved: Address,
+}
+
+/// Keeps the relation between a namespace alias used in a transaction and the real address.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressResolutionStatement {
+    pub height: Height,
+    /// The alias as used by the transaction.
+    pub unresolved: Address,
+    /// Within one block the resolution might change for different sources due to alias
+    /// related transactions.
+    pub resolution_entries: Vec<AddressResolutionEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicResolutionEntry {
+    pub source: ReceiptSource,
+    pub resolved: MosaicId,
+}
+
+/// Keeps the relation between a namespace alias used in a transaction and the real mosaic id.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MosaicResolutionStatement {
+    pub height: Height,
+    /// The `NamespaceId` or `MosaicId` as used by the transaction.
+    pub unresolved: Uint64,
+    /// Within one block the resolution might change for different sources due to alias
+    /// related transactions.
+    pub resolution_entries: Vec<MosaicResolutionEntry>,
+}
+
+/// The collection of transaction statements and resolutions triggered for a block.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statements {
+    pub transaction_statements: Vec<TransactionStatement>,
+    pub address_resolution_statements: Vec<AddressResolutionStatement>,
+    pub mosaic_resolution_statements: Vec<MosaicResolutionStatement>,
+}
+
+impl Statements {
+    /// The address an alias used by the transaction at `source` resolves to, or `address`
+    /// itself when it is not an alias of the block.
+    pub fn resolve_address(&self, address: &Address, source: ReceiptSource) -> Address {
+        let statement = self
+            .address_resolution_statements
+            .iter()
+            .find(|statement| statement.unresolved.address_string() == address.address_string());
+
+        let entries = match statement {
+            Some(statement) => &statement.resolution_entries,
+            None => return address.to_owned(),
+        };
+
+        entries
+            .iter()
+            .filter(|entry| is_before(&entry.source, &source))
+            .last()
+            .or_else(|| entries.first())
+            .map(|entry| entry.resolved.to_owned())
+            .unwrap_or_else(|| address.to_owned())
+    }
+
+    /// The mosaic an alias used by the transaction at `source` resolves to; fails for an
+    /// alias without a resolution in the block.
+    pub fn resolve_mosaic(
+        &self,
+        asset_id: &dyn AssetId,
+        source: ReceiptSource,
+    ) -> Result<MosaicId> {
+        if let AssetIdType::Mosaic = asset_id.get_type() {
+            return Ok(asset_id.to_mosaic_id());
+        }
+
+        let entries = self
+            .mosaic_resolution_statements
+            .iter()
+            .find(|statement| statement.unresolved.as_u64() == asset_id.to_u64())
+            .map(|statement| &statement.resolution_entries);
+
+        entries
+            .and_then(|entries| {
+                entries
+                    .iter()
+                    .filter(|entry| is_before(&entry.source, &source))
+                    .last()
+                    .or_else(|| entries.first())
+            })
+            .map(|entry| entry.resolved.clone())
+            .ok_or_else(|| {
+                Error::decode(format!(
+                    "{}: {}",
+                    ERR_UNRESOLVED_MOSAIC_ALIAS,
+                    asset_id.to_hex()
+                ))
+            })
+    }
+}
+
+impl core::fmt::Display for Statements {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string_pretty(self).unwrap_or_default()
+        )
+    }
+}
+
+/// Whether resolution entry `source` applies to a transaction at `tx_source`: the entries
+/// of a block are ordered and each one holds until the next.
+fn is_before(source: &ReceiptSource, tx_source: &ReceiptSource) -> bool {
+    (source.primary_id, source.secondary_id) <= (tx_source.primary_id, tx_source.secondary_id)
+}
@@ -32,7 +32,7 @@ impl BlockchainTimestamp {
 
     /// returns new timestamp from passed milliseconds value
     pub fn to_timestamp(&self) -> Timestamp {
-        Timestamp::new(self.0 + TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS)
+        Timestamp::new(self.0.saturating_add(TIMESTAMP_NEMESIS_BLOCK_MILLISECONDS))
     }
 
     pub fn to_uint64(&self) -> Uint64 {
@@ -40,14 +40,15 @@ impl BlockchainTimestamp {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize)]
 pub struct Timestamp(SystemTime);
 
 impl Timestamp {
     /// returns new timestamp from passed milliseconds value
     pub fn new(milliseconds: i64) -> Self {
         // Creates a new SystemTime from the specified number of whole seconds
-        Timestamp(UNIX_EPOCH + Duration::from_nanos((milliseconds * MILLISECONDS) as u64))
+        let nanos = milliseconds.saturating_mul(MILLISECONDS).max(0);
+        Timestamp(UNIX_EPOCH + Duration::from_nanos(nanos as u64))
     }
 
     pub fn to_blockchain_timestamp(&self) -> BlockchainTimestamp {
@@ -81,7 +82,7 @@ impl core::fmt::Display for Timestamp {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)] // we derive Default in order to use the clear() method in Drop
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)] // we derive Default in order to use the clear() method in Drop
 pub struct Deadline(Timestamp);
 
 impl Deadline {
@@ -98,6 +99,10 @@ impl Deadline {
     pub fn to_blockchain_timestamp(&self) -> BlockchainTimestamp {
         self.0.to_blockchain_timestamp()
     }
+
+    pub fn to_timestamp(&self) -> Timestamp {
+        self.0
+    }
 }
 
 impl core::fmt::Display for Deadline {
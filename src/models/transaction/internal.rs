@@ -13,7 +13,7 @@ use crate::{
             HALF_OF_SIGNATURE, SIGNATURE_SIZE, SIGNER_SIZE, SIZE_SIZE, TRANSACTION_HEADER_SIZE,
             TYPE_SIZE, VERSION_SIZE,
         },
-        errors_const::ERR_EMPTY_TRANSACTION_SIGNER,
+        errors_const::{ERR_EMPTY_TRANSACTION_SIGNER, ERR_INVALID_DATA_LENGTH},
         mosaic::MosaicProperty,
         multisig::CosignatoryModification,
     },
@@ -55,7 +55,7 @@ pub(crate) fn sign_transaction(
 
     let payload = vec_u8_to_hex(tx_vector);
 
-    let hash = create_transaction_hash(payload.clone(), &generation_hash);
+    let hash = create_transaction_hash(payload.clone(), &generation_hash)?;
 
     Ok(SignedTransaction::new(tx.entity_type(), payload, hash))
 }
@@ -97,8 +97,13 @@ pub(crate) fn sign_transaction_with_cosignatures(
     ))
 }
 
-pub(crate) fn create_transaction_hash(p: String, generation_hash: &str) -> String {
-    let p_bytes = hex::decode(p).unwrap();
+pub(crate) fn create_transaction_hash(p: String, generation_hash: &str) -> crate::Result<String> {
+    let p_bytes = hex::decode(p)?;
+
+    ensure!(
+        p_bytes.len() >= SIZE_SIZE + SIGNATURE_SIZE + SIGNER_SIZE,
+        "payload" => ERR_INVALID_DATA_LENGTH
+    );
 
     let mut sb = vec![];
 
@@ -108,15 +113,15 @@ pub(crate) fn create_transaction_hash(p: String, generation_hash: &str) -> Strin
         &p_bytes[SIGNATURE_SIZE + SIZE_SIZE..SIZE_SIZE + SIGNATURE_SIZE + SIGNER_SIZE],
     );
 
-    let generation_hash_bytes = hex::decode(generation_hash);
+    let generation_hash_bytes = hex::decode(generation_hash)?;
 
-    sb.extend_from_slice(&generation_hash_bytes.unwrap());
+    sb.extend_from_slice(&generation_hash_bytes);
 
-    sb.extend_from_slice(&p_bytes[100..]);
+    sb.extend_from_slice(&p_bytes[SIZE_SIZE + SIGNATURE_SIZE + SIGNER_SIZE..]);
 
     let sha3_public_key_hash = Sha3_256::digest(sb.as_slice());
 
-    vec_u8_to_hex(sha3_public_key_hash[..].to_vec())
+    Ok(vec_u8_to_hex(sha3_public_key_hash[..].to_vec()))
 }
 
 pub(crate) fn mosaic_property_array_to_buffer(
@@ -141,7 +146,7 @@ pub(crate) fn mosaic_property_array_to_buffer(
 pub(crate) fn to_aggregate_transaction_bytes(tx: &Box<dyn Transaction>) -> crate::Result<Vec<u8>> {
     ensure!(
         tx.abs_transaction().signer.public_key != "",
-        ERR_EMPTY_TRANSACTION_SIGNER
+        "signer" => ERR_EMPTY_TRANSACTION_SIGNER
     );
 
     let signer_bytes = tx.to_owned().abs_transaction().signer.to_bytes();
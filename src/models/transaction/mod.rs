@@ -14,6 +14,7 @@ pub use self::transaction_aggregate::*;
 pub use self::transaction_alias::*;
 pub use self::transaction_alias_address::*;
 pub use self::transaction_alias_mosaic::*;
+pub use self::transaction_announce::*;
 pub use self::transaction_cosignature_signed::*;
 pub use self::transaction_exchange_offer::*;
 pub use self::transaction_hash_lock::*;
@@ -24,8 +25,11 @@ pub use self::transaction_model::*;
 pub use self::transaction_modify_multisig_account::*;
 pub use self::transaction_mosaic_definition::*;
 pub use self::transaction_mosaic_supply_change::*;
+pub use self::transaction_pagination::*;
+pub use self::transaction_query::*;
 pub use self::transaction_register_namespace::*;
 pub use self::transaction_remove_exchange_offer::*;
+pub use self::transaction_status::*;
 pub use self::transaction_transfer::*;
 pub use self::transaction_type::*;
 
@@ -40,6 +44,7 @@ mod transaction_aggregate;
 mod transaction_alias;
 mod transaction_alias_address;
 mod transaction_alias_mosaic;
+mod transaction_announce;
 mod transaction_cosignature_signed;
 mod transaction_exchange_offer;
 mod transaction_hash_lock;
@@ -50,8 +55,11 @@ mod transaction_model;
 mod transaction_modify_multisig_account;
 mod transaction_mosaic_definition;
 mod transaction_mosaic_supply_change;
+mod transaction_pagination;
+mod transaction_query;
 mod transaction_register_namespace;
 mod transaction_remove_exchange_offer;
+mod transaction_status;
 mod transaction_transfer;
 mod transaction_type;
 
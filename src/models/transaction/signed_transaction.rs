@@ -41,12 +41,12 @@ impl SignedTransaction {
         }
     }
 
-    pub(crate) fn hash_to_bytes(&self) -> Vec<u8> {
-        hex::decode(&self.hash).unwrap()
+    pub(crate) fn hash_to_bytes(&self) -> crate::Result<Vec<u8>> {
+        Ok(hex::decode(&self.hash)?)
     }
 
-    pub fn payload_to_bytes(&self) -> Vec<u8> {
-        hex::decode(&self.get_payload()).unwrap()
+    pub fn payload_to_bytes(&self) -> crate::Result<Vec<u8>> {
+        Ok(hex::decode(&self.get_payload())?)
     }
 
     pub fn get_payload(&self) -> String {
@@ -5,11 +5,11 @@
  */
 
 use {
+    ::std::any::Any,
     ::std::{
         fmt,
         fmt::{Display, Formatter},
     },
-    failure::_core::any::Any,
     serde_json::Value,
 };
 
@@ -44,11 +44,11 @@ impl AccountPropertiesMosaicTransaction {
     ) -> crate::Result<Self> {
         ensure!(
             !modifications.is_empty(),
-            "modifications must not be empty."
+            "modifications" => "modifications must not be empty."
         );
 
         if property_type.value() & AccountPropertyType::AllowMosaic.value() == 0 {
-            bail!("wrong propertyType for mosaic account properties")
+            bail!("property_type" => "wrong propertyType for mosaic account properties")
         }
 
         let abs_tx = AbstractTransaction::new_from_type(
@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, fb::FlatBufferBuilder, serde_json::Value};
+use {::std::any::Any, ::std::fmt, fb::FlatBufferBuilder, serde_json::Value};
 
 use crate::transaction::schema::add_exchange_offer_transaction_schema;
 use crate::{
@@ -37,7 +37,7 @@ impl AddExchangeOfferTransaction {
         offers: Vec<AddOffer>,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(!offers.is_empty(), errors_const::ERR_EMPTY_ADDRESSES);
+        ensure!(!offers.is_empty(), "offers" => errors_const::ERR_EMPTY_ADDRESSES);
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
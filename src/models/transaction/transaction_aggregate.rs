@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, serde_json::Value};
+use {::std::any::Any, ::std::fmt, serde_json::Value};
 
 use crate::{
     models::{
@@ -44,7 +44,7 @@ impl AggregateTransaction {
         inner_txs: Vec<Box<dyn Transaction>>,
         network_type: NetworkType,
     ) -> Result<AggregateTransaction> {
-        ensure!(!inner_txs.is_empty(), ERR_EMPTY_INNER_TRANSACTION);
+        ensure!(!inner_txs.is_empty(), "inner_transactions" => ERR_EMPTY_INNER_TRANSACTION);
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
@@ -65,7 +65,7 @@ impl AggregateTransaction {
         inner_txs: Vec<Box<dyn Transaction>>,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(!inner_txs.is_empty(), ERR_EMPTY_INNER_TRANSACTION);
+        ensure!(!inner_txs.is_empty(), "inner_transactions" => ERR_EMPTY_INNER_TRANSACTION);
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
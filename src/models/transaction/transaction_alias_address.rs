@@ -44,12 +44,12 @@ impl AddressAliasTransaction {
     ) -> Result<Self> {
         ensure!(
             !address.address.is_empty(),
-            errors_const::ERR_EMPTY_ADDRESSES
+            "address" => errors_const::ERR_EMPTY_ADDRESSES
         );
 
         ensure!(
             !namespace_id.is_empty(),
-            errors_const::ERR_EMPTY_NAMESPACE_ID
+            "namespace_id" => errors_const::ERR_EMPTY_NAMESPACE_ID
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
@@ -43,11 +43,11 @@ impl MosaicAliasTransaction {
         action_type: AliasActionType,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(!mosaic_id.is_empty(), errors_const::ERR_EMPTY_MOSAIC_ID);
+        ensure!(!mosaic_id.is_empty(), "mosaic_id" => errors_const::ERR_EMPTY_MOSAIC_ID);
 
         ensure!(
             !namespace_id.is_empty(),
-            errors_const::ERR_EMPTY_NAMESPACE_ID
+            "namespace_id" => errors_const::ERR_EMPTY_NAMESPACE_ID
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
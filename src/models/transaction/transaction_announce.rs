@@ -0,0 +1,85 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::time::Duration;
+
+use crate::models::{mosaic::Mosaic, uint_64::Uint64};
+
+use super::{Hash, Height, Transaction};
+
+/// The default number of blocks the hash lock of an aggregate bonded stays active.
+const DEFAULT_LOCK_DURATION: u64 = 480;
+
+/// A transaction confirmed after being announced.
+#[derive(Debug)]
+pub struct ConfirmedTransaction {
+    pub transaction: Box<dyn Transaction>,
+    /// The height of the block the transaction was included in.
+    pub height: Height,
+}
+
+/// Settings of the aggregate bonded workflow.
+#[derive(Debug, Clone)]
+pub struct AggregateBondedOptions {
+    /// The funds locked until the aggregate bonded is confirmed, `10 XPX` by default.
+    pub lock_mosaic: Mosaic,
+    /// The number of blocks the funds stay locked.
+    pub lock_duration: Uint64,
+    /// How long to wait for the hash lock to be confirmed.
+    pub lock_timeout: Duration,
+    /// How long to wait for the cosignatures once the aggregate bonded is announced.
+    pub cosignatures_timeout: Duration,
+}
+
+impl AggregateBondedOptions {
+    pub fn new() -> Self {
+        AggregateBondedOptions::default()
+    }
+
+    pub fn with_lock_mosaic(mut self, mosaic: Mosaic) -> Self {
+        self.lock_mosaic = mosaic;
+        self
+    }
+
+    pub fn with_lock_duration(mut self, duration: u64) -> Self {
+        self.lock_duration = Uint64::new(duration);
+        self
+    }
+
+    pub fn with_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = timeout;
+        self
+    }
+
+    pub fn with_cosignatures_timeout(mut self, timeout: Duration) -> Self {
+        self.cosignatures_timeout = timeout;
+        self
+    }
+}
+
+impl Default for AggregateBondedOptions {
+    fn default() -> Self {
+        AggregateBondedOptions {
+            lock_mosaic: Mosaic::xpx_relative(10),
+            lock_duration: Uint64::new(DEFAULT_LOCK_DURATION),
+            lock_timeout: Duration::from_secs(2 * 60),
+            cosignatures_timeout: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+/// The steps reported by the aggregate bonded workflow.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregateBondedProgress {
+    /// The hash lock with the given hash was announced.
+    LockAnnounced(Hash),
+    /// The hash lock was confirmed at the given height.
+    LockConfirmed(Height),
+    /// The aggregate bonded with the given hash was announced.
+    BondedAnnounced(Hash),
+    /// Every cosignature was received and the aggregate was confirmed at the given height.
+    CosignaturesComplete(Height),
+}
@@ -22,13 +22,13 @@ impl Signature {
     }
     pub fn from_string(t: String) -> crate::Result<Self> {
         is_hex(&t);
-        ensure!(!t.is_empty(), ERR_INVALID_DATA_LENGTH);
+        ensure!(!t.is_empty(), "signature" => ERR_INVALID_DATA_LENGTH);
 
         Ok(Self::from_bytes(hex_to_vec_u8(&t).as_slice())?)
     }
 
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
-        ensure!(bytes.len() == 64, ERR_INVALID_DATA_LENGTH);
+        ensure!(bytes.len() == 64, "signature" => ERR_INVALID_DATA_LENGTH);
 
         let mut bits: [u8; 64] = [0u8; 64];
         bits.copy_from_slice(&bytes[..64]);
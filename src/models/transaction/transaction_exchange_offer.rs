@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, fb::FlatBufferBuilder, serde_json::Value};
+use {::std::any::Any, ::std::fmt, fb::FlatBufferBuilder, serde_json::Value};
 
 use crate::transaction::schema::exchange_offer_transaction_schema;
 use crate::{
@@ -36,7 +36,7 @@ impl ExchangeOfferTransaction {
         confirmations: Vec<ExchangeConfirmation>,
         network_type: NetworkType,
     ) -> Result<Self> {
-        ensure!(!confirmations.is_empty(), errors_const::ERR_EMPTY_ADDRESSES);
+        ensure!(!confirmations.is_empty(), "confirmations" => errors_const::ERR_EMPTY_ADDRESSES);
 
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {failure::_core::any::Any, serde_json::Value, std::fmt};
+use {::std::any::Any, serde_json::Value, std::fmt};
 
 use crate::{
     models::{
@@ -42,7 +42,7 @@ impl LockFundsTransaction {
     ) -> Result<Self> {
         ensure!(
             signed_tx.get_type() == EntityTypeEnum::AggregateBonded,
-            "signed_tx must be of type AggregateBonded."
+            "signed_tx" => "signed_tx must be of type AggregateBonded."
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
@@ -92,7 +92,7 @@ impl Transaction for LockFundsTransaction {
         let mosaic_id_vector = _builder.create_vector_direct(&self.mosaic.asset_id.to_u32_array());
         let amount_vector = _builder.create_vector_direct(&self.mosaic.amount.to_i32_array());
         let duration_vector = _builder.create_vector_direct(&self.duration.to_i32_array());
-        let hash_vector = _builder.create_vector_direct(&self.signed_transaction.hash_to_bytes());
+        let hash_vector = _builder.create_vector_direct(&self.signed_transaction.hash_to_bytes()?);
 
         let abs_vector = self.abs_transaction.build_vector(&mut _builder);
 
@@ -16,7 +16,10 @@ use crate::models::{
     uint_64::Uint64,
 };
 
-use super::{deadline::Deadline, AbstractTransaction, EntityTypeEnum, SignedTransaction};
+use super::{
+    deadline::Deadline, AbstractTransaction, EntityTypeEnum, SignedTransaction, TransactionGroup,
+    TransactionStatusCode,
+};
 
 pub type Amount = Uint64;
 
@@ -82,8 +85,8 @@ impl<'b> AbsVector<'b> {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionStatus {
-    pub group: String,
-    pub status: String,
+    pub group: TransactionGroup,
+    pub status: TransactionStatusCode,
     pub hash: Hash,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deadline: Option<Deadline>,
@@ -100,8 +103,8 @@ impl TransactionStatus {
         height: Option<Height>,
     ) -> Self {
         TransactionStatus {
-            group,
-            status,
+            group: TransactionGroup::from(group),
+            status: TransactionStatusCode::from(status),
             hash,
             deadline,
             height,
@@ -109,15 +112,25 @@ impl TransactionStatus {
     }
 
     pub fn is_success(&self) -> bool {
-        self.status == "Success"
+        self.status.is_success()
     }
 
     pub fn is_confirmed(&self) -> bool {
-        self.is_success() && self.group == "confirmed"
+        self.is_success() && self.group == TransactionGroup::Confirmed
     }
 
     pub fn is_partial(&self) -> bool {
-        self.is_success() && self.group == "partial"
+        self.is_success() && self.group == TransactionGroup::Partial
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.group == TransactionGroup::Failed || self.status.is_failure()
+    }
+
+    /// A human-readable description of the status, e.g. "the account does not have enough
+    /// balance" for `Failure_Core_Insufficient_Balance`.
+    pub fn description(&self) -> &str {
+        self.status.description()
     }
 }
 
@@ -166,7 +179,7 @@ pub trait AbsTransaction {
 
 pub trait Transaction
 where
-    Self: fmt::Debug + AbsTransaction + Downcast + Sync + erased_serde::Serialize,
+    Self: fmt::Debug + AbsTransaction + Downcast + Send + Sync + erased_serde::Serialize,
 {
     fn size(&self) -> usize;
 
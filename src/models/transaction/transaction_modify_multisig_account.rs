@@ -12,7 +12,7 @@ use crate::{
         consts::{KEY_SIZE, MODIFY_MULTISIG_HEADER_SIZE},
         errors_const::ERR_EMPTY_MODIFICATIONS,
         multisig::CosignatoryModification,
-        network::NetworkType,
+        network::{NetworkConfig, NetworkType},
     },
     Result,
 };
@@ -35,16 +35,38 @@ pub struct ModifyMultisigAccountTransaction {
 }
 
 impl ModifyMultisigAccountTransaction {
+    /// Same as [ModifyMultisigAccountTransaction::new_with_config] with the default
+    /// [NetworkConfig].
     pub fn new(
         deadline: Deadline,
         min_approval_delta: i8,
         min_removal_delta: i8,
         modifications: Vec<CosignatoryModification>,
         network_type: NetworkType,
+    ) -> Result<Self> {
+        Self::new_with_config(
+            deadline,
+            min_approval_delta,
+            min_removal_delta,
+            modifications,
+            &NetworkConfig::default(),
+            network_type,
+        )
+    }
+
+    /// Modifies a multisig account; the cosignatories added are checked against the multisig
+    /// limits of `config`.
+    pub fn new_with_config(
+        deadline: Deadline,
+        min_approval_delta: i8,
+        min_removal_delta: i8,
+        modifications: Vec<CosignatoryModification>,
+        config: &NetworkConfig,
+        network_type: NetworkType,
     ) -> Result<Self> {
         ensure!(
             !modifications.is_empty() && min_approval_delta != 0 && min_removal_delta != 0,
-            ERR_EMPTY_MODIFICATIONS
+            "modifications" => ERR_EMPTY_MODIFICATIONS
         );
 
         let abs_tx = AbstractTransaction {
@@ -58,12 +80,16 @@ impl ModifyMultisigAccountTransaction {
             deadline: Some(deadline),
         };
 
-        Ok(Self {
+        let transaction = Self {
             abs_transaction: abs_tx,
             min_removal_delta,
             min_approval_delta,
             modifications,
-        })
+        };
+
+        config.validate(&transaction)?;
+
+        Ok(transaction)
     }
 }
 
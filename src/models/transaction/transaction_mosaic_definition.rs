@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, serde_json::Value};
+use {::std::any::Any, ::std::fmt, serde_json::Value};
 
 use crate::{
     models::{
@@ -12,7 +12,7 @@ use crate::{
         asset_id_model::AssetId,
         consts::{MOSAIC_DEFINITION_TRANSACTION_HEADER_SIZE, MOSAIC_OPTIONAL_PROPERTY_SIZE},
         mosaic::{MosaicId, MosaicNonce, MosaicProperties, SUPPLY_MUTABLE, TRANSFERABLE},
-        network::NetworkType,
+        network::{NetworkConfig, NetworkType},
     },
     Result,
 };
@@ -36,12 +36,32 @@ pub struct MosaicDefinitionTransaction {
 }
 
 impl MosaicDefinitionTransaction {
+    /// Same as [MosaicDefinitionTransaction::new_with_config] with the default [NetworkConfig].
     pub fn new(
         deadline: Deadline,
         nonce: MosaicNonce,
         owner_public_account: PublicAccount,
         properties: MosaicProperties,
         network_type: NetworkType,
+    ) -> Result<Self> {
+        Self::new_with_config(
+            deadline,
+            nonce,
+            owner_public_account,
+            properties,
+            &NetworkConfig::default(),
+            network_type,
+        )
+    }
+
+    /// Defines a mosaic whose properties are checked against the mosaic limits of `config`.
+    pub fn new_with_config(
+        deadline: Deadline,
+        nonce: MosaicNonce,
+        owner_public_account: PublicAccount,
+        properties: MosaicProperties,
+        config: &NetworkConfig,
+        network_type: NetworkType,
     ) -> Result<Self> {
         let abs_tx = AbstractTransaction::new_from_type(
             deadline,
@@ -52,12 +72,16 @@ impl MosaicDefinitionTransaction {
 
         let mosaic_id = MosaicId::from_nonce_and_owner(nonce.clone(), owner_public_account);
 
-        Ok(Self {
+        let transaction = Self {
             abs_transaction: abs_tx,
             properties,
             mosaic_nonce: nonce,
             mosaic_id,
-        })
+        };
+
+        config.validate(&transaction)?;
+
+        Ok(transaction)
     }
 }
 
@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, serde_json::Value};
+use {::std::any::Any, ::std::fmt, serde_json::Value};
 
 use crate::{
     models::{
@@ -0,0 +1,111 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use std::fmt;
+
+use super::{AbsTransaction, Height, Timestamp, Transaction};
+
+/// The order in which the node returns account transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TransactionOrdering {
+    /// Oldest transactions first (`id`).
+    Asc,
+    /// Newest transactions first (`-id`).
+    Desc,
+}
+
+impl TransactionOrdering {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionOrdering::Asc => "id",
+            TransactionOrdering::Desc => "-id",
+        }
+    }
+}
+
+impl Default for TransactionOrdering {
+    fn default() -> Self {
+        TransactionOrdering::Desc
+    }
+}
+
+impl fmt::Display for TransactionOrdering {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Ends a transactions stream once the walk goes past the given boundary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransactionsStop {
+    /// Stop at the first transaction below (`Desc`) or above (`Asc`) this height.
+    Height(Height),
+    /// Stop at the first transaction whose deadline is before (`Desc`) or after (`Asc`)
+    /// this timestamp. Deadlines are set by the signer and only roughly follow the
+    /// block order, so this is a cut-off rather than an exact range.
+    Deadline(Timestamp),
+    /// Stop after this number of transactions.
+    Count(usize),
+}
+
+/// Paging options of the account transactions streams.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransactionsPagination {
+    pub page_size: Option<i32>,
+    pub ordering: TransactionOrdering,
+    pub stop: Option<TransactionsStop>,
+}
+
+impl TransactionsPagination {
+    pub fn new(page_size: Option<i32>, ordering: TransactionOrdering) -> Self {
+        TransactionsPagination {
+            page_size,
+            ordering,
+            stop: None,
+        }
+    }
+
+    pub fn with_stop(mut self, stop: TransactionsStop) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Returns `true` if `transaction` lies past the stop condition and the stream has to end.
+    /// `yielded` is the number of transactions already returned by the stream.
+    pub(crate) fn is_past_stop(
+        &self,
+        transaction: Option<&dyn Transaction>,
+        yielded: usize,
+    ) -> bool {
+        let desc = self.ordering == TransactionOrdering::Desc;
+
+        match (self.stop, transaction) {
+            (Some(TransactionsStop::Count(count)), _) => yielded >= count,
+            (Some(TransactionsStop::Height(height)), Some(tx)) => {
+                let tx_height = tx.height().as_u64();
+                if desc {
+                    tx_height < height.as_u64()
+                } else {
+                    tx_height > height.as_u64()
+                }
+            }
+            (Some(TransactionsStop::Deadline(stop)), Some(tx)) => {
+                match tx.abs_transaction().deadline {
+                    Some(deadline) if desc => deadline.to_timestamp() < stop,
+                    Some(deadline) => deadline.to_timestamp() > stop,
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Default for TransactionsPagination {
+    fn default() -> Self {
+        TransactionsPagination::new(None, TransactionOrdering::default())
+    }
+}
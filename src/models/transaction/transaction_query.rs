@@ -0,0 +1,165 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use super::{
+    EntityTypeEnum, Height, Timestamp, Transaction, TransactionOrdering, TransactionsPagination,
+    TransactionsStop,
+};
+
+/// Which side of the account transactions to walk.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TransactionDirection {
+    /// Transactions where the account is the sender or the receiver.
+    All,
+    /// Transactions where the account is the receiver.
+    Incoming,
+    /// Transactions signed by the account.
+    Outgoing,
+}
+
+impl Default for TransactionDirection {
+    fn default() -> Self {
+        TransactionDirection::All
+    }
+}
+
+/// Filters applied to the confirmed account transactions.
+///
+/// The direction, ordering and page size are sent to the node. The entity types, height range
+/// and time range are applied to every page received; both ranges also end the walk once it
+/// goes past them in the ordering direction. The time range is checked against the timestamp
+/// of the block of each transaction, read once per height.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccountTransactionsQuery {
+    pub direction: TransactionDirection,
+    pub entity_types: Vec<EntityTypeEnum>,
+    pub from_height: Option<Height>,
+    pub to_height: Option<Height>,
+    pub from_time: Option<Timestamp>,
+    pub to_time: Option<Timestamp>,
+    pub pagination: TransactionsPagination,
+}
+
+impl AccountTransactionsQuery {
+    pub fn new() -> Self {
+        AccountTransactionsQuery::default()
+    }
+
+    pub fn with_direction(mut self, direction: TransactionDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Only keep transactions of the given type. Can be called several times.
+    pub fn with_entity_type(mut self, entity_type: EntityTypeEnum) -> Self {
+        if !self.entity_types.contains(&entity_type) {
+            self.entity_types.push(entity_type);
+        }
+        self
+    }
+
+    pub fn with_entity_types(mut self, entity_types: Vec<EntityTypeEnum>) -> Self {
+        for entity_type in entity_types {
+            self = self.with_entity_type(entity_type);
+        }
+        self
+    }
+
+    /// Inclusive height range. `None` leaves the bound open.
+    pub fn with_height_range(mut self, from: Option<Height>, to: Option<Height>) -> Self {
+        self.from_height = from;
+        self.to_height = to;
+        self
+    }
+
+    /// Inclusive range of the timestamps of the blocks the transactions were confirmed in.
+    /// `None` leaves the bound open.
+    pub fn with_time_range(mut self, from: Option<Timestamp>, to: Option<Timestamp>) -> Self {
+        self.from_time = from;
+        self.to_time = to;
+        self
+    }
+
+    pub fn with_page_size(mut self, page_size: i32) -> Self {
+        self.pagination.page_size = Some(page_size);
+        self
+    }
+
+    pub fn with_ordering(mut self, ordering: TransactionOrdering) -> Self {
+        self.pagination.ordering = ordering;
+        self
+    }
+
+    pub fn with_stop(mut self, stop: TransactionsStop) -> Self {
+        self.pagination.stop = Some(stop);
+        self
+    }
+
+    /// Returns `true` if `transaction` passes the entity type and height filters of the query.
+    pub fn matches(&self, transaction: &dyn Transaction) -> bool {
+        if !self.entity_types.is_empty() && !self.entity_types.contains(&transaction.entity_type())
+        {
+            return false;
+        }
+
+        let height = transaction.height().as_u64();
+        if self
+            .from_height
+            .map_or(false, |from| height < from.as_u64())
+            || self.to_height.map_or(false, |to| height > to.as_u64())
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns `true` if `block_time`, the timestamp of the block of a transaction, is in the
+    /// time range of the query.
+    pub fn matches_time(&self, block_time: Timestamp) -> bool {
+        !(self.from_time.map_or(false, |from| block_time < from)
+            || self.to_time.map_or(false, |to| block_time > to))
+    }
+
+    pub(crate) fn has_time_range(&self) -> bool {
+        self.from_time.is_some() || self.to_time.is_some()
+    }
+
+    /// Returns `true` if no transaction after `transaction` in the walk can match the
+    /// height range, or the time range given the timestamp of its block.
+    pub(crate) fn is_past_range(
+        &self,
+        transaction: &dyn Transaction,
+        block_time: Option<Timestamp>,
+    ) -> bool {
+        let height = transaction.height().as_u64();
+
+        match self.pagination.ordering {
+            TransactionOrdering::Desc => {
+                self.from_height
+                    .map_or(false, |from| height < from.as_u64())
+                    || self
+                        .from_time
+                        .map_or(false, |from| block_time.map_or(false, |time| time < from))
+            }
+            TransactionOrdering::Asc => {
+                self.to_height.map_or(false, |to| height > to.as_u64())
+                    || self
+                        .to_time
+                        .map_or(false, |to| block_time.map_or(false, |time| time > to))
+            }
+        }
+    }
+}
+
+impl From<TransactionsPagination> for AccountTransactionsQuery {
+    fn from(pagination: TransactionsPagination) -> Self {
+        AccountTransactionsQuery {
+            pagination,
+            ..AccountTransactionsQuery::default()
+        }
+    }
+}
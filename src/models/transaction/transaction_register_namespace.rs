@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, serde_json::Value};
+use {::std::any::Any, ::std::fmt, serde_json::Value};
 
 use crate::{
     models::{
@@ -13,7 +13,7 @@ use crate::{
         consts::REGISTER_NAMESPACE_HEADER_SIZE,
         errors_const,
         namespace::{generate_namespace_id, NamespaceId, NamespaceType},
-        network::NetworkType,
+        network::{NetworkConfig, NetworkType},
         uint_64::Uint64,
     },
     Result,
@@ -39,15 +39,35 @@ pub struct RegisterNamespaceTransaction {
 }
 
 impl RegisterNamespaceTransaction {
+    /// Same as [RegisterNamespaceTransaction::create_root_with_config] with the default
+    /// [NetworkConfig].
     pub fn create_root(
         deadline: Deadline,
         namespace_name: &str,
         duration: Uint64,
         network_type: NetworkType,
+    ) -> Result<RegisterNamespaceTransaction> {
+        Self::create_root_with_config(
+            deadline,
+            namespace_name,
+            duration,
+            &NetworkConfig::default(),
+            network_type,
+        )
+    }
+
+    /// Registers the root namespace `namespace_name`, checked against the namespace limits
+    /// of `config`.
+    pub fn create_root_with_config(
+        deadline: Deadline,
+        namespace_name: &str,
+        duration: Uint64,
+        config: &NetworkConfig,
+        network_type: NetworkType,
     ) -> Result<RegisterNamespaceTransaction> {
         ensure!(
-            !namespace_name.is_empty() && namespace_name.len() <= 16,
-            errors_const::ERR_INVALID_NAMESPACE_NAME
+            !namespace_name.is_empty(),
+            "name" => errors_const::ERR_INVALID_NAMESPACE_NAME
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
@@ -57,32 +77,56 @@ impl RegisterNamespaceTransaction {
             network_type,
         );
 
-        let namespace_id = NamespaceId::from_name(namespace_name)?;
+        let namespace_id = config.namespace_id(namespace_name)?;
 
-        Ok(RegisterNamespaceTransaction {
+        let transaction = RegisterNamespaceTransaction {
             abs_transaction: abs_tx,
             namespace_type: NamespaceType::Root,
             namespace_id,
             name: namespace_name.parse().unwrap(),
             duration: Some(duration),
             parent_id: None,
-        })
+        };
+
+        config.validate(&transaction)?;
+
+        Ok(transaction)
     }
 
+    /// Same as [RegisterNamespaceTransaction::create_sub_with_config] with the default
+    /// [NetworkConfig].
     pub fn create_sub(
         deadline: Deadline,
         namespace_name: &'static str,
         parent_id: NamespaceId,
         network_type: NetworkType,
+    ) -> Result<Self> {
+        Self::create_sub_with_config(
+            deadline,
+            namespace_name,
+            parent_id,
+            &NetworkConfig::default(),
+            network_type,
+        )
+    }
+
+    /// Registers `namespace_name` under `parent_id`, checked against the namespace limits
+    /// of `config`.
+    pub fn create_sub_with_config(
+        deadline: Deadline,
+        namespace_name: &str,
+        parent_id: NamespaceId,
+        config: &NetworkConfig,
+        network_type: NetworkType,
     ) -> Result<Self> {
         ensure!(
-            !namespace_name.is_empty() && namespace_name.len() <= 64,
-            errors_const::ERR_INVALID_NAMESPACE_NAME
+            !namespace_name.is_empty(),
+            "name" => errors_const::ERR_INVALID_NAMESPACE_NAME
         );
 
         ensure!(
             parent_id.to_u64() != 0,
-            errors_const::ERR_EMPTY_NAMESPACE_ID
+            "parent_id" => errors_const::ERR_EMPTY_NAMESPACE_ID
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
@@ -94,14 +138,18 @@ impl RegisterNamespaceTransaction {
 
         let namespace_id = generate_namespace_id(namespace_name, parent_id)?;
 
-        Ok(Self {
+        let transaction = Self {
             abs_transaction: abs_tx,
             namespace_type: NamespaceType::Sub,
             namespace_id,
             name: namespace_name.parse().unwrap(),
             duration: None,
             parent_id: Some(parent_id),
-        })
+        };
+
+        config.validate(&transaction)?;
+
+        Ok(transaction)
     }
 }
 
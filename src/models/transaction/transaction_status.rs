@@ -0,0 +1,1247 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::fmt;
+
+/// The group a transaction status belongs to:
+/// * unconfirmed - The transaction is waiting to be included in a block.
+/// * confirmed - The transaction is included in a block.
+/// * failed - The node rejected the transaction.
+/// * partial - The aggregate bonded transaction is waiting for cosignatures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TransactionGroup {
+    Unconfirmed,
+    Confirmed,
+    Failed,
+    Partial,
+    Unknown(String),
+}
+
+impl TransactionGroup {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransactionGroup::Unconfirmed => "unconfirmed",
+            TransactionGroup::Confirmed => "confirmed",
+            TransactionGroup::Failed => "failed",
+            TransactionGroup::Partial => "partial",
+            TransactionGroup::Unknown(group) => group,
+        }
+    }
+}
+
+impl From<&str> for TransactionGroup {
+    fn from(group: &str) -> Self {
+        match group {
+            "unconfirmed" => TransactionGroup::Unconfirmed,
+            "confirmed" => TransactionGroup::Confirmed,
+            "failed" => TransactionGroup::Failed,
+            "partial" => TransactionGroup::Partial,
+            _ => TransactionGroup::Unknown(group.to_string()),
+        }
+    }
+}
+
+impl From<String> for TransactionGroup {
+    fn from(group: String) -> Self {
+        TransactionGroup::from(group.as_str())
+    }
+}
+
+impl From<TransactionGroup> for String {
+    fn from(group: TransactionGroup) -> Self {
+        group.as_str().to_string()
+    }
+}
+
+impl fmt::Display for TransactionGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The validation result the node reports for a transaction.
+///
+/// Codes not known by this version of the SDK are kept as `Unknown`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TransactionStatusCode {
+    Success,
+    Neutral,
+    Failure,
+    CorePastDeadline,
+    CoreFutureDeadline,
+    CoreInsufficientBalance,
+    CoreTooManyTransactions,
+    CoreNemesisAccountSignedAfterNemesisBlock,
+    CoreWrongNetwork,
+    CoreInvalidAddress,
+    CoreInvalidVersion,
+    CoreInvalidTransactionFee,
+    CoreBlockHarvesterIneligible,
+    CoreZeroAddress,
+    CoreZeroPublicKey,
+    CoreNonzeroInternalPadding,
+    CoreAddressCollision,
+    HashExists,
+    SignatureNotVerifiable,
+    AccountLinkInvalidAction,
+    AccountLinkLinkAlreadyExists,
+    AccountLinkLinkDoesNotExist,
+    AccountLinkUnlinkDataInconsistency,
+    AccountLinkRemoteAccountIneligible,
+    AccountLinkRemoteAccountSignerNotAllowed,
+    AccountLinkRemoteAccountParticipantNotAllowed,
+    AggregateTooManyTransactions,
+    AggregateNoTransactions,
+    AggregateTooManyCosignatures,
+    AggregateRedundantCosignatures,
+    AggregateIneligibleCosigners,
+    AggregateMissingCosigners,
+    AggregateUnsupportedBondedTransaction,
+    LockHashInvalidMosaicId,
+    LockHashInvalidMosaicAmount,
+    LockHashHashExists,
+    LockHashUnknownHash,
+    LockHashInactiveHash,
+    LockHashInvalidDuration,
+    LockSecretInvalidHashAlgorithm,
+    LockSecretHashExists,
+    LockSecretHashNotImplemented,
+    LockSecretProofSizeOutOfBounds,
+    LockSecretSecretMismatch,
+    LockSecretUnknownCompositeKey,
+    LockSecretInactiveSecret,
+    LockSecretHashAlgorithmMismatch,
+    LockSecretInvalidDuration,
+    MosaicInvalidDuration,
+    MosaicInvalidName,
+    MosaicNameIdMismatch,
+    MosaicExpired,
+    MosaicOwnerConflict,
+    MosaicIdMismatch,
+    MosaicParentIdConflict,
+    MosaicInvalidProperty,
+    MosaicInvalidFlags,
+    MosaicInvalidDivisibility,
+    MosaicInvalidSupplyChangeDirection,
+    MosaicInvalidSupplyChangeAmount,
+    MosaicInvalidId,
+    MosaicModificationDisallowed,
+    MosaicModificationNoChanges,
+    MosaicSupplyImmutable,
+    MosaicSupplyNegative,
+    MosaicSupplyExceeded,
+    MosaicNonTransferable,
+    MosaicMaxMosaicsExceeded,
+    MultisigModifyAccountInBothSets,
+    MultisigModifyMultipleDeletes,
+    MultisigModifyRedundantModifications,
+    MultisigModifyUnknownMultisigAccount,
+    MultisigModifyNotACosigner,
+    MultisigModifyAlreadyACosigner,
+    MultisigModifyMinSettingOutOfRange,
+    MultisigModifyMinSettingLargerThanNumCosignatories,
+    MultisigModifyUnsupportedModificationType,
+    MultisigModifyMaxCosignedAccounts,
+    MultisigModifyMaxCosigners,
+    MultisigModifyLoop,
+    MultisigModifyMaxMultisigDepth,
+    MultisigOperationNotPermittedByAccount,
+    NamespaceInvalidDuration,
+    NamespaceInvalidName,
+    NamespaceNameIdMismatch,
+    NamespaceExpired,
+    NamespaceOwnerConflict,
+    NamespaceIdMismatch,
+    NamespaceInvalidNamespaceType,
+    NamespaceRootNameReserved,
+    NamespaceTooDeep,
+    NamespaceParentUnknown,
+    NamespaceAlreadyExists,
+    NamespaceAlreadyActive,
+    NamespaceEternalAfterNemesisBlock,
+    NamespaceMaxChildrenExceeded,
+    NamespaceAliasInvalidAction,
+    NamespaceUnknown,
+    NamespaceAliasAlreadyExists,
+    NamespaceUnknownAlias,
+    NamespaceAliasOwnerConflict,
+    NamespaceAliasUnlinkTypeInconsistency,
+    NamespaceAliasUnlinkDataInconsistency,
+    NamespaceAliasInvalidAddress,
+    PropertyInvalidPropertyType,
+    PropertyModificationTypeInvalid,
+    PropertyModificationAddressInvalid,
+    PropertyModificationOperationTypeIncompatible,
+    PropertyModifyUnsupportedModificationType,
+    PropertyModificationRedundant,
+    PropertyModificationNotAllowed,
+    PropertyModificationCountExceeded,
+    PropertyValuesCountExceeded,
+    PropertyValueInvalid,
+    PropertySignerAddressInteractionNotAllowed,
+    PropertyMosaicTransferNotAllowed,
+    PropertyTransactionTypeNotAllowed,
+    TransferMessageTooLarge,
+    TransferOutOfOrderMosaics,
+    TransferTooManyMosaics,
+    ExchangeOfferExists,
+    ExchangeAccountDoesntHaveAnyOffer,
+    ExchangeOfferDoesntExist,
+    ExchangeOfferExpired,
+    ExchangeNotEnoughUnitsInOffer,
+    ExchangeBuyingOwnUnitsIsNotAllowed,
+    ExchangeInvalidPrice,
+    ExchangeInvalidDuration,
+    ChainUnlinked,
+    ChainBlockNotHit,
+    ChainBlockInconsistentStateHash,
+    ChainBlockInconsistentReceiptsHash,
+    ChainUnconfirmedCacheTooFull,
+    ConsumerEmptyInput,
+    ConsumerBlockTransactionsHashMismatch,
+    ConsumerHashInRecencyCache,
+    ConsumerRemoteChainTooManyBlocks,
+    ConsumerRemoteChainImproperLink,
+    ConsumerRemoteChainDuplicateTransactions,
+    ConsumerRemoteChainUnlinked,
+    ConsumerRemoteChainMismatchedDifficulties,
+    ConsumerRemoteChainScoreNotBetter,
+    ConsumerRemoteChainTooFarBehind,
+    ConsumerRemoteChainTooFarInFuture,
+    ExtensionPartialTransactionCachePrune,
+    ExtensionPartialTransactionDependencyRemoved,
+    Unknown(String),
+}
+
+impl TransactionStatusCode {
+    pub fn is_success(&self) -> bool {
+        *self == TransactionStatusCode::Success
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.code().starts_with("Failure")
+    }
+
+    /// The code as reported by the node, e.g. `Failure_Core_Insufficient_Balance`.
+    pub fn code(&self) -> &str {
+        match self {
+            TransactionStatusCode::Success => "Success",
+            TransactionStatusCode::Neutral => "Neutral",
+            TransactionStatusCode::Failure => "Failure",
+            TransactionStatusCode::CorePastDeadline => "Failure_Core_Past_Deadline",
+            TransactionStatusCode::CoreFutureDeadline => "Failure_Core_Future_Deadline",
+            TransactionStatusCode::CoreInsufficientBalance => "Failure_Core_Insufficient_Balance",
+            TransactionStatusCode::CoreTooManyTransactions => "Failure_Core_Too_Many_Transactions",
+            TransactionStatusCode::CoreNemesisAccountSignedAfterNemesisBlock => {
+                "Failure_Core_Nemesis_Account_Signed_After_Nemesis_Block"
+            }
+            TransactionStatusCode::CoreWrongNetwork => "Failure_Core_Wrong_Network",
+            TransactionStatusCode::CoreInvalidAddress => "Failure_Core_Invalid_Address",
+            TransactionStatusCode::CoreInvalidVersion => "Failure_Core_Invalid_Version",
+            TransactionStatusCode::CoreInvalidTransactionFee => {
+                "Failure_Core_Invalid_Transaction_Fee"
+            }
+            TransactionStatusCode::CoreBlockHarvesterIneligible => {
+                "Failure_Core_Block_Harvester_Ineligible"
+            }
+            TransactionStatusCode::CoreZeroAddress => "Failure_Core_Zero_Address",
+            TransactionStatusCode::CoreZeroPublicKey => "Failure_Core_Zero_Public_Key",
+            TransactionStatusCode::CoreNonzeroInternalPadding => {
+                "Failure_Core_Nonzero_Internal_Padding"
+            }
+            TransactionStatusCode::CoreAddressCollision => "Failure_Core_Address_Collision",
+            TransactionStatusCode::HashExists => "Failure_Hash_Exists",
+            TransactionStatusCode::SignatureNotVerifiable => "Failure_Signature_Not_Verifiable",
+            TransactionStatusCode::AccountLinkInvalidAction => "Failure_AccountLink_Invalid_Action",
+            TransactionStatusCode::AccountLinkLinkAlreadyExists => {
+                "Failure_AccountLink_Link_Already_Exists"
+            }
+            TransactionStatusCode::AccountLinkLinkDoesNotExist => {
+                "Failure_AccountLink_Link_Does_Not_Exist"
+            }
+            TransactionStatusCode::AccountLinkUnlinkDataInconsistency => {
+                "Failure_AccountLink_Unlink_Data_Inconsistency"
+            }
+            TransactionStatusCode::AccountLinkRemoteAccountIneligible => {
+                "Failure_AccountLink_Remote_Account_Ineligible"
+            }
+            TransactionStatusCode::AccountLinkRemoteAccountSignerNotAllowed => {
+                "Failure_AccountLink_Remote_Account_Signer_Not_Allowed"
+            }
+            TransactionStatusCode::AccountLinkRemoteAccountParticipantNotAllowed => {
+                "Failure_AccountLink_Remote_Account_Participant_Not_Allowed"
+            }
+            TransactionStatusCode::AggregateTooManyTransactions => {
+                "Failure_Aggregate_Too_Many_Transactions"
+            }
+            TransactionStatusCode::AggregateNoTransactions => "Failure_Aggregate_No_Transactions",
+            TransactionStatusCode::AggregateTooManyCosignatures => {
+                "Failure_Aggregate_Too_Many_Cosignatures"
+            }
+            TransactionStatusCode::AggregateRedundantCosignatures => {
+                "Failure_Aggregate_Redundant_Cosignatures"
+            }
+            TransactionStatusCode::AggregateIneligibleCosigners => {
+                "Failure_Aggregate_Ineligible_Cosigners"
+            }
+            TransactionStatusCode::AggregateMissingCosigners => {
+                "Failure_Aggregate_Missing_Cosigners"
+            }
+            TransactionStatusCode::AggregateUnsupportedBondedTransaction => {
+                "Failure_Aggregate_Unsupported_Bonded_Transaction"
+            }
+            TransactionStatusCode::LockHashInvalidMosaicId => "Failure_LockHash_Invalid_Mosaic_Id",
+            TransactionStatusCode::LockHashInvalidMosaicAmount => {
+                "Failure_LockHash_Invalid_Mosaic_Amount"
+            }
+            TransactionStatusCode::LockHashHashExists => "Failure_LockHash_Hash_Exists",
+            TransactionStatusCode::LockHashUnknownHash => "Failure_LockHash_Unknown_Hash",
+            TransactionStatusCode::LockHashInactiveHash => "Failure_LockHash_Inactive_Hash",
+            TransactionStatusCode::LockHashInvalidDuration => "Failure_LockHash_Invalid_Duration",
+            TransactionStatusCode::LockSecretInvalidHashAlgorithm => {
+                "Failure_LockSecret_Invalid_Hash_Algorithm"
+            }
+            TransactionStatusCode::LockSecretHashExists => "Failure_LockSecret_Hash_Exists",
+            TransactionStatusCode::LockSecretHashNotImplemented => {
+                "Failure_LockSecret_Hash_Not_Implemented"
+            }
+            TransactionStatusCode::LockSecretProofSizeOutOfBounds => {
+                "Failure_LockSecret_Proof_Size_Out_Of_Bounds"
+            }
+            TransactionStatusCode::LockSecretSecretMismatch => "Failure_LockSecret_Secret_Mismatch",
+            TransactionStatusCode::LockSecretUnknownCompositeKey => {
+                "Failure_LockSecret_Unknown_Composite_Key"
+            }
+            TransactionStatusCode::LockSecretInactiveSecret => "Failure_LockSecret_Inactive_Secret",
+            TransactionStatusCode::LockSecretHashAlgorithmMismatch => {
+                "Failure_LockSecret_Hash_Algorithm_Mismatch"
+            }
+            TransactionStatusCode::LockSecretInvalidDuration => {
+                "Failure_LockSecret_Invalid_Duration"
+            }
+            TransactionStatusCode::MosaicInvalidDuration => "Failure_Mosaic_Invalid_Duration",
+            TransactionStatusCode::MosaicInvalidName => "Failure_Mosaic_Invalid_Name",
+            TransactionStatusCode::MosaicNameIdMismatch => "Failure_Mosaic_Name_Id_Mismatch",
+            TransactionStatusCode::MosaicExpired => "Failure_Mosaic_Expired",
+            TransactionStatusCode::MosaicOwnerConflict => "Failure_Mosaic_Owner_Conflict",
+            TransactionStatusCode::MosaicIdMismatch => "Failure_Mosaic_Id_Mismatch",
+            TransactionStatusCode::MosaicParentIdConflict => "Failure_Mosaic_Parent_Id_Conflict",
+            TransactionStatusCode::MosaicInvalidProperty => "Failure_Mosaic_Invalid_Property",
+            TransactionStatusCode::MosaicInvalidFlags => "Failure_Mosaic_Invalid_Flags",
+            TransactionStatusCode::MosaicInvalidDivisibility => {
+                "Failure_Mosaic_Invalid_Divisibility"
+            }
+            TransactionStatusCode::MosaicInvalidSupplyChangeDirection => {
+                "Failure_Mosaic_Invalid_Supply_Change_Direction"
+            }
+            TransactionStatusCode::MosaicInvalidSupplyChangeAmount => {
+                "Failure_Mosaic_Invalid_Supply_Change_Amount"
+            }
+            TransactionStatusCode::MosaicInvalidId => "Failure_Mosaic_Invalid_Id",
+            TransactionStatusCode::MosaicModificationDisallowed => {
+                "Failure_Mosaic_Modification_Disallowed"
+            }
+            TransactionStatusCode::MosaicModificationNoChanges => {
+                "Failure_Mosaic_Modification_No_Changes"
+            }
+            TransactionStatusCode::MosaicSupplyImmutable => "Failure_Mosaic_Supply_Immutable",
+            TransactionStatusCode::MosaicSupplyNegative => "Failure_Mosaic_Supply_Negative",
+            TransactionStatusCode::MosaicSupplyExceeded => "Failure_Mosaic_Supply_Exceeded",
+            TransactionStatusCode::MosaicNonTransferable => "Failure_Mosaic_Non_Transferable",
+            TransactionStatusCode::MosaicMaxMosaicsExceeded => {
+                "Failure_Mosaic_Max_Mosaics_Exceeded"
+            }
+            TransactionStatusCode::MultisigModifyAccountInBothSets => {
+                "Failure_Multisig_Modify_Account_In_Both_Sets"
+            }
+            TransactionStatusCode::MultisigModifyMultipleDeletes => {
+                "Failure_Multisig_Modify_Multiple_Deletes"
+            }
+            TransactionStatusCode::MultisigModifyRedundantModifications => {
+                "Failure_Multisig_Modify_Redundant_Modifications"
+            }
+            TransactionStatusCode::MultisigModifyUnknownMultisigAccount => {
+                "Failure_Multisig_Modify_Unknown_Multisig_Account"
+            }
+            TransactionStatusCode::MultisigModifyNotACosigner => {
+                "Failure_Multisig_Modify_Not_A_Cosigner"
+            }
+            TransactionStatusCode::MultisigModifyAlreadyACosigner => {
+                "Failure_Multisig_Modify_Already_A_Cosigner"
+            }
+            TransactionStatusCode::MultisigModifyMinSettingOutOfRange => {
+                "Failure_Multisig_Modify_Min_Setting_Out_Of_Range"
+            }
+            TransactionStatusCode::MultisigModifyMinSettingLargerThanNumCosignatories => {
+                "Failure_Multisig_Modify_Min_Setting_Larger_Than_Num_Cosignatories"
+            }
+            TransactionStatusCode::MultisigModifyUnsupportedModificationType => {
+                "Failure_Multisig_Modify_Unsupported_Modification_Type"
+            }
+            TransactionStatusCode::MultisigModifyMaxCosignedAccounts => {
+                "Failure_Multisig_Modify_Max_Cosigned_Accounts"
+            }
+            TransactionStatusCode::MultisigModifyMaxCosigners => {
+                "Failure_Multisig_Modify_Max_Cosigners"
+            }
+            TransactionStatusCode::MultisigModifyLoop => "Failure_Multisig_Modify_Loop",
+            TransactionStatusCode::MultisigModifyMaxMultisigDepth => {
+                "Failure_Multisig_Modify_Max_Multisig_Depth"
+            }
+            TransactionStatusCode::MultisigOperationNotPermittedByAccount => {
+                "Failure_Multisig_Operation_Not_Permitted_By_Account"
+            }
+            TransactionStatusCode::NamespaceInvalidDuration => "Failure_Namespace_Invalid_Duration",
+            TransactionStatusCode::NamespaceInvalidName => "Failure_Namespace_Invalid_Name",
+            TransactionStatusCode::NamespaceNameIdMismatch => "Failure_Namespace_Name_Id_Mismatch",
+            TransactionStatusCode::NamespaceExpired => "Failure_Namespace_Expired",
+            TransactionStatusCode::NamespaceOwnerConflict => "Failure_Namespace_Owner_Conflict",
+            TransactionStatusCode::NamespaceIdMismatch => "Failure_Namespace_Id_Mismatch",
+            TransactionStatusCode::NamespaceInvalidNamespaceType => {
+                "Failure_Namespace_Invalid_Namespace_Type"
+            }
+            TransactionStatusCode::NamespaceRootNameReserved => {
+                "Failure_Namespace_Root_Name_Reserved"
+            }
+            TransactionStatusCode::NamespaceTooDeep => "Failure_Namespace_Too_Deep",
+            TransactionStatusCode::NamespaceParentUnknown => "Failure_Namespace_Parent_Unknown",
+            TransactionStatusCode::NamespaceAlreadyExists => "Failure_Namespace_Already_Exists",
+            TransactionStatusCode::NamespaceAlreadyActive => "Failure_Namespace_Already_Active",
+            TransactionStatusCode::NamespaceEternalAfterNemesisBlock => {
+                "Failure_Namespace_Eternal_After_Nemesis_Block"
+            }
+            TransactionStatusCode::NamespaceMaxChildrenExceeded => {
+                "Failure_Namespace_Max_Children_Exceeded"
+            }
+            TransactionStatusCode::NamespaceAliasInvalidAction => {
+                "Failure_Namespace_Alias_Invalid_Action"
+            }
+            TransactionStatusCode::NamespaceUnknown => "Failure_Namespace_Unknown",
+            TransactionStatusCode::NamespaceAliasAlreadyExists => {
+                "Failure_Namespace_Alias_Already_Exists"
+            }
+            TransactionStatusCode::NamespaceUnknownAlias => "Failure_Namespace_Unknown_Alias",
+            TransactionStatusCode::NamespaceAliasOwnerConflict => {
+                "Failure_Namespace_Alias_Owner_Conflict"
+            }
+            TransactionStatusCode::NamespaceAliasUnlinkTypeInconsistency => {
+                "Failure_Namespace_Alias_Unlink_Type_Inconsistency"
+            }
+            TransactionStatusCode::NamespaceAliasUnlinkDataInconsistency => {
+                "Failure_Namespace_Alias_Unlink_Data_Inconsistency"
+            }
+            TransactionStatusCode::NamespaceAliasInvalidAddress => {
+                "Failure_Namespace_Alias_Invalid_Address"
+            }
+            TransactionStatusCode::PropertyInvalidPropertyType => {
+                "Failure_Property_Invalid_Property_Type"
+            }
+            TransactionStatusCode::PropertyModificationTypeInvalid => {
+                "Failure_Property_Modification_Type_Invalid"
+            }
+            TransactionStatusCode::PropertyModificationAddressInvalid => {
+                "Failure_Property_Modification_Address_Invalid"
+            }
+            TransactionStatusCode::PropertyModificationOperationTypeIncompatible => {
+                "Failure_Property_Modification_Operation_Type_Incompatible"
+            }
+            TransactionStatusCode::PropertyModifyUnsupportedModificationType => {
+                "Failure_Property_Modify_Unsupported_Modification_Type"
+            }
+            TransactionStatusCode::PropertyModificationRedundant => {
+                "Failure_Property_Modification_Redundant"
+            }
+            TransactionStatusCode::PropertyModificationNotAllowed => {
+                "Failure_Property_Modification_Not_Allowed"
+            }
+            TransactionStatusCode::PropertyModificationCountExceeded => {
+                "Failure_Property_Modification_Count_Exceeded"
+            }
+            TransactionStatusCode::PropertyValuesCountExceeded => {
+                "Failure_Property_Values_Count_Exceeded"
+            }
+            TransactionStatusCode::PropertyValueInvalid => "Failure_Property_Value_Invalid",
+            TransactionStatusCode::PropertySignerAddressInteractionNotAllowed => {
+                "Failure_Property_Signer_Address_Interaction_Not_Allowed"
+            }
+            TransactionStatusCode::PropertyMosaicTransferNotAllowed => {
+                "Failure_Property_Mosaic_Transfer_Not_Allowed"
+            }
+            TransactionStatusCode::PropertyTransactionTypeNotAllowed => {
+                "Failure_Property_Transaction_Type_Not_Allowed"
+            }
+            TransactionStatusCode::TransferMessageTooLarge => "Failure_Transfer_Message_Too_Large",
+            TransactionStatusCode::TransferOutOfOrderMosaics => {
+                "Failure_Transfer_Out_Of_Order_Mosaics"
+            }
+            TransactionStatusCode::TransferTooManyMosaics => "Failure_Transfer_Too_Many_Mosaics",
+            TransactionStatusCode::ExchangeOfferExists => "Failure_Exchange_Offer_Exists",
+            TransactionStatusCode::ExchangeAccountDoesntHaveAnyOffer => {
+                "Failure_Exchange_Account_Doesnt_Have_Any_Offer"
+            }
+            TransactionStatusCode::ExchangeOfferDoesntExist => {
+                "Failure_Exchange_Offer_Doesnt_Exist"
+            }
+            TransactionStatusCode::ExchangeOfferExpired => "Failure_Exchange_Offer_Expired",
+            TransactionStatusCode::ExchangeNotEnoughUnitsInOffer => {
+                "Failure_Exchange_Not_Enough_Units_In_Offer"
+            }
+            TransactionStatusCode::ExchangeBuyingOwnUnitsIsNotAllowed => {
+                "Failure_Exchange_Buying_Own_Units_Is_Not_Allowed"
+            }
+            TransactionStatusCode::ExchangeInvalidPrice => "Failure_Exchange_Invalid_Price",
+            TransactionStatusCode::ExchangeInvalidDuration => "Failure_Exchange_Invalid_Duration",
+            TransactionStatusCode::ChainUnlinked => "Failure_Chain_Unlinked",
+            TransactionStatusCode::ChainBlockNotHit => "Failure_Chain_Block_Not_Hit",
+            TransactionStatusCode::ChainBlockInconsistentStateHash => {
+                "Failure_Chain_Block_Inconsistent_State_Hash"
+            }
+            TransactionStatusCode::ChainBlockInconsistentReceiptsHash => {
+                "Failure_Chain_Block_Inconsistent_Receipts_Hash"
+            }
+            TransactionStatusCode::ChainUnconfirmedCacheTooFull => {
+                "Failure_Chain_Unconfirmed_Cache_Too_Full"
+            }
+            TransactionStatusCode::ConsumerEmptyInput => "Failure_Consumer_Empty_Input",
+            TransactionStatusCode::ConsumerBlockTransactionsHashMismatch => {
+                "Failure_Consumer_Block_Transactions_Hash_Mismatch"
+            }
+            TransactionStatusCode::ConsumerHashInRecencyCache => {
+                "Failure_Consumer_Hash_In_Recency_Cache"
+            }
+            TransactionStatusCode::ConsumerRemoteChainTooManyBlocks => {
+                "Failure_Consumer_Remote_Chain_Too_Many_Blocks"
+            }
+            TransactionStatusCode::ConsumerRemoteChainImproperLink => {
+                "Failure_Consumer_Remote_Chain_Improper_Link"
+            }
+            TransactionStatusCode::ConsumerRemoteChainDuplicateTransactions => {
+                "Failure_Consumer_Remote_Chain_Duplicate_Transactions"
+            }
+            TransactionStatusCode::ConsumerRemoteChainUnlinked => {
+                "Failure_Consumer_Remote_Chain_Unlinked"
+            }
+            TransactionStatusCode::ConsumerRemoteChainMismatchedDifficulties => {
+                "Failure_Consumer_Remote_Chain_Mismatched_Difficulties"
+            }
+            TransactionStatusCode::ConsumerRemoteChainScoreNotBetter => {
+                "Failure_Consumer_Remote_Chain_Score_Not_Better"
+            }
+            TransactionStatusCode::ConsumerRemoteChainTooFarBehind => {
+                "Failure_Consumer_Remote_Chain_Too_Far_Behind"
+            }
+            TransactionStatusCode::ConsumerRemoteChainTooFarInFuture => {
+                "Failure_Consumer_Remote_Chain_Too_Far_In_Future"
+            }
+            TransactionStatusCode::ExtensionPartialTransactionCachePrune => {
+                "Failure_Extension_Partial_Transaction_Cache_Prune"
+            }
+            TransactionStatusCode::ExtensionPartialTransactionDependencyRemoved => {
+                "Failure_Extension_Partial_Transaction_Dependency_Removed"
+            }
+            TransactionStatusCode::Unknown(code) => code,
+        }
+    }
+
+    /// A human-readable description of the code.
+    pub fn description(&self) -> &str {
+        match self {
+            TransactionStatusCode::Success => "the transaction was accepted",
+            TransactionStatusCode::Neutral => "the transaction was neither accepted nor rejected",
+            TransactionStatusCode::Failure => "the transaction was rejected",
+            TransactionStatusCode::CorePastDeadline => {
+                "the deadline of the transaction has already passed"
+            }
+            TransactionStatusCode::CoreFutureDeadline => {
+                "the deadline of the transaction is too far in the future"
+            }
+            TransactionStatusCode::CoreInsufficientBalance => {
+                "the account does not have enough balance"
+            }
+            TransactionStatusCode::CoreTooManyTransactions => {
+                "there are too many transactions in the block"
+            }
+            TransactionStatusCode::CoreNemesisAccountSignedAfterNemesisBlock => {
+                "the nemesis account can not sign transactions after the nemesis block"
+            }
+            TransactionStatusCode::CoreWrongNetwork => {
+                "the transaction was created for another network"
+            }
+            TransactionStatusCode::CoreInvalidAddress => "the address is not valid",
+            TransactionStatusCode::CoreInvalidVersion => {
+                "the version of the transaction is not supported"
+            }
+            TransactionStatusCode::CoreInvalidTransactionFee => {
+                "the fee of the transaction is not valid"
+            }
+            TransactionStatusCode::CoreBlockHarvesterIneligible => {
+                "the harvester of the block is not eligible"
+            }
+            TransactionStatusCode::CoreZeroAddress => "the address is the zero address",
+            TransactionStatusCode::CoreZeroPublicKey => "the public key is the zero public key",
+            TransactionStatusCode::CoreNonzeroInternalPadding => {
+                "the internal padding of the transaction is not zero"
+            }
+            TransactionStatusCode::CoreAddressCollision => {
+                "the address collides with another account"
+            }
+            TransactionStatusCode::HashExists => "a transaction with the same hash already exists",
+            TransactionStatusCode::SignatureNotVerifiable => {
+                "the signature of the transaction can not be verified"
+            }
+            TransactionStatusCode::AccountLinkInvalidAction => {
+                "the account link action is not valid"
+            }
+            TransactionStatusCode::AccountLinkLinkAlreadyExists => {
+                "the account is already linked to a remote account"
+            }
+            TransactionStatusCode::AccountLinkLinkDoesNotExist => {
+                "the account is not linked to a remote account"
+            }
+            TransactionStatusCode::AccountLinkUnlinkDataInconsistency => {
+                "the unlinked remote account does not match the linked one"
+            }
+            TransactionStatusCode::AccountLinkRemoteAccountIneligible => {
+                "the remote account is not eligible for linking"
+            }
+            TransactionStatusCode::AccountLinkRemoteAccountSignerNotAllowed => {
+                "a remote account can not sign transactions"
+            }
+            TransactionStatusCode::AccountLinkRemoteAccountParticipantNotAllowed => {
+                "a remote account can not participate in transactions"
+            }
+            TransactionStatusCode::AggregateTooManyTransactions => {
+                "the aggregate has too many inner transactions"
+            }
+            TransactionStatusCode::AggregateNoTransactions => {
+                "the aggregate has no inner transactions"
+            }
+            TransactionStatusCode::AggregateTooManyCosignatures => {
+                "the aggregate has too many cosignatures"
+            }
+            TransactionStatusCode::AggregateRedundantCosignatures => {
+                "the aggregate has redundant cosignatures"
+            }
+            TransactionStatusCode::AggregateIneligibleCosigners => {
+                "a cosigner of the aggregate is not a required signer"
+            }
+            TransactionStatusCode::AggregateMissingCosigners => {
+                "the aggregate is missing required cosignatures"
+            }
+            TransactionStatusCode::AggregateUnsupportedBondedTransaction => {
+                "aggregate bonded transactions are not enabled"
+            }
+            TransactionStatusCode::LockHashInvalidMosaicId => {
+                "the hash lock mosaic is not the network currency"
+            }
+            TransactionStatusCode::LockHashInvalidMosaicAmount => {
+                "the hash lock amount is not valid"
+            }
+            TransactionStatusCode::LockHashHashExists => "a hash lock for the hash already exists",
+            TransactionStatusCode::LockHashUnknownHash => "there is no hash lock for the hash",
+            TransactionStatusCode::LockHashInactiveHash => "the hash lock is used or expired",
+            TransactionStatusCode::LockHashInvalidDuration => "the hash lock duration is too long",
+            TransactionStatusCode::LockSecretInvalidHashAlgorithm => {
+                "the secret lock hash algorithm is not valid"
+            }
+            TransactionStatusCode::LockSecretHashExists => {
+                "a secret lock for the secret already exists"
+            }
+            TransactionStatusCode::LockSecretHashNotImplemented => {
+                "the secret lock hash algorithm is not implemented"
+            }
+            TransactionStatusCode::LockSecretProofSizeOutOfBounds => {
+                "the secret proof size is out of bounds"
+            }
+            TransactionStatusCode::LockSecretSecretMismatch => {
+                "the secret proof does not match the secret"
+            }
+            TransactionStatusCode::LockSecretUnknownCompositeKey => {
+                "there is no secret lock for the secret and recipient"
+            }
+            TransactionStatusCode::LockSecretInactiveSecret => "the secret lock is used or expired",
+            TransactionStatusCode::LockSecretHashAlgorithmMismatch => {
+                "the hash algorithm does not match the secret lock"
+            }
+            TransactionStatusCode::LockSecretInvalidDuration => {
+                "the secret lock duration is too long"
+            }
+            TransactionStatusCode::MosaicInvalidDuration => "the mosaic duration is not valid",
+            TransactionStatusCode::MosaicInvalidName => "the mosaic name is not valid",
+            TransactionStatusCode::MosaicNameIdMismatch => {
+                "the mosaic name does not match the mosaic id"
+            }
+            TransactionStatusCode::MosaicExpired => "the mosaic has expired",
+            TransactionStatusCode::MosaicOwnerConflict => "the signer does not own the mosaic",
+            TransactionStatusCode::MosaicIdMismatch => {
+                "the mosaic id does not match the nonce and owner"
+            }
+            TransactionStatusCode::MosaicParentIdConflict => {
+                "the mosaic parent namespace conflicts with the existing one"
+            }
+            TransactionStatusCode::MosaicInvalidProperty => "the mosaic property is not valid",
+            TransactionStatusCode::MosaicInvalidFlags => "the mosaic flags are not valid",
+            TransactionStatusCode::MosaicInvalidDivisibility => {
+                "the mosaic divisibility is not valid"
+            }
+            TransactionStatusCode::MosaicInvalidSupplyChangeDirection => {
+                "the mosaic supply change direction is not valid"
+            }
+            TransactionStatusCode::MosaicInvalidSupplyChangeAmount => {
+                "the mosaic supply change amount is not valid"
+            }
+            TransactionStatusCode::MosaicInvalidId => "the mosaic id is not valid",
+            TransactionStatusCode::MosaicModificationDisallowed => {
+                "the mosaic definition can not be changed"
+            }
+            TransactionStatusCode::MosaicModificationNoChanges => {
+                "the mosaic definition does not change anything"
+            }
+            TransactionStatusCode::MosaicSupplyImmutable => "the mosaic supply is not mutable",
+            TransactionStatusCode::MosaicSupplyNegative => {
+                "the mosaic supply would become negative"
+            }
+            TransactionStatusCode::MosaicSupplyExceeded => {
+                "the mosaic supply would exceed the maximum"
+            }
+            TransactionStatusCode::MosaicNonTransferable => "the mosaic is not transferable",
+            TransactionStatusCode::MosaicMaxMosaicsExceeded => "the account owns too many mosaics",
+            TransactionStatusCode::MultisigModifyAccountInBothSets => {
+                "the account is both added and removed as cosignatory"
+            }
+            TransactionStatusCode::MultisigModifyMultipleDeletes => {
+                "more than one cosignatory is removed"
+            }
+            TransactionStatusCode::MultisigModifyRedundantModifications => {
+                "the multisig modifications are redundant"
+            }
+            TransactionStatusCode::MultisigModifyUnknownMultisigAccount => {
+                "the account is not a multisig account"
+            }
+            TransactionStatusCode::MultisigModifyNotACosigner => {
+                "the account is not a cosignatory of the multisig account"
+            }
+            TransactionStatusCode::MultisigModifyAlreadyACosigner => {
+                "the account is already a cosignatory of the multisig account"
+            }
+            TransactionStatusCode::MultisigModifyMinSettingOutOfRange => {
+                "the minimum approval or removal is out of range"
+            }
+            TransactionStatusCode::MultisigModifyMinSettingLargerThanNumCosignatories => {
+                "the minimum approval or removal is larger than the number of cosignatories"
+            }
+            TransactionStatusCode::MultisigModifyUnsupportedModificationType => {
+                "the multisig modification type is not supported"
+            }
+            TransactionStatusCode::MultisigModifyMaxCosignedAccounts => {
+                "the account cosigns too many multisig accounts"
+            }
+            TransactionStatusCode::MultisigModifyMaxCosigners => {
+                "the multisig account has too many cosignatories"
+            }
+            TransactionStatusCode::MultisigModifyLoop => "the multisig modification creates a loop",
+            TransactionStatusCode::MultisigModifyMaxMultisigDepth => {
+                "the multisig hierarchy is too deep"
+            }
+            TransactionStatusCode::MultisigOperationNotPermittedByAccount => {
+                "a multisig account can not sign this transaction"
+            }
+            TransactionStatusCode::NamespaceInvalidDuration => {
+                "the namespace duration is not valid"
+            }
+            TransactionStatusCode::NamespaceInvalidName => "the namespace name is not valid",
+            TransactionStatusCode::NamespaceNameIdMismatch => {
+                "the namespace name does not match the namespace id"
+            }
+            TransactionStatusCode::NamespaceExpired => "the namespace has expired",
+            TransactionStatusCode::NamespaceOwnerConflict => {
+                "the signer does not own the namespace"
+            }
+            TransactionStatusCode::NamespaceIdMismatch => {
+                "the namespace id does not match the name and parent"
+            }
+            TransactionStatusCode::NamespaceInvalidNamespaceType => {
+                "the namespace type is not valid"
+            }
+            TransactionStatusCode::NamespaceRootNameReserved => {
+                "the root namespace name is reserved"
+            }
+            TransactionStatusCode::NamespaceTooDeep => "the namespace is too deep",
+            TransactionStatusCode::NamespaceParentUnknown => "the parent namespace does not exist",
+            TransactionStatusCode::NamespaceAlreadyExists => "the namespace already exists",
+            TransactionStatusCode::NamespaceAlreadyActive => "the namespace is already active",
+            TransactionStatusCode::NamespaceEternalAfterNemesisBlock => {
+                "eternal namespaces can only be created in the nemesis block"
+            }
+            TransactionStatusCode::NamespaceMaxChildrenExceeded => {
+                "the namespace has too many children"
+            }
+            TransactionStatusCode::NamespaceAliasInvalidAction => "the alias action is not valid",
+            TransactionStatusCode::NamespaceUnknown => "the namespace does not exist",
+            TransactionStatusCode::NamespaceAliasAlreadyExists => {
+                "the namespace already has an alias"
+            }
+            TransactionStatusCode::NamespaceUnknownAlias => "the namespace has no alias",
+            TransactionStatusCode::NamespaceAliasOwnerConflict => {
+                "the signer does not own the alias"
+            }
+            TransactionStatusCode::NamespaceAliasUnlinkTypeInconsistency => {
+                "the unlinked alias type does not match the linked one"
+            }
+            TransactionStatusCode::NamespaceAliasUnlinkDataInconsistency => {
+                "the unlinked alias does not match the linked one"
+            }
+            TransactionStatusCode::NamespaceAliasInvalidAddress => "the alias address is not valid",
+            TransactionStatusCode::PropertyInvalidPropertyType => {
+                "the account property type is not valid"
+            }
+            TransactionStatusCode::PropertyModificationTypeInvalid => {
+                "the account property modification type is not valid"
+            }
+            TransactionStatusCode::PropertyModificationAddressInvalid => {
+                "the account property address is not valid"
+            }
+            TransactionStatusCode::PropertyModificationOperationTypeIncompatible => {
+                "the account property operation type is not compatible"
+            }
+            TransactionStatusCode::PropertyModifyUnsupportedModificationType => {
+                "the account property modification type is not supported"
+            }
+            TransactionStatusCode::PropertyModificationRedundant => {
+                "the account property modifications are redundant"
+            }
+            TransactionStatusCode::PropertyModificationNotAllowed => {
+                "the account property modification is not allowed"
+            }
+            TransactionStatusCode::PropertyModificationCountExceeded => {
+                "there are too many account property modifications"
+            }
+            TransactionStatusCode::PropertyValuesCountExceeded => {
+                "the account property has too many values"
+            }
+            TransactionStatusCode::PropertyValueInvalid => {
+                "the account property value is not valid"
+            }
+            TransactionStatusCode::PropertySignerAddressInteractionNotAllowed => {
+                "the recipient does not accept transactions from the signer"
+            }
+            TransactionStatusCode::PropertyMosaicTransferNotAllowed => {
+                "the recipient does not accept the mosaic"
+            }
+            TransactionStatusCode::PropertyTransactionTypeNotAllowed => {
+                "the account can not send this transaction type"
+            }
+            TransactionStatusCode::TransferMessageTooLarge => "the transfer message is too large",
+            TransactionStatusCode::TransferOutOfOrderMosaics => {
+                "the transfer mosaics are not sorted"
+            }
+            TransactionStatusCode::TransferTooManyMosaics => "the transfer has too many mosaics",
+            TransactionStatusCode::ExchangeOfferExists => {
+                "the account already has an offer for the mosaic"
+            }
+            TransactionStatusCode::ExchangeAccountDoesntHaveAnyOffer => "the account has no offers",
+            TransactionStatusCode::ExchangeOfferDoesntExist => "the offer does not exist",
+            TransactionStatusCode::ExchangeOfferExpired => "the offer has expired",
+            TransactionStatusCode::ExchangeNotEnoughUnitsInOffer => {
+                "the offer does not have enough units"
+            }
+            TransactionStatusCode::ExchangeBuyingOwnUnitsIsNotAllowed => {
+                "an account can not accept its own offer"
+            }
+            TransactionStatusCode::ExchangeInvalidPrice => "the offer price is not valid",
+            TransactionStatusCode::ExchangeInvalidDuration => "the offer duration is not valid",
+            TransactionStatusCode::ChainUnlinked => "the block is not linked to the chain",
+            TransactionStatusCode::ChainBlockNotHit => "the block generation hash is not a hit",
+            TransactionStatusCode::ChainBlockInconsistentStateHash => {
+                "the block state hash does not match"
+            }
+            TransactionStatusCode::ChainBlockInconsistentReceiptsHash => {
+                "the block receipts hash does not match"
+            }
+            TransactionStatusCode::ChainUnconfirmedCacheTooFull => {
+                "the unconfirmed transactions cache is full"
+            }
+            TransactionStatusCode::ConsumerEmptyInput => "the node received no elements",
+            TransactionStatusCode::ConsumerBlockTransactionsHashMismatch => {
+                "the block transactions hash does not match"
+            }
+            TransactionStatusCode::ConsumerHashInRecencyCache => "the element was seen recently",
+            TransactionStatusCode::ConsumerRemoteChainTooManyBlocks => {
+                "the remote chain has too many blocks"
+            }
+            TransactionStatusCode::ConsumerRemoteChainImproperLink => {
+                "the remote chain is not properly linked"
+            }
+            TransactionStatusCode::ConsumerRemoteChainDuplicateTransactions => {
+                "the remote chain has duplicate transactions"
+            }
+            TransactionStatusCode::ConsumerRemoteChainUnlinked => {
+                "the remote chain is not linked to the local chain"
+            }
+            TransactionStatusCode::ConsumerRemoteChainMismatchedDifficulties => {
+                "the remote chain difficulties do not match"
+            }
+            TransactionStatusCode::ConsumerRemoteChainScoreNotBetter => {
+                "the remote chain score is not better"
+            }
+            TransactionStatusCode::ConsumerRemoteChainTooFarBehind => {
+                "the remote chain is too far behind"
+            }
+            TransactionStatusCode::ConsumerRemoteChainTooFarInFuture => {
+                "the remote chain is too far in the future"
+            }
+            TransactionStatusCode::ExtensionPartialTransactionCachePrune => {
+                "the partial transaction expired"
+            }
+            TransactionStatusCode::ExtensionPartialTransactionDependencyRemoved => {
+                "a transaction the partial transaction depends on was removed"
+            }
+            TransactionStatusCode::Unknown(_) => "unknown transaction status",
+        }
+    }
+}
+
+impl From<&str> for TransactionStatusCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "Success" => TransactionStatusCode::Success,
+            "Neutral" => TransactionStatusCode::Neutral,
+            "Failure" => TransactionStatusCode::Failure,
+            "Failure_Core_Past_Deadline" => TransactionStatusCode::CorePastDeadline,
+            "Failure_Core_Future_Deadline" => TransactionStatusCode::CoreFutureDeadline,
+            "Failure_Core_Insufficient_Balance" => TransactionStatusCode::CoreInsufficientBalance,
+            "Failure_Core_Too_Many_Transactions" => TransactionStatusCode::CoreTooManyTransactions,
+            "Failure_Core_Nemesis_Account_Signed_After_Nemesis_Block" => {
+                TransactionStatusCode::CoreNemesisAccountSignedAfterNemesisBlock
+            }
+            "Failure_Core_Wrong_Network" => TransactionStatusCode::CoreWrongNetwork,
+            "Failure_Core_Invalid_Address" => TransactionStatusCode::CoreInvalidAddress,
+            "Failure_Core_Invalid_Version" => TransactionStatusCode::CoreInvalidVersion,
+            "Failure_Core_Invalid_Transaction_Fee" => {
+                TransactionStatusCode::CoreInvalidTransactionFee
+            }
+            "Failure_Core_Block_Harvester_Ineligible" => {
+                TransactionStatusCode::CoreBlockHarvesterIneligible
+            }
+            "Failure_Core_Zero_Address" => TransactionStatusCode::CoreZeroAddress,
+            "Failure_Core_Zero_Public_Key" => TransactionStatusCode::CoreZeroPublicKey,
+            "Failure_Core_Nonzero_Internal_Padding" => {
+                TransactionStatusCode::CoreNonzeroInternalPadding
+            }
+            "Failure_Core_Address_Collision" => TransactionStatusCode::CoreAddressCollision,
+            "Failure_Hash_Exists" => TransactionStatusCode::HashExists,
+            "Failure_Signature_Not_Verifiable" => TransactionStatusCode::SignatureNotVerifiable,
+            "Failure_AccountLink_Invalid_Action" => TransactionStatusCode::AccountLinkInvalidAction,
+            "Failure_AccountLink_Link_Already_Exists" => {
+                TransactionStatusCode::AccountLinkLinkAlreadyExists
+            }
+            "Failure_AccountLink_Link_Does_Not_Exist" => {
+                TransactionStatusCode::AccountLinkLinkDoesNotExist
+            }
+            "Failure_AccountLink_Unlink_Data_Inconsistency" => {
+                TransactionStatusCode::AccountLinkUnlinkDataInconsistency
+            }
+            "Failure_AccountLink_Remote_Account_Ineligible" => {
+                TransactionStatusCode::AccountLinkRemoteAccountIneligible
+            }
+            "Failure_AccountLink_Remote_Account_Signer_Not_Allowed" => {
+                TransactionStatusCode::AccountLinkRemoteAccountSignerNotAllowed
+            }
+            "Failure_AccountLink_Remote_Account_Participant_Not_Allowed" => {
+                TransactionStatusCode::AccountLinkRemoteAccountParticipantNotAllowed
+            }
+            "Failure_Aggregate_Too_Many_Transactions" => {
+                TransactionStatusCode::AggregateTooManyTransactions
+            }
+            "Failure_Aggregate_No_Transactions" => TransactionStatusCode::AggregateNoTransactions,
+            "Failure_Aggregate_Too_Many_Cosignatures" => {
+                TransactionStatusCode::AggregateTooManyCosignatures
+            }
+            "Failure_Aggregate_Redundant_Cosignatures" => {
+                TransactionStatusCode::AggregateRedundantCosignatures
+            }
+            "Failure_Aggregate_Ineligible_Cosigners" => {
+                TransactionStatusCode::AggregateIneligibleCosigners
+            }
+            "Failure_Aggregate_Missing_Cosigners" => {
+                TransactionStatusCode::AggregateMissingCosigners
+            }
+            "Failure_Aggregate_Unsupported_Bonded_Transaction" => {
+                TransactionStatusCode::AggregateUnsupportedBondedTransaction
+            }
+            "Failure_LockHash_Invalid_Mosaic_Id" => TransactionStatusCode::LockHashInvalidMosaicId,
+            "Failure_LockHash_Invalid_Mosaic_Amount" => {
+                TransactionStatusCode::LockHashInvalidMosaicAmount
+            }
+            "Failure_LockHash_Hash_Exists" => TransactionStatusCode::LockHashHashExists,
+            "Failure_LockHash_Unknown_Hash" => TransactionStatusCode::LockHashUnknownHash,
+            "Failure_LockHash_Inactive_Hash" => TransactionStatusCode::LockHashInactiveHash,
+            "Failure_LockHash_Invalid_Duration" => TransactionStatusCode::LockHashInvalidDuration,
+            "Failure_LockSecret_Invalid_Hash_Algorithm" => {
+                TransactionStatusCode::LockSecretInvalidHashAlgorithm
+            }
+            "Failure_LockSecret_Hash_Exists" => TransactionStatusCode::LockSecretHashExists,
+            "Failure_LockSecret_Hash_Not_Implemented" => {
+                TransactionStatusCode::LockSecretHashNotImplemented
+            }
+            "Failure_LockSecret_Proof_Size_Out_Of_Bounds" => {
+                TransactionStatusCode::LockSecretProofSizeOutOfBounds
+            }
+            "Failure_LockSecret_Secret_Mismatch" => TransactionStatusCode::LockSecretSecretMismatch,
+            "Failure_LockSecret_Unknown_Composite_Key" => {
+                TransactionStatusCode::LockSecretUnknownCompositeKey
+            }
+            "Failure_LockSecret_Inactive_Secret" => TransactionStatusCode::LockSecretInactiveSecret,
+            "Failure_LockSecret_Hash_Algorithm_Mismatch" => {
+                TransactionStatusCode::LockSecretHashAlgorithmMismatch
+            }
+            "Failure_LockSecret_Invalid_Duration" => {
+                TransactionStatusCode::LockSecretInvalidDuration
+            }
+            "Failure_Mosaic_Invalid_Duration" => TransactionStatusCode::MosaicInvalidDuration,
+            "Failure_Mosaic_Invalid_Name" => TransactionStatusCode::MosaicInvalidName,
+            "Failure_Mosaic_Name_Id_Mismatch" => TransactionStatusCode::MosaicNameIdMismatch,
+            "Failure_Mosaic_Expired" => TransactionStatusCode::MosaicExpired,
+            "Failure_Mosaic_Owner_Conflict" => TransactionStatusCode::MosaicOwnerConflict,
+            "Failure_Mosaic_Id_Mismatch" => TransactionStatusCode::MosaicIdMismatch,
+            "Failure_Mosaic_Parent_Id_Conflict" => TransactionStatusCode::MosaicParentIdConflict,
+            "Failure_Mosaic_Invalid_Property" => TransactionStatusCode::MosaicInvalidProperty,
+            "Failure_Mosaic_Invalid_Flags" => TransactionStatusCode::MosaicInvalidFlags,
+            "Failure_Mosaic_Invalid_Divisibility" => {
+                TransactionStatusCode::MosaicInvalidDivisibility
+            }
+            "Failure_Mosaic_Invalid_Supply_Change_Direction" => {
+                TransactionStatusCode::MosaicInvalidSupplyChangeDirection
+            }
+            "Failure_Mosaic_Invalid_Supply_Change_Amount" => {
+                TransactionStatusCode::MosaicInvalidSupplyChangeAmount
+            }
+            "Failure_Mosaic_Invalid_Id" => TransactionStatusCode::MosaicInvalidId,
+            "Failure_Mosaic_Modification_Disallowed" => {
+                TransactionStatusCode::MosaicModificationDisallowed
+            }
+            "Failure_Mosaic_Modification_No_Changes" => {
+                TransactionStatusCode::MosaicModificationNoChanges
+            }
+            "Failure_Mosaic_Supply_Immutable" => TransactionStatusCode::MosaicSupplyImmutable,
+            "Failure_Mosaic_Supply_Negative" => TransactionStatusCode::MosaicSupplyNegative,
+            "Failure_Mosaic_Supply_Exceeded" => TransactionStatusCode::MosaicSupplyExceeded,
+            "Failure_Mosaic_Non_Transferable" => TransactionStatusCode::MosaicNonTransferable,
+            "Failure_Mosaic_Max_Mosaics_Exceeded" => {
+                TransactionStatusCode::MosaicMaxMosaicsExceeded
+            }
+            "Failure_Multisig_Modify_Account_In_Both_Sets" => {
+                TransactionStatusCode::MultisigModifyAccountInBothSets
+            }
+            "Failure_Multisig_Modify_Multiple_Deletes" => {
+                TransactionStatusCode::MultisigModifyMultipleDeletes
+            }
+            "Failure_Multisig_Modify_Redundant_Modifications" => {
+                TransactionStatusCode::MultisigModifyRedundantModifications
+            }
+            "Failure_Multisig_Modify_Unknown_Multisig_Account" => {
+                TransactionStatusCode::MultisigModifyUnknownMultisigAccount
+            }
+            "Failure_Multisig_Modify_Not_A_Cosigner" => {
+                TransactionStatusCode::MultisigModifyNotACosigner
+            }
+            "Failure_Multisig_Modify_Already_A_Cosigner" => {
+                TransactionStatusCode::MultisigModifyAlreadyACosigner
+            }
+            "Failure_Multisig_Modify_Min_Setting_Out_Of_Range" => {
+                TransactionStatusCode::MultisigModifyMinSettingOutOfRange
+            }
+            "Failure_Multisig_Modify_Min_Setting_Larger_Than_Num_Cosignatories" => {
+                TransactionStatusCode::MultisigModifyMinSettingLargerThanNumCosignatories
+            }
+            "Failure_Multisig_Modify_Unsupported_Modification_Type" => {
+                TransactionStatusCode::MultisigModifyUnsupportedModificationType
+            }
+            "Failure_Multisig_Modify_Max_Cosigned_Accounts" => {
+                TransactionStatusCode::MultisigModifyMaxCosignedAccounts
+            }
+            "Failure_Multisig_Modify_Max_Cosigners" => {
+                TransactionStatusCode::MultisigModifyMaxCosigners
+            }
+            "Failure_Multisig_Modify_Loop" => TransactionStatusCode::MultisigModifyLoop,
+            "Failure_Multisig_Modify_Max_Multisig_Depth" => {
+                TransactionStatusCode::MultisigModifyMaxMultisigDepth
+            }
+            "Failure_Multisig_Operation_Not_Permitted_By_Account" => {
+                TransactionStatusCode::MultisigOperationNotPermittedByAccount
+            }
+            "Failure_Namespace_Invalid_Duration" => TransactionStatusCode::NamespaceInvalidDuration,
+            "Failure_Namespace_Invalid_Name" => TransactionStatusCode::NamespaceInvalidName,
+            "Failure_Namespace_Name_Id_Mismatch" => TransactionStatusCode::NamespaceNameIdMismatch,
+            "Failure_Namespace_Expired" => TransactionStatusCode::NamespaceExpired,
+            "Failure_Namespace_Owner_Conflict" => TransactionStatusCode::NamespaceOwnerConflict,
+            "Failure_Namespace_Id_Mismatch" => TransactionStatusCode::NamespaceIdMismatch,
+            "Failure_Namespace_Invalid_Namespace_Type" => {
+                TransactionStatusCode::NamespaceInvalidNamespaceType
+            }
+            "Failure_Namespace_Root_Name_Reserved" => {
+                TransactionStatusCode::NamespaceRootNameReserved
+            }
+            "Failure_Namespace_Too_Deep" => TransactionStatusCode::NamespaceTooDeep,
+            "Failure_Namespace_Parent_Unknown" => TransactionStatusCode::NamespaceParentUnknown,
+            "Failure_Namespace_Already_Exists" => TransactionStatusCode::NamespaceAlreadyExists,
+            "Failure_Namespace_Already_Active" => TransactionStatusCode::NamespaceAlreadyActive,
+            "Failure_Namespace_Eternal_After_Nemesis_Block" => {
+                TransactionStatusCode::NamespaceEternalAfterNemesisBlock
+            }
+            "Failure_Namespace_Max_Children_Exceeded" => {
+                TransactionStatusCode::NamespaceMaxChildrenExceeded
+            }
+            "Failure_Namespace_Alias_Invalid_Action" => {
+                TransactionStatusCode::NamespaceAliasInvalidAction
+            }
+            "Failure_Namespace_Unknown" => TransactionStatusCode::NamespaceUnknown,
+            "Failure_Namespace_Alias_Already_Exists" => {
+                TransactionStatusCode::NamespaceAliasAlreadyExists
+            }
+            "Failure_Namespace_Unknown_Alias" => TransactionStatusCode::NamespaceUnknownAlias,
+            "Failure_Namespace_Alias_Owner_Conflict" => {
+                TransactionStatusCode::NamespaceAliasOwnerConflict
+            }
+            "Failure_Namespace_Alias_Unlink_Type_Inconsistency" => {
+                TransactionStatusCode::NamespaceAliasUnlinkTypeInconsistency
+            }
+            "Failure_Namespace_Alias_Unlink_Data_Inconsistency" => {
+                TransactionStatusCode::NamespaceAliasUnlinkDataInconsistency
+            }
+            "Failure_Namespace_Alias_Invalid_Address" => {
+                TransactionStatusCode::NamespaceAliasInvalidAddress
+            }
+            "Failure_Property_Invalid_Property_Type" => {
+                TransactionStatusCode::PropertyInvalidPropertyType
+            }
+            "Failure_Property_Modification_Type_Invalid" => {
+                TransactionStatusCode::PropertyModificationTypeInvalid
+            }
+            "Failure_Property_Modification_Address_Invalid" => {
+                TransactionStatusCode::PropertyModificationAddressInvalid
+            }
+            "Failure_Property_Modification_Operation_Type_Incompatible" => {
+                TransactionStatusCode::PropertyModificationOperationTypeIncompatible
+            }
+            "Failure_Property_Modify_Unsupported_Modification_Type" => {
+                TransactionStatusCode::PropertyModifyUnsupportedModificationType
+            }
+            "Failure_Property_Modification_Redundant" => {
+                TransactionStatusCode::PropertyModificationRedundant
+            }
+            "Failure_Property_Modification_Not_Allowed" => {
+                TransactionStatusCode::PropertyModificationNotAllowed
+            }
+            "Failure_Property_Modification_Count_Exceeded" => {
+                TransactionStatusCode::PropertyModificationCountExceeded
+            }
+            "Failure_Property_Values_Count_Exceeded" => {
+                TransactionStatusCode::PropertyValuesCountExceeded
+            }
+            "Failure_Property_Value_Invalid" => TransactionStatusCode::PropertyValueInvalid,
+            "Failure_Property_Signer_Address_Interaction_Not_Allowed" => {
+                TransactionStatusCode::PropertySignerAddressInteractionNotAllowed
+            }
+            "Failure_Property_Mosaic_Transfer_Not_Allowed" => {
+                TransactionStatusCode::PropertyMosaicTransferNotAllowed
+            }
+            "Failure_Property_Transaction_Type_Not_Allowed" => {
+                TransactionStatusCode::PropertyTransactionTypeNotAllowed
+            }
+            "Failure_Transfer_Message_Too_Large" => TransactionStatusCode::TransferMessageTooLarge,
+            "Failure_Transfer_Out_Of_Order_Mosaics" => {
+                TransactionStatusCode::TransferOutOfOrderMosaics
+            }
+            "Failure_Transfer_Too_Many_Mosaics" => TransactionStatusCode::TransferTooManyMosaics,
+            "Failure_Exchange_Offer_Exists" => TransactionStatusCode::ExchangeOfferExists,
+            "Failure_Exchange_Account_Doesnt_Have_Any_Offer" => {
+                TransactionStatusCode::ExchangeAccountDoesntHaveAnyOffer
+            }
+            "Failure_Exchange_Offer_Doesnt_Exist" => {
+                TransactionStatusCode::ExchangeOfferDoesntExist
+            }
+            "Failure_Exchange_Offer_Expired" => TransactionStatusCode::ExchangeOfferExpired,
+            "Failure_Exchange_Not_Enough_Units_In_Offer" => {
+                TransactionStatusCode::ExchangeNotEnoughUnitsInOffer
+            }
+            "Failure_Exchange_Buying_Own_Units_Is_Not_Allowed" => {
+                TransactionStatusCode::ExchangeBuyingOwnUnitsIsNotAllowed
+            }
+            "Failure_Exchange_Invalid_Price" => TransactionStatusCode::ExchangeInvalidPrice,
+            "Failure_Exchange_Invalid_Duration" => TransactionStatusCode::ExchangeInvalidDuration,
+            "Failure_Chain_Unlinked" => TransactionStatusCode::ChainUnlinked,
+            "Failure_Chain_Block_Not_Hit" => TransactionStatusCode::ChainBlockNotHit,
+            "Failure_Chain_Block_Inconsistent_State_Hash" => {
+                TransactionStatusCode::ChainBlockInconsistentStateHash
+            }
+            "Failure_Chain_Block_Inconsistent_Receipts_Hash" => {
+                TransactionStatusCode::ChainBlockInconsistentReceiptsHash
+            }
+            "Failure_Chain_Unconfirmed_Cache_Too_Full" => {
+                TransactionStatusCode::ChainUnconfirmedCacheTooFull
+            }
+            "Failure_Consumer_Empty_Input" => TransactionStatusCode::ConsumerEmptyInput,
+            "Failure_Consumer_Block_Transactions_Hash_Mismatch" => {
+                TransactionStatusCode::ConsumerBlockTransactionsHashMismatch
+            }
+            "Failure_Consumer_Hash_In_Recency_Cache" => {
+                TransactionStatusCode::ConsumerHashInRecencyCache
+            }
+            "Failure_Consumer_Remote_Chain_Too_Many_Blocks" => {
+                TransactionStatusCode::ConsumerRemoteChainTooManyBlocks
+            }
+            "Failure_Consumer_Remote_Chain_Improper_Link" => {
+                TransactionStatusCode::ConsumerRemoteChainImproperLink
+            }
+            "Failure_Consumer_Remote_Chain_Duplicate_Transactions" => {
+                TransactionStatusCode::ConsumerRemoteChainDuplicateTransactions
+            }
+            "Failure_Consumer_Remote_Chain_Unlinked" => {
+                TransactionStatusCode::ConsumerRemoteChainUnlinked
+            }
+            "Failure_Consumer_Remote_Chain_Mismatched_Difficulties" => {
+                TransactionStatusCode::ConsumerRemoteChainMismatchedDifficulties
+            }
+            "Failure_Consumer_Remote_Chain_Score_Not_Better" => {
+                TransactionStatusCode::ConsumerRemoteChainScoreNotBetter
+            }
+            "Failure_Consumer_Remote_Chain_Too_Far_Behind" => {
+                TransactionStatusCode::ConsumerRemoteChainTooFarBehind
+            }
+            "Failure_Consumer_Remote_Chain_Too_Far_In_Future" => {
+                TransactionStatusCode::ConsumerRemoteChainTooFarInFuture
+            }
+            "Failure_Extension_Partial_Transaction_Cache_Prune" => {
+                TransactionStatusCode::ExtensionPartialTransactionCachePrune
+            }
+            "Failure_Extension_Partial_Transaction_Dependency_Removed" => {
+                TransactionStatusCode::ExtensionPartialTransactionDependencyRemoved
+            }
+            _ => TransactionStatusCode::Unknown(code.to_string()),
+        }
+    }
+}
+
+impl From<String> for TransactionStatusCode {
+    fn from(code: String) -> Self {
+        TransactionStatusCode::from(code.as_str())
+    }
+}
+
+impl From<TransactionStatusCode> for String {
+    fn from(code: TransactionStatusCode) -> Self {
+        code.code().to_string()
+    }
+}
+
+impl fmt::Display for TransactionStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -4,7 +4,7 @@
  * license that can be found in the LICENSE file.
  */
 
-use {::std::fmt, failure::_core::any::Any, serde_json::Value};
+use {::std::any::Any, ::std::fmt, serde_json::Value};
 
 use crate::{
     models::{
@@ -49,7 +49,7 @@ impl TransferTransaction {
     ) -> Result<Self> {
         ensure!(
             !recipient.address.is_empty(),
-            errors_const::ERR_EMPTY_ADDRESSES
+            "recipient" => errors_const::ERR_EMPTY_ADDRESSES
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
@@ -76,7 +76,7 @@ impl TransferTransaction {
     ) -> Result<Self> {
         ensure!(
             recipient.to_u64() != 0,
-            errors_const::ERR_EMPTY_NAMESPACE_ID
+            "recipient" => errors_const::ERR_EMPTY_NAMESPACE_ID
         );
 
         let abs_tx = AbstractTransaction::new_from_type(
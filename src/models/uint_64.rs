@@ -5,9 +5,9 @@
  */
 
 use {
+    ::std::ops::BitAnd,
     ::std::{fmt, ops::Deref},
     byteorder::{BigEndian, WriteBytesExt},
-    failure::_core::ops::BitAnd,
     serde_json::Value,
 };
 
@@ -0,0 +1,870 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    account::Address,
+    models::{
+        consts::{KEY_SIZE, SIGNATURE_SIZE},
+        error::Error,
+    },
+    mosaic::{Mosaic, MosaicId, MosaicSupplyType},
+    receipt::{Receipt, ReceiptSource, ReceiptType, Statements},
+    transaction::{
+        AggregateTransaction, Hash, MosaicSupplyChangeTransaction, Transaction, TransferTransaction,
+    },
+    Result,
+};
+
+use super::{Rollback, SyncBatch, SyncSink};
+
+/// What changed the balance of an account.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BalanceChangeCause {
+    /// A transfer sent or received, possibly inside an aggregate.
+    Transfer(Hash),
+    /// The fee of a transaction signed by the account.
+    Fee(Hash),
+    /// A change of the supply of a mosaic owned by the account.
+    SupplyChange(Hash),
+    /// A balance change or transfer receipt: harvest fees, rental fees, levies and locks.
+    Receipt(ReceiptType),
+}
+
+/// A credit (positive `amount`) or debit of one mosaic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceChange {
+    pub height: u64,
+    pub mosaic_id: MosaicId,
+    pub amount: i128,
+    pub cause: BalanceChangeCause,
+}
+
+/// The balance of a mosaic after the changes of the block at `height`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BalancePoint {
+    pub height: u64,
+    pub balance: u64,
+}
+
+/// The balance of each mosaic of an account over time, built by a [BalanceTracker].
+#[derive(Debug, Clone, Default)]
+pub struct BalanceHistory {
+    points: HashMap<MosaicId, Vec<BalancePoint>>,
+    changes: Vec<BalanceChange>,
+    synced_height: u64,
+}
+
+impl BalanceHistory {
+    /// Every mosaic the account ever held.
+    pub fn mosaic_ids(&self) -> Vec<MosaicId> {
+        self.points.keys().cloned().collect()
+    }
+
+    /// The balance of `mosaic_id` after each block that changed it, in height order.
+    pub fn series(&self, mosaic_id: &MosaicId) -> &[BalancePoint] {
+        self.points
+            .get(mosaic_id)
+            .map(|points| points.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Every change applied, in height order.
+    pub fn changes(&self) -> &[BalanceChange] {
+        &self.changes
+    }
+
+    /// The balance of `mosaic_id` after the block at `height`.
+    pub fn balance_at(&self, mosaic_id: &MosaicId, height: u64) -> u64 {
+        self.series(mosaic_id)
+            .iter()
+            .take_while(|point| point.height <= height)
+            .last()
+            .map_or(0, |point| point.balance)
+    }
+
+    /// The non-zero balances after the block at `height`.
+    pub fn balances_at(&self, height: u64) -> Vec<Mosaic> {
+        self.points
+            .keys()
+            .filter_map(|mosaic_id| match self.balance_at(mosaic_id, height) {
+                0 => None,
+                balance => Some(Mosaic::new(mosaic_id.clone(), balance)),
+            })
+            .collect()
+    }
+
+    /// The height of the last block applied.
+    pub fn synced_height(&self) -> u64 {
+        self.synced_height
+    }
+
+    fn apply_block(&mut self, height: u64, changes: Vec<BalanceChange>) -> Result<()> {
+        let mut deltas: HashMap<MosaicId, i128> = HashMap::new();
+        for change in changes.iter() {
+            *deltas.entry(change.mosaic_id.clone()).or_default() += change.amount;
+        }
+
+        for (mosaic_id, delta) in deltas.into_iter() {
+            if delta == 0 {
+                continue;
+            }
+
+            let balance = i128::from(self.balance_at(&mosaic_id, height)) + delta;
+            if balance < 0 || balance > i128::from(u64::max_value()) {
+                return Err(Error::decode(format!(
+                    "{}: mosaic {} at height {}",
+                    crate::errors_const::ERR_INVALID_BALANCE,
+                    mosaic_id.to_hex(),
+                    height
+                )));
+            }
+
+            self.points
+                .entry(mosaic_id)
+                .or_default()
+                .push(BalancePoint {
+                    height,
+                    balance: balance as u64,
+                });
+        }
+
+        self.changes.extend(changes);
+        self.synced_height = height;
+
+        Ok(())
+    }
+
+    /// Drops the changes from `height` up.
+    fn rollback(&mut self, height: u64) {
+        for points in self.points.values_mut() {
+            points.retain(|point| point.height < height);
+        }
+        self.points.retain(|_, points| !points.is_empty());
+
+        self.changes.retain(|change| change.height < height);
+        self.synced_height = self.synced_height.min(height.saturating_sub(1));
+    }
+}
+
+/// [SyncSink] reconstructing the [BalanceHistory] of an account from the blocks read by a
+/// [ChainSync].
+///
+/// The balance is moved by the transfers sent and received, including inside aggregates and
+/// through namespace aliases, the fees of the transactions signed by the account, the supply
+/// changes of its mosaics, and the balance change and transfer receipts of every block:
+/// harvest fees, rental fees, levies, and the locked and returned funds of hash and secret
+/// locks. The fee of a transaction is `fee_multiplier * size` of its block, the size of an
+/// aggregate including its cosignatures, capped at its `max_fee`. Exchange offers are not
+/// covered.
+///
+/// Harvest fees and lock returns are only found in the receipts, so the sync has to read every
+/// block from the nemesis, or from a height whose balances are given with
+/// [BalanceTracker::with_opening_balance]. The tracker is a cheap handle: keep a clone to read
+/// the history while the sync runs.
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::{
+///    account::Address,
+///    api::SiriusClient,
+///    mosaic::MosaicId,
+///    sync::{BalanceTracker, ChainSync},
+///};
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+/// let client = SiriusClient::new(node_url).await.unwrap();
+///
+///    let address = Address::from_raw("VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS").unwrap();
+///    let currency = MosaicId::from_hex("0DC67FBE1CAD29E3").unwrap();
+///
+///    let tracker = BalanceTracker::new(&address, currency.clone());
+///
+///    let tip = ChainSync::new(&client, tracker.clone())
+///        .sync_to_tip()
+///        .await
+///        .unwrap();
+///
+///    println!("{}", tracker.balance_at(&currency, tip / 2));
+///}
+/// ```
+///
+/// [ChainSync]: struct.ChainSync.html
+#[derive(Clone)]
+pub struct BalanceTracker {
+    address: String,
+    currency: MosaicId,
+    history: Arc<Mutex<BalanceHistory>>,
+}
+
+impl BalanceTracker {
+    /// Tracks `address`; `currency` is the mosaic the transaction fees are paid with.
+    pub fn new(address: &Address, currency: MosaicId) -> Self {
+        BalanceTracker {
+            address: address.address_string(),
+            currency,
+            history: Arc::new(Mutex::new(BalanceHistory::default())),
+        }
+    }
+
+    /// The balance of `mosaic_id` after the block at `height`, for a sync started after the
+    /// nemesis block with `ChainSync::with_start_height(height + 1)`. Fails with an
+    /// `Error::Internal` when the history lock is poisoned.
+    pub fn with_opening_balance(
+        self,
+        mosaic_id: MosaicId,
+        height: u64,
+        balance: u64,
+    ) -> Result<Self> {
+        {
+            let mut history = self
+                .history
+                .lock()
+                .map_err(|_| Error::internal(crate::errors_const::ERR_BALANCE_HISTORY_LOCK))?;
+            let points = history.points.entry(mosaic_id).or_default();
+            points.retain(|point| point.height != height);
+            points.push(BalancePoint { height, balance });
+            points.sort_by_key(|point| point.height);
+        }
+        Ok(self)
+    }
+
+    pub fn history(&self) -> BalanceHistory {
+        self.history
+            .lock()
+            .map(|history| history.clone())
+            .unwrap_or_default()
+    }
+
+    /// Same as [BalanceHistory::balance_at].
+    pub fn balance_at(&self, mosaic_id: &MosaicId, height: u64) -> u64 {
+        self.history
+            .lock()
+            .map(|history| history.balance_at(mosaic_id, height))
+            .unwrap_or(0)
+    }
+
+    fn is_account(&self, address: &Address) -> bool {
+        address.address_string() == self.address
+    }
+
+    fn block_changes(&self, batch: &SyncBatch) -> Result<Vec<BalanceChange>> {
+        let height = batch.height().as_u64();
+        let mut changes = vec![];
+
+        for tx in batch.transactions.iter() {
+            let abs_transaction = tx.abs_transaction();
+            let hash = tx.transaction_hash();
+
+            let primary_id = abs_transaction
+                .transaction_info
+                .as_ref()
+                .map_or(0, |info| info.index + 1);
+
+            let aggregate = tx.as_any().downcast_ref::<AggregateTransaction>();
+
+            if self.is_account(&abs_transaction.signer.address) {
+                // the cosignatures of an aggregate are part of its size in the block.
+                let size = tx.size()
+                    + aggregate.map_or(0, |aggregate| {
+                        aggregate.cosignatures.len() * (KEY_SIZE + SIGNATURE_SIZE)
+                    });
+
+                let fee = (batch.block.fee_multiplier.max(0) as u64).saturating_mul(size as u64);
+                let fee = match abs_transaction.max_fee {
+                    Some(max_fee) => fee.min(max_fee.as_u64()),
+                    None => fee,
+                };
+
+                if fee > 0 {
+                    changes.push(BalanceChange {
+                        height,
+                        mosaic_id: self.currency.clone(),
+                        amount: -i128::from(fee),
+                        cause: BalanceChangeCause::Fee(hash.to_owned()),
+                    });
+                }
+            }
+
+            let inner_transactions: Vec<(u32, &dyn Transaction)> = match aggregate {
+                Some(aggregate) => aggregate
+                    .inner_transactions
+                    .iter()
+                    .enumerate()
+                    .map(|(index, inner)| (index as u32 + 1, inner.as_ref()))
+                    .collect(),
+                None => vec![(0, tx.as_ref())],
+            };
+
+            for (secondary_id, inner) in inner_transactions.into_iter() {
+                let source = ReceiptSource {
+                    primary_id,
+                    secondary_id,
+                };
+
+                self.transaction_changes(
+                    inner,
+                    source,
+                    &hash,
+                    height,
+                    &batch.receipts,
+                    &mut changes,
+                )?;
+            }
+        }
+
+        for statement in batch.receipts.transaction_statements.iter() {
+            for receipt in statement.receipts.iter() {
+                self.receipt_changes(receipt, height, &mut changes);
+            }
+        }
+
+        Ok(changes)
+    }
+
+    fn transaction_changes(
+        &self,
+        tx: &dyn Transaction,
+        source: ReceiptSource,
+        hash: &str,
+        height: u64,
+        statements: &Statements,
+        changes: &mut Vec<BalanceChange>,
+    ) -> Result<()> {
+        let signer = tx.abs_transaction().signer.address;
+
+        if let Some(transfer) = tx.as_any().downcast_ref::<TransferTransaction>() {
+            let recipient = statements.resolve_address(&transfer.recipient, source);
+
+            let sign = match (self.is_account(&signer), self.is_account(&recipient)) {
+                (true, false) => -1,
+                (false, true) => 1,
+                _ => return Ok(()),
+            };
+
+            for mosaic in transfer.mosaics.iter() {
+                changes.push(BalanceChange {
+                    height,
+                    mosaic_id: statements.resolve_mosaic(mosaic.asset_id.as_ref(), source)?,
+                    amount: sign * i128::from(mosaic.amount.as_u64()),
+                    cause: BalanceChangeCause::Transfer(hash.to_owned()),
+                });
+            }
+        } else if let Some(supply) = tx.as_any().downcast_ref::<MosaicSupplyChangeTransaction>() {
+            if !self.is_account(&signer) {
+                return Ok(());
+            }
+
+            let sign = match supply.supply_type {
+                MosaicSupplyType::Increase => 1,
+                MosaicSupplyType::Decrease => -1,
+            };
+
+            changes.push(BalanceChange {
+                height,
+                mosaic_id: statements.resolve_mosaic(supply.asset_id.as_ref(), source)?,
+                amount: sign * i128::from(supply.delta.as_u64()),
+                cause: BalanceChangeCause::SupplyChange(hash.to_owned()),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn receipt_changes(&self, receipt: &Receipt, height: u64, changes: &mut Vec<BalanceChange>) {
+        let (mosaic, amount) = match receipt {
+            Receipt::BalanceChange(change) if self.is_account(&change.account.address) => {
+                let amount = i128::from(change.mosaic.amount.as_u64());
+                if change.is_credit() {
+                    (&change.mosaic, amount)
+                } else {
+                    (&change.mosaic, -amount)
+                }
+            }
+            Receipt::BalanceTransfer(transfer) => {
+                let amount = i128::from(transfer.mosaic.amount.as_u64());
+                match (
+                    self.is_account(&transfer.sender.address),
+                    self.is_account(&transfer.recipient),
+                ) {
+                    (true, false) => (&transfer.mosaic, -amount),
+                    (false, true) => (&transfer.mosaic, amount),
+                    _ => return,
+                }
+            }
+            _ => return,
+        };
+
+        changes.push(BalanceChange {
+            height,
+            mosaic_id: mosaic.asset_id.to_mosaic_id(),
+            amount,
+            cause: BalanceChangeCause::Receipt(receipt.receipt_type()),
+        });
+    }
+}
+
+impl SyncSink for BalanceTracker {
+    fn on_batch(&mut self, batch: SyncBatch) -> Result<()> {
+        let height = batch.height().as_u64();
+        let changes = self.block_changes(&batch)?;
+
+        let mut history = self
+            .history
+            .lock()
+            .map_err(|_| Error::internal(crate::errors_const::ERR_BALANCE_HISTORY_LOCK))?;
+
+        // a batch passed again after an error was already applied.
+        if height <= history.synced_height {
+            return Ok(());
+        }
+
+        history.apply_block(height, changes)
+    }
+
+    fn on_rollback(&mut self, rollback: Rollback) -> Result<()> {
+        let mut history = self
+            .history
+            .lock()
+            .map_err(|_| Error::internal(crate::errors_const::ERR_BALANCE_HISTORY_LOCK))?;
+
+        history.rollback(rollback.from_height);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        account::{Address, PublicAccount},
+        blockchain::BlockInfo,
+        message::PlainMessage,
+        models::error::Error,
+        mosaic::{Mosaic, MosaicId},
+        multisig::Cosignature,
+        namespace::NamespaceId,
+        network::PUBLIC_TEST,
+        receipt::{
+            AddressResolutionEntry, AddressResolutionStatement, BalanceChangeReceipt,
+            BalanceTransferReceipt, MosaicResolutionEntry, MosaicResolutionStatement, Receipt,
+            ReceiptSource, ReceiptType, Statements, TransactionStatement,
+        },
+        sync::{Rollback, SyncBatch, SyncSink},
+        transaction::{
+            AggregateTransaction, Deadline, Timestamp, Transaction, TransactionInfo, Transactions,
+            TransferTransaction,
+        },
+        AssetId, Uint64,
+    };
+
+    use super::{BalanceChange, BalanceChangeCause, BalancePoint, BalanceTracker};
+
+    const ACCOUNT: &str = "93C3B9075649F59BD88573ADC55B8915B12390A47C76F0C45F362ED0800BE237";
+    const OTHER: &str = "3B49BF0A08BB7528E54BB803BEEE0D935B2C800364917B6EFF331368A4232FD5";
+    const THIRD: &str = "5649D09FB884424AB5E3ED16B965CF69E3048A5E641287C319AC3DE995C97FB0";
+
+    fn public_account(public_key: &str) -> PublicAccount {
+        PublicAccount::from_public_key(public_key, PUBLIC_TEST).unwrap()
+    }
+
+    fn address(public_key: &str) -> Address {
+        public_account(public_key).address
+    }
+
+    fn currency() -> MosaicId {
+        MosaicId::new(0x0DC6_7FBE_1CAD_29E3)
+    }
+
+    fn tracker() -> BalanceTracker {
+        BalanceTracker::new(&address(ACCOUNT), currency())
+    }
+
+    /// The hash of the `index`th transaction of a block.
+    fn hash(index: u32) -> String {
+        format!("{:02X}", index + 1).repeat(32)
+    }
+
+    fn info(index: u32) -> TransactionInfo {
+        TransactionInfo {
+            height: Uint64::new(0),
+            index,
+            id: String::new(),
+            hash: Some(hash(index)),
+            merkle_component_hash: None,
+            agregate_hash: None,
+            aggregate_id: None,
+            unique_aggregate_hash: None,
+        }
+    }
+
+    /// A transfer signed by `signer`, the `index`th transaction of its block.
+    fn transfer(
+        signer: &str,
+        recipient: &Address,
+        mosaic: Mosaic,
+        index: u32,
+    ) -> TransferTransaction {
+        let mut transfer = TransferTransaction::new(
+            Deadline::default(),
+            recipient.to_owned(),
+            vec![mosaic],
+            PlainMessage::empty(),
+            PUBLIC_TEST,
+        )
+        .unwrap();
+        transfer.abs_transaction.signer = public_account(signer);
+        transfer.abs_transaction.transaction_info = Some(info(index));
+        transfer
+    }
+
+    fn no_statements() -> Statements {
+        Statements {
+            transaction_statements: vec![],
+            address_resolution_statements: vec![],
+            mosaic_resolution_statements: vec![],
+        }
+    }
+
+    fn batch(
+        height: u64,
+        fee_multiplier: i32,
+        transactions: Transactions,
+        receipts: Statements,
+    ) -> SyncBatch {
+        let block = BlockInfo {
+            network_type: PUBLIC_TEST,
+            signature: String::new(),
+            signer: public_account(THIRD),
+            version: 3,
+            ver_type: 0x8143,
+            height: Uint64::new(height),
+            timestamp: Timestamp::new(0),
+            difficulty: Uint64::new(0),
+            num_transactions: transactions.len() as u64,
+            fee_multiplier,
+            hash: String::new(),
+            generation_hash: String::new(),
+            previous_block_hash: String::new(),
+            block_transactions_hash: String::new(),
+            block_receipts_hash: String::new(),
+            state_hash: String::new(),
+            beneficiary: None,
+            fee_interest: 1,
+            total_fee: Uint64::new(0),
+            fee_interest_denominator: 1,
+        };
+
+        SyncBatch {
+            block,
+            transactions,
+            receipts,
+        }
+    }
+
+    fn source(primary_id: u32) -> ReceiptSource {
+        ReceiptSource {
+            primary_id,
+            secondary_id: 0,
+        }
+    }
+
+    fn fees(tracker: &BalanceTracker) -> Vec<i128> {
+        tracker
+            .history()
+            .changes()
+            .iter()
+            .filter(|change| matches!(change.cause, BalanceChangeCause::Fee(_)))
+            .map(|change| change.amount)
+            .collect()
+    }
+
+    #[test]
+    fn a_poisoned_history_is_an_internal_error() {
+        let tracker = tracker();
+        let history = tracker.history.clone();
+        let _ = ::std::thread::spawn(move || {
+            let _history = history.lock().unwrap();
+            panic!("poisons the history lock");
+        })
+        .join();
+
+        match tracker.with_opening_balance(currency(), 0, 100) {
+            Err(Error::Internal(_)) => {}
+            Err(err) => panic!("unexpected error {}", err),
+            Ok(_) => panic!("the opening balance was set on a poisoned history"),
+        }
+    }
+
+    #[test]
+    fn transfers_move_the_balance_block_by_block() {
+        let mut tracker = tracker().with_opening_balance(currency(), 0, 1000).unwrap();
+
+        let sent = transfer(ACCOUNT, &address(OTHER), Mosaic::new(currency(), 100), 0);
+        tracker
+            .on_batch(batch(1, 0, vec![Box::new(sent)], no_statements()))
+            .unwrap();
+
+        let received = transfer(OTHER, &address(ACCOUNT), Mosaic::new(currency(), 30), 0);
+        tracker
+            .on_batch(batch(2, 0, vec![Box::new(received)], no_statements()))
+            .unwrap();
+
+        let history = tracker.history();
+        assert_eq!(
+            history.series(&currency()),
+            [
+                BalancePoint {
+                    height: 0,
+                    balance: 1000
+                },
+                BalancePoint {
+                    height: 1,
+                    balance: 900
+                },
+                BalancePoint {
+                    height: 2,
+                    balance: 930
+                },
+            ]
+        );
+        assert_eq!(history.synced_height(), 2);
+        assert_eq!(
+            history.changes()[1],
+            BalanceChange {
+                height: 2,
+                mosaic_id: currency(),
+                amount: 30,
+                cause: BalanceChangeCause::Transfer(hash(0)),
+            }
+        );
+
+        // a batch passed again is not applied twice.
+        let received = transfer(OTHER, &address(ACCOUNT), Mosaic::new(currency(), 30), 0);
+        tracker
+            .on_batch(batch(2, 0, vec![Box::new(received)], no_statements()))
+            .unwrap();
+        assert_eq!(tracker.balance_at(&currency(), 2), 930);
+    }
+
+    #[test]
+    fn rollback_drops_the_replaced_blocks() {
+        let mut tracker = tracker();
+
+        for height in 1..=3 {
+            let received = transfer(OTHER, &address(ACCOUNT), Mosaic::new(currency(), 10), 0);
+            tracker
+                .on_batch(batch(height, 0, vec![Box::new(received)], no_statements()))
+                .unwrap();
+        }
+
+        tracker
+            .on_rollback(Rollback {
+                from_height: 2,
+                to_height: 3,
+                hashes: vec![hash(1), hash(2)],
+            })
+            .unwrap();
+
+        let history = tracker.history();
+        assert_eq!(history.synced_height(), 1);
+        assert_eq!(history.changes().len(), 1);
+        assert_eq!(history.balance_at(&currency(), 3), 10);
+
+        // the blocks of the new chain are applied from the rolled back height.
+        let received = transfer(OTHER, &address(ACCOUNT), Mosaic::new(currency(), 5), 0);
+        tracker
+            .on_batch(batch(2, 0, vec![Box::new(received)], no_statements()))
+            .unwrap();
+        assert_eq!(tracker.balance_at(&currency(), 2), 15);
+    }
+
+    #[test]
+    fn fees_are_capped_at_the_max_fee() {
+        let mut tracker = tracker()
+            .with_opening_balance(currency(), 0, 100_000)
+            .unwrap();
+
+        let uncapped = transfer(ACCOUNT, &address(OTHER), Mosaic::new(currency(), 1), 0);
+        let size = uncapped.size() as i128;
+
+        let mut capped = transfer(ACCOUNT, &address(OTHER), Mosaic::new(currency(), 1), 1);
+        capped.abs_transaction.max_fee = Some(Uint64::new(50));
+
+        tracker
+            .on_batch(batch(
+                1,
+                10,
+                vec![Box::new(uncapped), Box::new(capped)],
+                no_statements(),
+            ))
+            .unwrap();
+
+        assert_eq!(fees(&tracker), vec![-10 * size, -50]);
+    }
+
+    #[test]
+    fn aggregate_fees_count_the_cosignatures() {
+        let mut tracker = tracker()
+            .with_opening_balance(currency(), 0, 100_000)
+            .unwrap();
+
+        let inner = transfer(OTHER, &address(THIRD), Mosaic::new(currency(), 1), 0);
+        let mut aggregate = AggregateTransaction::new_complete(
+            Deadline::default(),
+            vec![Box::new(inner)],
+            PUBLIC_TEST,
+        )
+        .unwrap();
+        aggregate.abs_transaction.signer = public_account(ACCOUNT);
+        aggregate.abs_transaction.transaction_info = Some(info(0));
+        aggregate.cosignatures = [OTHER, THIRD]
+            .iter()
+            .map(|signer| Cosignature {
+                signature: "00".repeat(64),
+                signer: public_account(signer),
+            })
+            .collect();
+
+        // a cosignature is a public key and a signature.
+        let size = (aggregate.size() + 2 * (32 + 64)) as i128;
+
+        tracker
+            .on_batch(batch(1, 2, vec![Box::new(aggregate)], no_statements()))
+            .unwrap();
+
+        assert_eq!(fees(&tracker), vec![-2 * size]);
+    }
+
+    #[test]
+    fn aliases_resolve_to_the_last_entry_before_the_transaction() {
+        let mut tracker = tracker();
+
+        let alias = Address::from_encoded(&format!("91{}", "01".repeat(24))).unwrap();
+        let currency_alias = NamespaceId::new(0x85BB_EA6C_C462_B244);
+
+        // the alias moves from the other account to the tracked one after the first transfer.
+        let statements = Statements {
+            transaction_statements: vec![],
+            address_resolution_statements: vec![AddressResolutionStatement {
+                height: Uint64::new(1),
+                unresolved: alias.clone(),
+                resolution_entries: vec![
+                    AddressResolutionEntry {
+                        source: source(1),
+                        resolved: address(OTHER),
+                    },
+                    AddressResolutionEntry {
+                        source: source(2),
+                        resolved: address(ACCOUNT),
+                    },
+                ],
+            }],
+            mosaic_resolution_statements: vec![MosaicResolutionStatement {
+                height: Uint64::new(1),
+                unresolved: Uint64::new(currency_alias.to_u64()),
+                resolution_entries: vec![MosaicResolutionEntry {
+                    source: source(1),
+                    resolved: currency(),
+                }],
+            }],
+        };
+
+        let transactions: Transactions = (0..3)
+            .map(|index| {
+                let transfer = transfer(THIRD, &alias, Mosaic::new(currency_alias, 10), index);
+                Box::new(transfer) as Box<dyn Transaction>
+            })
+            .collect();
+
+        tracker
+            .on_batch(batch(1, 0, transactions, statements))
+            .unwrap();
+
+        let history = tracker.history();
+        let transfers: Vec<&BalanceChangeCause> = history
+            .changes()
+            .iter()
+            .map(|change| &change.cause)
+            .collect();
+        assert_eq!(
+            transfers,
+            vec![
+                &BalanceChangeCause::Transfer(hash(1)),
+                &BalanceChangeCause::Transfer(hash(2)),
+            ]
+        );
+        assert_eq!(history.balance_at(&currency(), 1), 20);
+    }
+
+    #[test]
+    fn receipts_credit_and_debit_the_account() {
+        let mut tracker = tracker().with_opening_balance(currency(), 0, 100).unwrap();
+
+        let change = |account: &str, receipt_type: ReceiptType, amount: u64| {
+            Receipt::BalanceChange(BalanceChangeReceipt {
+                version: 1,
+                receipt_type,
+                account: public_account(account),
+                mosaic: Mosaic::new(currency(), amount),
+            })
+        };
+        let transfer = |sender: &str, recipient: &str, amount: u64| {
+            Receipt::BalanceTransfer(BalanceTransferReceipt {
+                version: 1,
+                receipt_type: ReceiptType::MosaicLevy,
+                sender: public_account(sender),
+                recipient: address(recipient),
+                mosaic: Mosaic::new(currency(), amount),
+            })
+        };
+
+        let statements = Statements {
+            transaction_statements: vec![TransactionStatement {
+                height: Uint64::new(1),
+                source: source(0),
+                receipts: vec![
+                    change(ACCOUNT, ReceiptType::HarvestFee, 5),
+                    change(ACCOUNT, ReceiptType::LockHashCreated, 10),
+                    change(OTHER, ReceiptType::HarvestFee, 1000),
+                    transfer(ACCOUNT, OTHER, 2),
+                    transfer(OTHER, ACCOUNT, 3),
+                ],
+            }],
+            address_resolution_statements: vec![],
+            mosaic_resolution_statements: vec![],
+        };
+
+        tracker.on_batch(batch(1, 0, vec![], statements)).unwrap();
+
+        let amounts: Vec<(i128, BalanceChangeCause)> = tracker
+            .history()
+            .changes()
+            .iter()
+            .map(|change| (change.amount, change.cause.to_owned()))
+            .collect();
+        assert_eq!(
+            amounts,
+            vec![
+                (5, BalanceChangeCause::Receipt(ReceiptType::HarvestFee)),
+                (
+                    -10,
+                    BalanceChangeCause::Receipt(ReceiptType::LockHashCreated)
+                ),
+                (-2, BalanceChangeCause::Receipt(ReceiptType::MosaicLevy)),
+                (3, BalanceChangeCause::Receipt(ReceiptType::MosaicLevy)),
+            ]
+        );
+        assert_eq!(tracker.balance_at(&currency(), 1), 96);
+    }
+}
@@ -0,0 +1,705 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::{HashMap, VecDeque},
+        time::Duration,
+    },
+    futures_util::{stream, StreamExt},
+    tokio::time::delay_for,
+};
+
+use crate::{
+    api::{SiriusClient, DEFAULT_FINALITY_DEPTH},
+    blockchain::BlockInfo,
+    errors_const::ERR_SYNC_BROKEN_CHAIN,
+    models::error::Error,
+    transaction::Transactions,
+    websocket::{KeepAlive, ReconnectPolicy, SiriusWebsocketClient, WsChannel, WsEvent},
+    Result,
+};
+
+use super::{Checkpoint, CheckpointStore, MemoryCheckpoint, Rollback, SyncBatch, SyncSink};
+
+/// Default number of blocks whose transactions and receipts are read at the same time.
+pub const DEFAULT_SYNC_CONCURRENCY: usize = 4;
+
+/// Number of blocks read by one request, the largest limit allowed by the node.
+const BLOCKS_PAGE_SIZE: i32 = 100;
+
+/// Page size of the block transactions.
+const TRANSACTIONS_PAGE_SIZE: i32 = 100;
+
+/// Number of times a block that does not follow the last kept block is read again when the
+/// node still has that last block, e.g. while it switches to a fork.
+const BROKEN_LINK_RETRIES: u32 = 3;
+
+/// Delay before a block that did not follow the last kept block is read again.
+const BROKEN_LINK_DELAY: Duration = Duration::from_secs(1);
+
+/// Passes every block of the chain, with its transactions and receipts, to a [SyncSink].
+///
+/// The sync starts after the last height saved in its [CheckpointStore], or at
+/// [ChainSync::with_start_height] when there is none. [ChainSync::sync_to_tip] reads the
+/// blocks up to the current chain height from the REST routes; [ChainSync::run] then follows
+/// the `block` websocket channel.
+///
+/// The transactions and receipts of several blocks are read at the same time, see
+/// [ChainSync::with_concurrency], but the sink always receives the blocks in height order.
+///
+/// The height and hash of the last blocks accepted by the sink, down to the finality depth,
+/// are saved to notice forks, also after a restart: when a block does not follow the last
+/// one passed to the sink, or the websocket announces another block at a known height, the
+/// blocks no longer in the chain are passed to [SyncSink::on_rollback] and the sync goes on
+/// from the last block both chains share.
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::{
+///    api::SiriusClient,
+///    sync::{ChainSync, FileCheckpoint, SyncBatch},
+///    Result,
+///};
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+/// let client = SiriusClient::new(node_url).await.unwrap();
+///
+///    let sink = |batch: SyncBatch| -> Result<()> {
+///        println!("block {}: {} transactions", batch.height(), batch.transactions.len());
+///        Ok(())
+///    };
+///
+///    let mut sync = ChainSync::new(&client, sink)
+///        .with_checkpoint(FileCheckpoint::new("sync.checkpoint"))
+///        .with_concurrency(8);
+///
+///    if let Err(err) = sync.run().await {
+///        eprintln!("{}", err)
+///    }
+///}
+/// ```
+pub struct ChainSync {
+    client: SiriusClient,
+    sink: Box<dyn SyncSink>,
+    checkpoint: Box<dyn CheckpointStore>,
+    start_height: u64,
+    concurrency: usize,
+    reconnect: ReconnectPolicy,
+    keepalive: KeepAlive,
+    finality_depth: u64,
+    /// The last blocks accepted by the sink, oldest first.
+    recent: VecDeque<Checkpoint>,
+    loaded: bool,
+}
+
+impl ChainSync {
+    pub fn new<S>(client: &SiriusClient, sink: S) -> Self
+    where
+        S: SyncSink + 'static,
+    {
+        ChainSync {
+            client: client.to_owned(),
+            sink: Box::new(sink),
+            checkpoint: Box::new(MemoryCheckpoint::new()),
+            start_height: 1,
+            concurrency: DEFAULT_SYNC_CONCURRENCY,
+            reconnect: ReconnectPolicy::default(),
+            keepalive: KeepAlive::default(),
+            finality_depth: DEFAULT_FINALITY_DEPTH,
+            recent: VecDeque::new(),
+            loaded: false,
+        }
+    }
+
+    /// Resumes from and saves to `checkpoint`; the default [MemoryCheckpoint] starts over
+    /// with every new `ChainSync`.
+    pub fn with_checkpoint<C>(mut self, checkpoint: C) -> Self
+    where
+        C: CheckpointStore + 'static,
+    {
+        self.checkpoint = Box::new(checkpoint);
+        self.recent.clear();
+        self.loaded = false;
+        self
+    }
+
+    /// The first height passed to the sink when the checkpoint store is empty; 1 by default.
+    pub fn with_start_height(mut self, height: u64) -> Self {
+        self.start_height = height.max(1);
+        self
+    }
+
+    /// Reads the transactions and receipts of at most `concurrency` blocks at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Reconnection of the websocket followed by [ChainSync::run].
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    /// Keepalive of the websocket followed by [ChainSync::run].
+    pub fn with_keepalive(mut self, keepalive: KeepAlive) -> Self {
+        self.keepalive = keepalive;
+        self
+    }
+
+    /// Number of blocks below the chain height that can still be rolled back, and so the
+    /// number of block hashes saved to find where a fork starts.
+    pub fn with_finality_depth(mut self, depth: u64) -> Self {
+        self.finality_depth = depth.max(1);
+        self
+    }
+
+    /// The height of the last block accepted by the sink, `None` before the first one.
+    pub fn synced_height(&mut self) -> Result<Option<u64>> {
+        if !self.loaded {
+            self.recent.extend(self.checkpoint.load()?);
+            while self.recent.len() as u64 > self.finality_depth {
+                self.recent.pop_front();
+            }
+            self.loaded = true;
+        }
+        Ok(self.recent.back().map(|checkpoint| checkpoint.height))
+    }
+
+    /// Passes the blocks up to the current chain height to the sink and returns that height.
+    pub async fn sync_to_tip(&mut self) -> Result<u64> {
+        let height_info = self.client.chain_api().get_blockchain_height().await?;
+        let tip = height_info.height.as_u64();
+
+        self.sync_to(tip).await?;
+
+        Ok(tip)
+    }
+
+    /// Syncs to the tip, then passes every new block to the sink as it is announced on the
+    /// `block` websocket channel, along with any block missed while the websocket was
+    /// reconnecting.
+    ///
+    /// Only returns on an error of the sink, the checkpoint store or the REST routes, or
+    /// once the websocket gives up reconnecting.
+    pub async fn run(&mut self) -> Result<()> {
+        self.sync_to_tip().await?;
+
+        let mut ws = SiriusWebsocketClient::new(self.client.node())
+            .await?
+            .with_reconnect(self.reconnect)
+            .with_keepalive(self.keepalive);
+
+        let mut blocks = ws.subscribe(WsChannel::Block, None).await?;
+
+        let follow = async {
+            while let Some(event) = blocks.next().await {
+                // a block that failed to decode is read with the next one.
+                if let Ok(WsEvent::Block(block)) = event {
+                    self.on_announced(&block).await?;
+                }
+            }
+            Ok::<(), Error>(())
+        };
+
+        tokio::select! {
+            result = ws.listen() => result,
+            result = follow => result,
+        }
+    }
+
+    /// Rolls back first when `block` replaces a block already passed to the sink.
+    async fn on_announced(&mut self, block: &BlockInfo) -> Result<()> {
+        let height = block.height.as_u64();
+
+        let replaced = self
+            .recent
+            .iter()
+            .find(|checkpoint| checkpoint.height == height && !checkpoint.hash.is_empty())
+            .map_or(false, |checkpoint| {
+                !same_hash(&checkpoint.hash, &block.hash)
+            });
+
+        if replaced {
+            self.rollback().await?;
+        }
+
+        self.sync_to(height).await
+    }
+
+    /// Passes the blocks after the checkpoint up to `to_height`, both included, to the sink.
+    async fn sync_to(&mut self, to_height: u64) -> Result<()> {
+        let client = self.client.clone();
+        let mut retries = 0;
+
+        loop {
+            let height = match self.synced_height()? {
+                Some(synced_height) => synced_height + 1,
+                None => self.start_height,
+            };
+
+            if height > to_height {
+                return Ok(());
+            }
+
+            let count = (to_height - height + 1).min(BLOCKS_PAGE_SIZE as u64);
+            let blocks = read_blocks(&client, height, count).await?;
+
+            let mut forked = false;
+            let mut accepted = false;
+            {
+                let mut batches = stream::iter(blocks)
+                    .map(|block| read_batch(&client, block))
+                    .buffered(self.concurrency);
+
+                while let Some(batch) = batches.next().await {
+                    let batch = batch?;
+
+                    if !self.follows(&batch.block) {
+                        forked = true;
+                        break;
+                    }
+
+                    self.accept(batch)?;
+                    accepted = true;
+                }
+            }
+
+            if accepted {
+                retries = 0;
+            }
+
+            if forked && !self.rollback().await? {
+                // the node still has the last kept block but served one that does not follow it.
+                retries += 1;
+                if retries > BROKEN_LINK_RETRIES {
+                    return Err(Error::internal(ERR_SYNC_BROKEN_CHAIN));
+                }
+                delay_for(BROKEN_LINK_DELAY).await;
+            }
+        }
+    }
+
+    /// Whether `block` follows the last block accepted by the sink.
+    fn follows(&self, block: &BlockInfo) -> bool {
+        match self.recent.back() {
+            Some(last) if last.height + 1 == block.height.as_u64() && !last.hash.is_empty() => {
+                same_hash(&last.hash, &block.previous_block_hash)
+            }
+            _ => true,
+        }
+    }
+
+    fn accept(&mut self, batch: SyncBatch) -> Result<()> {
+        let checkpoint = Checkpoint::new(batch.height().as_u64(), &batch.block.hash);
+
+        self.sink.on_batch(batch)?;
+
+        // the kept blocks only change once saved, a failed save passes the batch again.
+        let mut recent = self.recent.clone();
+        recent.push_back(checkpoint);
+        while recent.len() as u64 > self.finality_depth {
+            recent.pop_front();
+        }
+
+        self.save(recent)
+    }
+
+    /// Drops the recent blocks no longer in the chain, passes them to the sink and moves the
+    /// checkpoint back to the last block still in the chain, or fails with
+    /// `Error::ForkTooDeep` when none of them is. Returns whether any block was dropped.
+    async fn rollback(&mut self) -> Result<bool> {
+        let height_info = self.client.chain_api().get_blockchain_height().await?;
+        let tip = height_info.height.as_u64();
+
+        // the recent blocks are only dropped once the last block both chains share is found.
+        let mut kept = self.recent.len();
+        while kept > 0 {
+            let last = self.recent[kept - 1].to_owned();
+            if last.height <= tip {
+                let block = self
+                    .client
+                    .block_api()
+                    .get_block_by_height(last.height)
+                    .await?;
+                if same_hash(&last.hash, &block.hash) {
+                    break;
+                }
+            }
+            kept -= 1;
+        }
+
+        let last = match kept {
+            0 => {
+                let oldest = self
+                    .recent
+                    .front()
+                    .map_or(0, |checkpoint| checkpoint.height);
+                return Err(Error::ForkTooDeep(oldest));
+            }
+            _ => self.recent[kept - 1].to_owned(),
+        };
+
+        if kept == self.recent.len() {
+            return Ok(false);
+        }
+
+        let mut recent = self.recent.clone();
+        let hashes: Vec<_> = recent
+            .drain(kept..)
+            .map(|checkpoint| checkpoint.hash)
+            .collect();
+
+        self.sink.on_rollback(Rollback {
+            from_height: last.height + 1,
+            to_height: last.height + hashes.len() as u64,
+            hashes,
+        })?;
+
+        self.save(recent)?;
+        Ok(true)
+    }
+
+    fn save(&mut self, recent: VecDeque<Checkpoint>) -> Result<()> {
+        let checkpoints: Vec<Checkpoint> = recent.iter().cloned().collect();
+        self.checkpoint.save(&checkpoints)?;
+
+        self.recent = recent;
+        Ok(())
+    }
+}
+
+fn same_hash(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+/// The `count` blocks from `height`, in height order.
+async fn read_blocks(client: &SiriusClient, height: u64, count: u64) -> Result<Vec<BlockInfo>> {
+    let mut page: HashMap<u64, BlockInfo> = client
+        .block_api()
+        .get_blocks_by_height_with_limit(height, BLOCKS_PAGE_SIZE)
+        .await?
+        .into_iter()
+        .map(|block| (block.height.as_u64(), block))
+        .collect();
+
+    let mut blocks = Vec::with_capacity(count as usize);
+    for height in height..height + count {
+        let block = match page.remove(&height) {
+            Some(block) => block,
+            None => client.block_api().get_block_by_height(height).await?,
+        };
+        blocks.push(block);
+    }
+
+    Ok(blocks)
+}
+
+async fn read_batch(client: &SiriusClient, block: BlockInfo) -> Result<SyncBatch> {
+    let height = block.height.as_u64();
+
+    let transactions = if block.num_transactions == 0 {
+        vec![]
+    } else {
+        read_transactions(client, height).await?
+    };
+
+    let receipts = client.block_api().get_block_receipts(height).await?;
+
+    Ok(SyncBatch {
+        block,
+        transactions,
+        receipts,
+    })
+}
+
+/// Every transaction of the block at `height`, page by page.
+async fn read_transactions(client: &SiriusClient, height: u64) -> Result<Transactions> {
+    let mut transactions: Transactions = vec![];
+    let mut id: Option<String> = None;
+
+    loop {
+        let page = client
+            .block_api()
+            .get_block_transactions(height, Some(TRANSACTIONS_PAGE_SIZE), id.as_deref())
+            .await?;
+
+        let count = page.len();
+        id = page
+            .last()
+            .and_then(|tx| tx.abs_transaction().transaction_info)
+            .map(|info| info.id);
+
+        transactions.extend(page);
+
+        if count < TRANSACTIONS_PAGE_SIZE as usize || id.is_none() {
+            break;
+        }
+    }
+
+    Ok(transactions)
+}
+
+#[cfg(all(test, feature = "mock-node"))]
+mod tests {
+    use {
+        ::std::{
+            fs,
+            sync::{Arc, Mutex},
+            time::Duration,
+        },
+        tokio::{sync::mpsc, time::timeout},
+    };
+
+    use crate::{
+        account::Account,
+        api::SiriusClient,
+        errors_const::ERR_SYNC_BROKEN_CHAIN,
+        message::PlainMessage,
+        mock::MockNode,
+        models::error::Error,
+        mosaic::Mosaic,
+        sync::{CheckpointStore, FileCheckpoint, Rollback, SyncBatch, SyncSink},
+        transaction::{Deadline, TransferTransaction},
+        Result,
+    };
+
+    use super::ChainSync;
+
+    type Batches = Arc<Mutex<Vec<(u64, usize)>>>;
+
+    /// A sink recording the height and number of transactions of every batch.
+    fn record_batches() -> (Batches, impl FnMut(SyncBatch) -> Result<()> + Send) {
+        let batches = Batches::default();
+        let sink_batches = batches.clone();
+        let sink = move |batch: SyncBatch| -> Result<()> {
+            sink_batches
+                .lock()
+                .unwrap()
+                .push((batch.height().as_u64(), batch.transactions.len()));
+            Ok(())
+        };
+        (batches, sink)
+    }
+
+    /// Announces `count` transfers to be included in the next harvested block.
+    async fn announce_transfers(node: &MockNode, client: &SiriusClient, count: u64) {
+        let network_type = node.network_type();
+        let sender = Account::new(network_type);
+        let recipient = Account::new(network_type).address();
+
+        for amount in 1..=count {
+            let transfer = TransferTransaction::new(
+                Deadline::default(),
+                recipient.clone(),
+                vec![Mosaic::xpx(amount)],
+                PlainMessage::empty(),
+                network_type,
+            )
+            .unwrap();
+            let signed = sender.sign(transfer, &node.generation_hash()).unwrap();
+            client.transaction_api().announce(&signed).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn sync_resumes_after_the_checkpoint() {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+
+        let path = ::std::env::temp_dir().join(format!(
+            "xpx-chain-sync-{}-resume.checkpoint",
+            ::std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        for _ in 0..3 {
+            node.harvest();
+        }
+
+        let (first, sink) = record_batches();
+        let mut sync = ChainSync::new(&client, sink).with_checkpoint(FileCheckpoint::new(&path));
+        assert_eq!(sync.sync_to_tip().await.unwrap(), 4);
+
+        node.harvest();
+        node.harvest();
+
+        let (second, sink) = record_batches();
+        let mut sync = ChainSync::new(&client, sink).with_checkpoint(FileCheckpoint::new(&path));
+        assert_eq!(sync.synced_height().unwrap(), Some(4));
+        assert_eq!(sync.sync_to_tip().await.unwrap(), 6);
+
+        let _ = fs::remove_file(&path);
+
+        let heights = |batches: &Batches| -> Vec<u64> {
+            batches
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(height, _)| *height)
+                .collect()
+        };
+        assert_eq!(heights(&first), vec![1, 2, 3, 4]);
+        assert_eq!(heights(&second), vec![5, 6]);
+    }
+
+    #[tokio::test]
+    async fn batches_are_passed_in_height_order() {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+
+        // blocks with more transactions take longer to read than the ones after them.
+        let mut expected = vec![(1, 0)];
+        for count in (0..12).rev() {
+            announce_transfers(&node, &client, count).await;
+            expected.push((node.harvest(), count as usize));
+        }
+
+        let (batches, sink) = record_batches();
+        let mut sync = ChainSync::new(&client, sink).with_concurrency(8);
+        sync.sync_to_tip().await.unwrap();
+
+        assert_eq!(*batches.lock().unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn run_passes_the_announced_blocks() {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+
+        node.harvest();
+
+        let (sender, mut heights) = mpsc::unbounded_channel();
+        let sink = move |batch: SyncBatch| -> Result<()> {
+            let _ = sender.send(batch.height().as_u64());
+            Ok(())
+        };
+        let mut sync = ChainSync::new(&client, sink);
+
+        let follow = async {
+            let mut received = vec![];
+            while received.len() < 2 {
+                received.push(heights.recv().await.unwrap());
+            }
+
+            // the blocks after the tip come from the websocket.
+            for _ in 0..200 {
+                if node.subscriptions() == ["block"] {
+                    break;
+                }
+                tokio::time::delay_for(Duration::from_millis(10)).await;
+            }
+            node.harvest();
+            node.harvest();
+
+            while received.len() < 4 {
+                received.push(heights.recv().await.unwrap());
+            }
+            received
+        };
+
+        let received = tokio::select! {
+            result = sync.run() => panic!("run returned {:?}", result),
+            received = timeout(Duration::from_secs(5), follow) => received.unwrap(),
+        };
+
+        assert_eq!(received, vec![1, 2, 3, 4]);
+    }
+
+    #[derive(Default, Clone)]
+    struct RecordingSink {
+        heights: Arc<Mutex<Vec<u64>>>,
+        rollbacks: Arc<Mutex<Vec<Rollback>>>,
+    }
+
+    impl SyncSink for RecordingSink {
+        fn on_batch(&mut self, batch: SyncBatch) -> Result<()> {
+            self.heights.lock().unwrap().push(batch.height().as_u64());
+            Ok(())
+        }
+
+        fn on_rollback(&mut self, rollback: Rollback) -> Result<()> {
+            self.rollbacks.lock().unwrap().push(rollback);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn a_resumed_sync_rolls_back_a_fork() {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+
+        let path = ::std::env::temp_dir().join(format!(
+            "xpx-chain-sync-{}-fork.checkpoint",
+            ::std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        for _ in 0..3 {
+            node.harvest();
+        }
+
+        let mut sync = ChainSync::new(&client, RecordingSink::default())
+            .with_checkpoint(FileCheckpoint::new(&path));
+        sync.sync_to_tip().await.unwrap();
+
+        let saved = FileCheckpoint::new(&path).load().unwrap();
+        let saved_heights: Vec<u64> = saved.iter().map(|checkpoint| checkpoint.height).collect();
+        assert_eq!(saved_heights, vec![1, 2, 3, 4]);
+
+        let replaced = client
+            .block_api()
+            .get_block_by_height(4)
+            .await
+            .unwrap()
+            .hash;
+        node.fork(1);
+        node.harvest();
+
+        let sink = RecordingSink::default();
+        let mut sync =
+            ChainSync::new(&client, sink.clone()).with_checkpoint(FileCheckpoint::new(&path));
+        sync.sync_to_tip().await.unwrap();
+
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            *sink.rollbacks.lock().unwrap(),
+            vec![Rollback {
+                from_height: 4,
+                to_height: 4,
+                hashes: vec![replaced.to_uppercase()],
+            }]
+        );
+        assert_eq!(*sink.heights.lock().unwrap(), vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn a_block_that_never_follows_ends_the_sync() {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+
+        for _ in 0..3 {
+            node.harvest();
+        }
+        node.break_link(3);
+
+        let sink = RecordingSink::default();
+        let mut sync = ChainSync::new(&client, sink.clone());
+
+        match sync.sync_to_tip().await {
+            Err(Error::Internal(message)) => assert_eq!(message, ERR_SYNC_BROKEN_CHAIN),
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(*sink.heights.lock().unwrap(), vec![1, 2]);
+        assert!(sink.rollbacks.lock().unwrap().is_empty());
+        assert_eq!(sync.synced_height().unwrap(), Some(2));
+    }
+}
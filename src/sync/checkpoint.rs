@@ -0,0 +1,133 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crate::{models::error::Error, transaction::Hash, Result};
+
+/// A block passed to the sink of a [ChainSync].
+///
+/// [ChainSync]: struct.ChainSync.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkpoint {
+    pub height: u64,
+    /// Checked against the previous block hash of the next block, empty when unknown.
+    pub hash: Hash,
+}
+
+impl Checkpoint {
+    pub fn new(height: u64, hash: &str) -> Self {
+        Checkpoint {
+            height,
+            hash: hash.to_uppercase(),
+        }
+    }
+}
+
+/// Storage of the last [Checkpoint]s of a [ChainSync], down to its finality depth, so that a
+/// fork of the last blocks is still found after a restart.
+///
+/// [ChainSync]: struct.ChainSync.html
+pub trait CheckpointStore: Send {
+    /// The saved checkpoints, oldest first; empty before the first block.
+    fn load(&mut self) -> Result<Vec<Checkpoint>>;
+
+    /// Replaces the saved checkpoints with `checkpoints`, oldest first.
+    fn save(&mut self, checkpoints: &[Checkpoint]) -> Result<()>;
+}
+
+/// [CheckpointStore] lost with the process.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryCheckpoint {
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl MemoryCheckpoint {
+    pub fn new() -> Self {
+        MemoryCheckpoint::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpoint {
+    fn load(&mut self) -> Result<Vec<Checkpoint>> {
+        Ok(self.checkpoints.clone())
+    }
+
+    fn save(&mut self, checkpoints: &[Checkpoint]) -> Result<()> {
+        self.checkpoints = checkpoints.to_vec();
+        Ok(())
+    }
+}
+
+/// [CheckpointStore] keeping one checkpoint per line as text in a file, e.g. `1234 5D1A...`;
+/// a file holding only a height is also read.
+///
+/// The checkpoints are written to a temporary file next to `path` which is then renamed, so
+/// an interrupted write leaves the previous checkpoints in place.
+#[derive(Debug, Clone)]
+pub struct FileCheckpoint {
+    path: PathBuf,
+}
+
+impl FileCheckpoint {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        FileCheckpoint {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn parse_line(&self, line: &str) -> Result<Checkpoint> {
+        let mut fields = line.split_whitespace();
+
+        let height = fields.next().and_then(|height| height.parse().ok());
+        let hash = fields.next().unwrap_or_default();
+
+        match (height, fields.next()) {
+            (Some(height), None) => Ok(Checkpoint::new(height, hash)),
+            _ => Err(Error::decode(format!(
+                "invalid checkpoint in {}: {}",
+                self.path.display(),
+                line.trim()
+            ))),
+        }
+    }
+}
+
+impl CheckpointStore for FileCheckpoint {
+    fn load(&mut self) -> Result<Vec<Checkpoint>> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| self.parse_line(line))
+            .collect()
+    }
+
+    fn save(&mut self, checkpoints: &[Checkpoint]) -> Result<()> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+
+        let text: String = checkpoints
+            .iter()
+            .map(|checkpoint| format!("{} {}\n", checkpoint.height, checkpoint.hash))
+            .collect();
+
+        fs::write(&tmp, text)?;
+        Ok(fs::rename(&tmp, &self.path)?)
+    }
+}
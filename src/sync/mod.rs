@@ -0,0 +1,15 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+pub use self::balance::*;
+pub use self::chain_sync::*;
+pub use self::checkpoint::*;
+pub use self::sink::*;
+
+mod balance;
+mod chain_sync;
+mod checkpoint;
+mod sink;
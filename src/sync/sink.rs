@@ -0,0 +1,63 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use crate::{
+    blockchain::BlockInfo,
+    receipt::Statements,
+    transaction::{Hash, Height, Transactions},
+    Result,
+};
+
+/// A block with its transactions and receipts.
+#[derive(Debug, Clone)]
+pub struct SyncBatch {
+    pub block: BlockInfo,
+    pub transactions: Transactions,
+    pub receipts: Statements,
+}
+
+impl SyncBatch {
+    pub fn height(&self) -> Height {
+        self.block.height
+    }
+}
+
+/// Blocks passed to the sink that are no longer part of the chain after a fork.
+///
+/// The blocks from `from_height` to `to_height`, both included, were replaced; the blocks of
+/// the new chain are passed to the sink from `from_height` after the rollback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rollback {
+    pub from_height: u64,
+    pub to_height: u64,
+    /// Hashes of the rolled back blocks, from `from_height` up.
+    pub hashes: Vec<Hash>,
+}
+
+/// Receives the blocks read by a [ChainSync], in height order.
+///
+/// An error stops the sync before the checkpoint of the batch is saved, so the batch is
+/// passed again when the sync is resumed; a sink must therefore accept a batch twice.
+///
+/// [ChainSync]: struct.ChainSync.html
+pub trait SyncSink: Send {
+    fn on_batch(&mut self, batch: SyncBatch) -> Result<()>;
+
+    /// Called when a fork replaced blocks already passed to [SyncSink::on_batch], so their
+    /// data can be undone. Ignored by default, as by closures.
+    fn on_rollback(&mut self, _rollback: Rollback) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<F> SyncSink for F
+where
+    F: FnMut(SyncBatch) -> Result<()> + Send,
+{
+    fn on_batch(&mut self, batch: SyncBatch) -> Result<()> {
+        self(batch)
+    }
+}
@@ -24,12 +24,11 @@ pub(crate) struct WsBlockInfoDto {
 }
 
 impl WsSubscribeDto for WsBlockInfoDto {
-    type Output = crate::blockchain::BlockInfo;
+    type Output = crate::Result<crate::blockchain::BlockInfo>;
 
     fn compact(self) -> Self::Output {
         self.block
-            .compact(self.meta.generation_hash, 0, [0, 0])
-            .unwrap()
+            .compact(self.meta.hash, self.meta.generation_hash, 0, [0, 0])
     }
 
     fn name(&self) -> &str {
@@ -5,36 +5,51 @@
  */
 
 use {
-    ::std::{borrow::Cow, collections::HashMap},
+    ::std::{
+        borrow::Cow,
+        collections::{HashMap, HashSet},
+    },
     bytes::Bytes,
     downcast_rs::Downcast,
-    futures_util::{SinkExt, StreamExt},
+    futures_util::{stream, FutureExt, StreamExt},
     serde_json::Value,
-    tokio_tungstenite::{connect_async, tungstenite::Message, WebSocketStream},
+    tokio::{
+        sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+        time::{delay_for, delay_until, Instant},
+    },
+    tokio_tungstenite::{
+        connect_async,
+        tungstenite::{Error as WsError, Message},
+    },
     url::Url,
 };
 
 use crate::{
-    api::{map_transaction_dto, TransactionDto},
+    api::{map_transaction_dto, SiriusClient, TransactionDto},
     models::{
         account::Address,
         blockchain::BlockInfo,
         error::Error,
         multisig::CosignatureInfo,
-        transaction::{AggregateTransaction, Transaction, TransactionInfo, TransactionStatus},
+        receipt::{ReceiptSource, Statements},
+        transaction::{
+            AggregateTransaction, Hash, Transaction, TransactionInfo, TransactionStatus,
+            TransferTransaction,
+        },
         Result,
     },
 };
 
 use super::{
-    model::{
-        RouterPath, SubscribeDto, WsConnectionResponse, WsSubscribeDto, PATH_BLOCK,
-        PATH_CONFIRMED_ADDED, PATH_COSIGNATURE, PATH_PARTIAL_ADDED, PATH_PARTIAL_REMOVED,
-        PATH_STATUS, PATH_UNCONFIRMED_ADDED, PATH_UNCONFIRMED_REMOVED,
-    },
-    HandlerBlock, HandlerConfirmedAdd, HandlerCosignature, HandlerPartialAdd, HandlerPartialRemove,
-    HandlerStatus, HandlerUnconfirmedAdd, HandlerUnconfirmedRemoved, WsBlockInfoDto,
-    WsPartialRemoveDto, WsStatusInfoDto, WsUnconfirmedRemovedDto,
+    connection::Connection,
+    event::HandlerStream,
+    handle::Command,
+    model::{RouterPath, SubscribeDto, UnsubscribeDto, WsConnectionResponse, WsSubscribeDto},
+    ConnectionState, HandlerBlock, HandlerConfirmedAdd, HandlerCosignature, HandlerPartialAdd,
+    HandlerPartialRemove, HandlerStatus, HandlerUnconfirmedAdd, HandlerUnconfirmedRemoved,
+    KeepAlive, ReconnectPolicy, SubscriptionHandle, WsBlockInfoDto, WsChannel, WsEvent,
+    WsEventStream, WsHandle, WsPartialRemoveDto, WsRecorder, WsReplay, WsStatusInfoDto,
+    WsSubscription, WsUnconfirmedRemovedDto,
 };
 
 pub(crate) type AutoStream<S> = S;
@@ -43,14 +58,117 @@ pub trait Handler: Send + Downcast {}
 
 impl_downcast!(Handler);
 
+type Handlers = Vec<(u64, Box<dyn Handler>)>;
+
+type StateHandler = Box<dyn Fn(ConnectionState) -> bool + Send>;
+
+type ErrorHandler = Box<dyn Fn(&Error) -> bool + Send>;
+
+/// `uid` of a replaying client, which has no connection to a node.
+const REPLAY_UID: &str = "replay";
+
+/// Page size of the block transactions read by the backfill.
+const BACKFILL_PAGE_SIZE: i32 = 100;
+
+/// Websocket client of a node.
+///
+/// Subscriptions are keyed by channel and address, so the same channel can be watched for
+/// many accounts, and each subscription can have several handlers. A message is dispatched to
+/// the handlers of the subscription named by its `meta.address`. A handler returning `true`
+/// stops [SiriusWebsocketClient::listen]. A message that cannot be decoded is passed to the
+/// handlers added with [SiriusWebsocketClient::add_error_handlers].
+///
+/// Every `add_*_handlers` method returns a [SubscriptionHandle] removing the handler when
+/// dropped.
+///
+/// With [SiriusWebsocketClient::with_reconnect] a lost connection is opened again with a new
+/// `uid` and every subscription is replayed; [SiriusWebsocketClient::with_backfill] also
+/// passes the blocks and confirmed transactions missed during the outage to the handlers.
+///
+/// A connection silently dropped by a proxy or load balancer is detected with
+/// [SiriusWebsocketClient::with_keepalive], and reconnected like any other lost connection.
+///
+/// The messages received can be recorded with [SiriusWebsocketClient::with_recorder] and
+/// replayed without a node by [SiriusWebsocketClient::replay].
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::{
+///    api::SiriusClient,
+///    websocket::{ConnectionState, KeepAlive, ReconnectPolicy, SiriusWebsocketClient},
+///};
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = vec!["http://bctestnet1.brimstone.xpxsirius.io:3000"];
+/// let client = SiriusClient::new(node_url).await.unwrap();
+///
+///    let mut ws = SiriusWebsocketClient::new(client.node())
+///        .await
+///        .unwrap()
+///        .with_reconnect(ReconnectPolicy::default().with_max_attempts(10))
+///        .with_keepalive(KeepAlive::default())
+///        .with_backfill(&client);
+///
+///    ws.add_state_handlers(|state: ConnectionState| {
+///        println!("{:?}", state);
+///        false
+///    });
+///
+///    ws.add_block_handlers(|block| {
+///        println!("block {}", block.height);
+///        false
+///    })
+///    .await
+///    .unwrap()
+///    .detach();
+///
+///    if let Err(err) = ws.listen().await {
+///        eprintln!("{}", err)
+///    }
+///}
+/// ```
 pub struct SiriusWebsocketClient {
+    url: String,
     uid: WsConnectionResponse,
-    conn: WebSocketStream<AutoStream<tokio::net::TcpStream>>,
-    handlers: HashMap<String, Box<dyn Handler>>,
+    conn: Connection,
+    handlers: HashMap<WsSubscription, Handlers>,
+    next_handler_id: u64,
+    removed_sender: UnboundedSender<(WsSubscription, u64)>,
+    removed_receiver: UnboundedReceiver<(WsSubscription, u64)>,
+    command_sender: UnboundedSender<Command>,
+    command_receiver: UnboundedReceiver<Command>,
+    reconnect: Option<ReconnectPolicy>,
+    keepalive: Option<KeepAlive>,
+    recorder: Option<WsRecorder>,
+    backfill: Option<SiriusClient>,
+    state_handlers: Vec<StateHandler>,
+    error_handlers: Vec<ErrorHandler>,
+    /// Highest chain height whose blocks and transactions were received.
+    synced_height: u64,
+    /// Highest block passed to the handlers by the last backfill.
+    backfilled_height: u64,
+    /// Address and hash of the transactions passed to the handlers by the last backfill.
+    backfilled: HashSet<(String, Hash)>,
+}
+
+/// Why a connection stopped being listened to.
+enum ListenEnd {
+    Stopped,
+    Disconnected(Option<Error>),
+}
+
+/// What woke up the listening loop.
+enum Next {
+    Message(Option<::std::result::Result<Message, WsError>>),
+    Removed((WsSubscription, u64)),
+    Command(Command),
+    KeepAlive,
 }
 
 impl SiriusWebsocketClient {
-    pub async fn add_block_handlers<F>(&mut self, handler_fn: F) -> Result<()>
+    pub async fn add_block_handlers<F>(&mut self, handler_fn: F) -> Result<SubscriptionHandle>
     where
         F: Fn(BlockInfo) -> bool + Send + 'static,
     {
@@ -58,13 +176,17 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(PATH_BLOCK).await?;
-        self.handlers
-            .insert(PATH_BLOCK.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(WsSubscription::new(WsChannel::Block, None), handler)
+            .await
     }
 
-    pub async fn add_status_handlers<F>(&mut self, address: &Address, handler_fn: F) -> Result<()>
+    /// Handles the transactions of `address` rejected by the node; every status is in the
+    /// `TransactionGroup::Failed` group and carries the failure `TransactionStatusCode`.
+    pub async fn add_status_handlers<F>(
+        &mut self,
+        address: &Address,
+        handler_fn: F,
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(TransactionStatus) -> bool + Send + 'static,
     {
@@ -72,18 +194,18 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(PATH_STATUS.to_string(), address))
-            .await?;
-        self.handlers
-            .insert(PATH_STATUS.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(
+            WsSubscription::new(WsChannel::Status, Some(address)),
+            handler,
+        )
+        .await
     }
 
     pub async fn add_confirmed_added_handlers<F>(
         &mut self,
         address: &Address,
         handler_fn: F,
-    ) -> Result<()>
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(Box<dyn Transaction>) -> bool + Send + 'static,
     {
@@ -91,21 +213,18 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(
-            PATH_CONFIRMED_ADDED.to_string(),
-            address,
-        ))
-        .await?;
-        self.handlers
-            .insert(PATH_CONFIRMED_ADDED.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(
+            WsSubscription::new(WsChannel::ConfirmedAdded, Some(address)),
+            handler,
+        )
+        .await
     }
 
     pub async fn add_unconfirmed_removed_handlers<F>(
         &mut self,
         address: &Address,
         handler_fn: F,
-    ) -> Result<()>
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(TransactionInfo) -> bool + Send + 'static,
     {
@@ -113,21 +232,18 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(
-            PATH_UNCONFIRMED_REMOVED.to_string(),
-            address,
-        ))
-        .await?;
-        self.handlers
-            .insert(PATH_UNCONFIRMED_REMOVED.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(
+            WsSubscription::new(WsChannel::UnconfirmedRemoved, Some(address)),
+            handler,
+        )
+        .await
     }
 
     pub async fn add_unconfirmed_added_handlers<F>(
         &mut self,
         address: &Address,
         handler_fn: F,
-    ) -> Result<()>
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(Box<dyn Transaction>) -> bool + Send + 'static,
     {
@@ -135,21 +251,18 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(
-            PATH_UNCONFIRMED_ADDED.to_string(),
-            address,
-        ))
-        .await?;
-        self.handlers
-            .insert(PATH_UNCONFIRMED_ADDED.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(
+            WsSubscription::new(WsChannel::UnconfirmedAdded, Some(address)),
+            handler,
+        )
+        .await
     }
 
     pub async fn add_partial_added_handlers<F>(
         &mut self,
         address: &Address,
         handler_fn: F,
-    ) -> Result<()>
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(AggregateTransaction) -> bool + Send + 'static,
     {
@@ -157,43 +270,37 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(
-            PATH_PARTIAL_ADDED.to_string(),
-            address,
-        ))
-        .await?;
-        self.handlers
-            .insert(PATH_PARTIAL_ADDED.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(
+            WsSubscription::new(WsChannel::PartialAdded, Some(address)),
+            handler,
+        )
+        .await
     }
 
     pub async fn add_partial_removed_handlers<F>(
         &mut self,
         address: &Address,
         handler_fn: F,
-    ) -> Result<()>
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(TransactionInfo) -> bool + Send + 'static,
     {
-        let handler = HandlerUnconfirmedRemoved {
+        let handler = HandlerPartialRemove {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(
-            PATH_PARTIAL_REMOVED.to_string(),
-            address,
-        ))
-        .await?;
-        self.handlers
-            .insert(PATH_PARTIAL_REMOVED.to_string(), Box::new(handler));
-        Ok(())
+        self.add_handler(
+            WsSubscription::new(WsChannel::PartialRemoved, Some(address)),
+            handler,
+        )
+        .await
     }
 
     pub async fn add_cosignature_handlers<F>(
         &mut self,
         address: &Address,
         handler_fn: F,
-    ) -> Result<()>
+    ) -> Result<SubscriptionHandle>
     where
         F: Fn(CosignatureInfo) -> bool + Send + 'static,
     {
@@ -201,31 +308,284 @@ impl SiriusWebsocketClient {
             handler: Box::new(handler_fn),
         };
 
-        self.publish_subscribe_message(&path_parse_address(PATH_COSIGNATURE.to_string(), address))
+        self.add_handler(
+            WsSubscription::new(WsChannel::Cosignature, Some(address)),
+            handler,
+        )
+        .await
+    }
+
+    /// Subscribes to `channel` for `address` and returns its messages as a stream of
+    /// [WsEvent]; `address` is ignored for [WsChannel::Block] and required by every other
+    /// channel.
+    ///
+    /// The events are produced while [SiriusWebsocketClient::listen] runs, and a message that
+    /// cannot be decoded is yielded as an error instead of ending `listen`. Dropping the stream
+    /// removes it like a dropped [SubscriptionHandle].
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///use futures_util::{future, pin_mut, StreamExt};
+    ///use xpx_chain_sdk::{
+    ///    account::Address,
+    ///    websocket::{SiriusWebsocketClient, WsChannel, WsEvent},
+    ///};
+    ///
+    ///#[tokio::main]
+    ///async fn main() {
+    /// let node_url = "http://bctestnet1.brimstone.xpxsirius.io:3000";
+    /// let mut ws = SiriusWebsocketClient::new(node_url).await.unwrap();
+    ///
+    ///    let address = Address::from_raw("VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS").unwrap();
+    ///
+    ///    let blocks = ws.subscribe(WsChannel::Block, None).await.unwrap();
+    ///    let confirmed = ws.subscribe(WsChannel::ConfirmedAdded, Some(&address)).await.unwrap();
+    ///
+    ///    let events = futures_util::stream::select(blocks, confirmed).for_each(|event| async {
+    ///        match event {
+    ///            Ok(WsEvent::Block(block)) => println!("block {}", block.height),
+    ///            Ok(WsEvent::ConfirmedAdded(tx)) => println!("confirmed {}", tx),
+    ///            Ok(_) => {}
+    ///            Err(err) => eprintln!("{}", err),
+    ///        }
+    ///    });
+    ///    pin_mut!(events);
+    ///
+    ///    let listen = ws.listen();
+    ///    pin_mut!(listen);
+    ///
+    ///    future::select(listen, events).await;
+    ///}
+    /// ```
+    pub async fn subscribe(
+        &mut self,
+        channel: WsChannel,
+        address: Option<&Address>,
+    ) -> Result<WsEventStream> {
+        let address = match channel {
+            WsChannel::Block => None,
+            _ => Some(address.ok_or_else(|| {
+                Error::validation(Some("address"), "the channel needs an address")
+            })?),
+        };
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let handle = self
+            .add_handler(
+                WsSubscription::new(channel, address),
+                HandlerStream { sender },
+            )
             .await?;
+
+        Ok(WsEventStream::new(stream::unfold(
+            (receiver, handle),
+            |(mut receiver, handle)| async move {
+                receiver
+                    .recv()
+                    .await
+                    .map(|event| (event, (receiver, handle)))
+            },
+        )))
+    }
+
+    /// Removes every handler of `channel` for `address` and cancels the subscription on the
+    /// node. `address` is ignored for [WsChannel::Block].
+    pub async fn unsubscribe(
+        &mut self,
+        channel: WsChannel,
+        address: Option<&Address>,
+    ) -> Result<()> {
+        self.remove_dropped_handlers().await?;
+
+        let address = match channel {
+            WsChannel::Block => None,
+            _ => address,
+        };
+
+        let subscription = WsSubscription::new(channel, address);
+        if self.handlers.remove(&subscription).is_some() {
+            self.publish_unsubscribe_message(&subscription.path())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// The active subscriptions, i.e. the ones with at least one handler.
+    ///
+    /// Handlers whose [SubscriptionHandle] was dropped are removed by the next call to
+    /// `listen`, `unsubscribe` or one of the `add_*_handlers` methods.
+    pub fn subscriptions(&self) -> Vec<WsSubscription> {
+        self.handlers.keys().cloned().collect()
+    }
+
+    /// Handles the [ConnectionState] events; a handler returning `true` stops
+    /// [SiriusWebsocketClient::listen].
+    pub fn add_state_handlers<F>(&mut self, handler_fn: F)
+    where
+        F: Fn(ConnectionState) -> bool + Send + 'static,
+    {
+        self.state_handlers.push(Box::new(handler_fn));
+    }
+
+    /// Handles the messages of the subscriptions that cannot be decoded, which are also
+    /// passed to their event streams; a handler returning `true` stops
+    /// [SiriusWebsocketClient::listen].
+    pub fn add_error_handlers<F>(&mut self, handler_fn: F)
+    where
+        F: Fn(&Error) -> bool + Send + 'static,
+    {
+        self.error_handlers.push(Box::new(handler_fn));
+    }
+
+    /// Adds `handler` to `subscription`, subscribing to it on its first handler.
+    async fn add_handler<H: Handler>(
+        &mut self,
+        subscription: WsSubscription,
+        handler: H,
+    ) -> Result<SubscriptionHandle> {
+        self.remove_dropped_handlers().await?;
+
+        if !self.handlers.contains_key(&subscription) {
+            self.publish_subscribe_message(&subscription.path()).await?;
+        }
+
+        let id = self.next_handler_id;
+        self.next_handler_id += 1;
+
         self.handlers
-            .insert(PATH_COSIGNATURE.to_string(), Box::new(handler));
+            .entry(subscription.clone())
+            .or_insert_with(Vec::new)
+            .push((id, Box::new(handler)));
+
+        Ok(SubscriptionHandle::new(
+            subscription,
+            id,
+            self.removed_sender.clone(),
+        ))
+    }
+
+    /// Removes the handlers of the dropped handles received so far.
+    async fn remove_dropped_handlers(&mut self) -> Result<()> {
+        while let Some(Some((subscription, id))) = self.removed_receiver.recv().now_or_never() {
+            self.remove_handler(subscription, id).await?;
+        }
         Ok(())
     }
+
+    async fn remove_handler(&mut self, subscription: WsSubscription, id: u64) -> Result<()> {
+        if self.forget_handler(&subscription, id) {
+            self.publish_unsubscribe_message(&subscription.path())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Removes the handler `id` of `subscription`; returns `true` if it was the last one.
+    fn forget_handler(&mut self, subscription: &WsSubscription, id: u64) -> bool {
+        let is_empty = match self.handlers.get_mut(subscription) {
+            Some(handlers) => {
+                handlers.retain(|(handler_id, _)| *handler_id != id);
+                handlers.is_empty()
+            }
+            None => false,
+        };
+
+        if is_empty {
+            self.handlers.remove(subscription);
+        }
+        is_empty
+    }
 }
 
 impl SiriusWebsocketClient {
     pub async fn new(url: &str) -> Result<SiriusWebsocketClient> {
-        let scheme_str = convert_to_ws_url(url)?;
+        let (conn, uid) = connect(url).await?;
 
-        let (mut conn, _) = connect_async(scheme_str).await?;
+        Ok(SiriusWebsocketClient::with_connection(url, conn, uid))
+    }
 
-        let msg = conn.next().await.expect("Can't fetch case count")?;
+    /// A client reading the messages of `replay` instead of a node, see [WsReplay].
+    ///
+    /// The subscriptions are only kept by the client, and
+    /// [SiriusWebsocketClient::listen] returns once every message was replayed.
+    pub fn replay(replay: WsReplay) -> SiriusWebsocketClient {
+        let uid = WsConnectionResponse {
+            uid: REPLAY_UID.to_string(),
+        };
 
-        let rwa_uid = msg.into_text()?;
+        SiriusWebsocketClient::with_connection("", Connection::Replay(replay), uid)
+    }
 
-        let uid: WsConnectionResponse = serde_json::from_str(&rwa_uid)?;
+    fn with_connection(url: &str, conn: Connection, uid: WsConnectionResponse) -> Self {
+        let (removed_sender, removed_receiver) = mpsc::unbounded_channel();
+        let (command_sender, command_receiver) = mpsc::unbounded_channel();
 
-        Ok(SiriusWebsocketClient {
+        SiriusWebsocketClient {
+            url: url.to_string(),
             uid,
             conn,
             handlers: HashMap::new(),
-        })
+            next_handler_id: 0,
+            removed_sender,
+            removed_receiver,
+            command_sender,
+            command_receiver,
+            reconnect: None,
+            keepalive: None,
+            recorder: None,
+            backfill: None,
+            state_handlers: vec![],
+            error_handlers: vec![],
+            synced_height: 0,
+            backfilled_height: 0,
+            backfilled: HashSet::new(),
+        }
+    }
+
+    /// Reconnects with `policy` when the connection is lost instead of ending
+    /// [SiriusWebsocketClient::listen].
+    pub fn with_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
+    /// Pings the node and treats the connection as lost once nothing was received for
+    /// the idle timeout of `keepalive`, notifying [ConnectionState::Stale].
+    ///
+    /// Without a [ReconnectPolicy], [SiriusWebsocketClient::listen] then returns
+    /// `Error::Stale`.
+    pub fn with_keepalive(mut self, keepalive: KeepAlive) -> Self {
+        self.keepalive = Some(keepalive);
+        self
+    }
+
+    /// Writes every text message received from the node to `recorder`, to be replayed
+    /// later with [SiriusWebsocketClient::replay].
+    ///
+    /// [SiriusWebsocketClient::listen] returns the error of a failed write.
+    pub fn with_recorder(mut self, recorder: WsRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// After a reconnection, reads the blocks and confirmed transactions missed during the
+    /// outage from the REST routes of `client` and passes them to the `block` and
+    /// `confirmedAdded` handlers.
+    ///
+    /// A missed transaction is passed to the `confirmedAdded` handlers of an address when the
+    /// address signed or cosigned it, or received a transfer from it, including through the
+    /// inner transactions of an aggregate and namespace aliases.
+    pub fn with_backfill(mut self, client: &SiriusClient) -> Self {
+        self.backfill = Some(client.to_owned());
+        self
+    }
+
+    /// A handle subscribing and unsubscribing from other tasks while
+    /// [SiriusWebsocketClient::listen] runs, e.g. in a task of its own, until it returns.
+    pub fn handle(&self) -> WsHandle {
+        WsHandle::new(self.command_sender.clone())
     }
 
     pub fn uid(&self) -> String {
@@ -233,7 +593,7 @@ impl SiriusWebsocketClient {
     }
 
     pub async fn close(&mut self) -> Result<()> {
-        Ok(self.conn.close(None).await?)
+        Ok(self.conn.close().await?)
     }
 
     async fn publish_subscribe_message(&mut self, path: &RouterPath) -> Result<()> {
@@ -247,75 +607,639 @@ impl SiriusWebsocketClient {
         Ok(self.conn.send(Message::text(msg)).await?)
     }
 
+    async fn publish_unsubscribe_message(&mut self, path: &RouterPath) -> Result<()> {
+        let dto = UnsubscribeDto {
+            uid: self.uid.uid.to_owned(),
+            unsubscribe: path.to_string(),
+        };
+
+        let msg = serde_json::to_string(&dto)?;
+
+        Ok(self.conn.send(Message::text(msg)).await?)
+    }
+
+    /// Passes the messages of the node to the handlers until one of them, a [WsHandle] or
+    /// the connection stops it.
+    ///
+    /// The handles taken before fail once it returns; listening again needs new handles
+    /// from [SiriusWebsocketClient::handle].
     pub async fn listen(&mut self) -> Result<()> {
-        while let Some(msg) = self.conn.next().await {
-            let msg =
-                msg.map_err(|e| failure::err_msg(format!("Error on server stream: {:?}", e)))?;
-
-            if msg.is_text() {
-                let msg_string = msg.to_string();
-                let channel_name = get_channel_name(&msg_string)?;
-
-                if let Some(base) = self.handlers.get(&channel_name) {
-                    if let Some(handler_info) = base.downcast_ref::<HandlerBlock>() {
-                        let channel = get_channel_data::<WsBlockInfoDto>(&msg_string, false)?;
-                        if (handler_info.handler)(channel.compact()) {
-                            break;
-                        }
-                    } else if let Some(handler_info) = base.downcast_ref::<HandlerStatus>() {
-                        let channel = get_channel_data::<WsStatusInfoDto>(&msg_string, false)?;
-                        if (handler_info.handler)(channel.compact()) {
-                            break;
-                        }
-                    } else if let Some(handler_info) = base.downcast_ref::<HandlerConfirmedAdd>() {
-                        let channel =
-                            get_channel_data::<Box<dyn TransactionDto>>(&msg_string, true)?;
-                        if (handler_info.handler)(channel.compact()?) {
-                            break;
-                        }
-                    } else if let Some(handler_info) = base.downcast_ref::<HandlerUnconfirmedAdd>()
-                    {
-                        let channel =
-                            get_channel_data::<Box<dyn TransactionDto>>(&msg_string, true)?;
-                        if (handler_info.handler)(channel.compact()?) {
-                            break;
-                        }
-                    } else if let Some(handler_info) =
-                        base.downcast_ref::<HandlerUnconfirmedRemoved>()
-                    {
-                        let channel =
-                            get_channel_data::<WsUnconfirmedRemovedDto>(&msg_string, false)?;
-                        if (handler_info.handler)(channel.compact()) {
-                            break;
-                        }
-                    } else if let Some(handler_info) = base.downcast_ref::<HandlerPartialAdd>() {
-                        let channel =
-                            get_channel_data::<Box<dyn TransactionDto>>(&msg_string, true)?;
-                        let tx = channel.compact()?;
-                        let aggregate = tx
-                            .downcast::<crate::transaction::AggregateTransaction>()
-                            .map_err(|_| {
-                            failure::err_msg(crate::errors_const::ERR_INVALID_AGGREGATE_TRANSACTION)
-                        })?;
-                        if (handler_info.handler)(*aggregate) {
-                            break;
+        let result = self.listen_until_stopped().await;
+
+        // fail the requests of the handles instead of leaving them waiting.
+        self.command_receiver.close();
+        while let Some(Some(_)) = self.command_receiver.recv().now_or_never() {}
+
+        let (command_sender, command_receiver) = mpsc::unbounded_channel();
+        self.command_sender = command_sender;
+        self.command_receiver = command_receiver;
+
+        result
+    }
+
+    async fn listen_until_stopped(&mut self) -> Result<()> {
+        self.remove_dropped_handlers().await?;
+
+        if let Some(client) = self.backfill.clone() {
+            if self.synced_height == 0 {
+                if let Ok(height_info) = client.chain_api().get_blockchain_height().await {
+                    self.synced_height = height_info.height.as_u64();
+                }
+            }
+        }
+
+        loop {
+            match self.listen_connection().await? {
+                ListenEnd::Stopped => break,
+                ListenEnd::Disconnected(error) => {
+                    let policy = match self.reconnect {
+                        Some(policy) => policy,
+                        None => match error {
+                            Some(err) => return Err(err),
+                            None => break,
+                        },
+                    };
+
+                    let error = error.map(|err| err.to_string());
+                    if self.notify(ConnectionState::Disconnected { error }) {
+                        break;
+                    }
+
+                    if !self.reconnect(policy).await? || self.backfill().await {
+                        break;
+                    }
+                }
+            }
+        }
+        self.close().await
+    }
+
+    async fn listen_connection(&mut self) -> Result<ListenEnd> {
+        let mut last_received = Instant::now();
+        let mut last_ping = last_received;
+
+        loop {
+            let deadline = match self.keepalive {
+                Some(keepalive) => ::std::cmp::min(
+                    last_received + keepalive.idle_timeout,
+                    last_ping + keepalive.ping_interval,
+                ),
+                None => last_received,
+            };
+
+            let next = tokio::select! {
+                msg = self.conn.next() => Next::Message(msg),
+                Some(removed) = self.removed_receiver.recv() => Next::Removed(removed),
+                Some(command) = self.command_receiver.recv() => Next::Command(command),
+                _ = delay_until(deadline), if self.keepalive.is_some() => Next::KeepAlive,
+            };
+
+            let msg = match next {
+                Next::Message(Some(Ok(msg))) => {
+                    last_received = Instant::now();
+                    msg
+                }
+                Next::Message(Some(Err(err))) => {
+                    return Ok(ListenEnd::Disconnected(Some(err.into())))
+                }
+                Next::Message(None) if self.conn.is_replay() => return Ok(ListenEnd::Stopped),
+                Next::Message(None) => return Ok(ListenEnd::Disconnected(None)),
+                Next::Removed((subscription, id)) => {
+                    if let Err(err) = self.remove_handler(subscription, id).await {
+                        return Ok(ListenEnd::Disconnected(Some(err)));
+                    }
+                    continue;
+                }
+                Next::Command(command) => {
+                    if self.on_command(command).await {
+                        return Ok(ListenEnd::Stopped);
+                    }
+                    continue;
+                }
+                Next::KeepAlive => {
+                    let keepalive = match self.keepalive {
+                        Some(keepalive) => keepalive,
+                        None => continue,
+                    };
+
+                    let now = Instant::now();
+                    let idle = now - last_received;
+                    if idle >= keepalive.idle_timeout {
+                        if self.notify(ConnectionState::Stale { idle }) {
+                            return Ok(ListenEnd::Stopped);
                         }
-                    } else if let Some(handler_info) = base.downcast_ref::<HandlerPartialRemove>() {
-                        let channel = get_channel_data::<WsPartialRemoveDto>(&msg_string, false)?;
-                        if (handler_info.handler)(channel.compact()) {
-                            break;
+                        return Ok(ListenEnd::Disconnected(Some(Error::Stale(idle))));
+                    }
+
+                    if now - last_ping >= keepalive.ping_interval {
+                        last_ping = now;
+                        if let Err(err) = self.conn.send(Message::Ping(vec![])).await {
+                            return Ok(ListenEnd::Disconnected(Some(err.into())));
                         }
-                    } else if let Some(handler_info) = base.downcast_ref::<HandlerCosignature>() {
-                        let channel = get_channel_data::<CosignatureInfo>(&msg_string, false)?;
-                        if (handler_info.handler)(channel) {
-                            break;
+                    }
+                    continue;
+                }
+            };
+
+            if !msg.is_text() {
+                continue;
+            }
+
+            let text = msg.to_string();
+            if let Some(recorder) = &mut self.recorder {
+                recorder.record(&text)?;
+            }
+
+            if self.on_message(&text) {
+                return Ok(ListenEnd::Stopped);
+            }
+        }
+    }
+
+    /// Serves a request of a [WsHandle]; returns `true` if it closes the client.
+    async fn on_command(&mut self, command: Command) -> bool {
+        match command {
+            Command::Subscribe {
+                channel,
+                address,
+                reply,
+            } => {
+                let _ = reply.send(self.subscribe(channel, address.as_ref()).await);
+            }
+            Command::Unsubscribe {
+                channel,
+                address,
+                reply,
+            } => {
+                let _ = reply.send(self.unsubscribe(channel, address.as_ref()).await);
+            }
+            Command::Subscriptions { reply } => {
+                let _ = reply.send(self.subscriptions());
+            }
+            Command::Close { reply } => {
+                let _ = reply.send(Ok(()));
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Passes a message to the handlers of its subscriptions; returns `true` if one of them
+    /// stops listening.
+    ///
+    /// A message that cannot be decoded is passed as an error to the error handlers and to
+    /// the event streams it was meant for, or to every stream when its channel is unknown.
+    fn on_message(&mut self, msg: &str) -> bool {
+        let (channel_name, address) = match get_channel_meta(msg) {
+            Ok(meta) => meta,
+            Err(err) => {
+                self.handlers
+                    .values()
+                    .for_each(|handlers| deliver_error(&err, handlers));
+                return self.notify_error(&err);
+            }
+        };
+
+        let channel = match WsChannel::from_name(&channel_name) {
+            Some(channel) => channel,
+            None => return false,
+        };
+
+        let subscriptions: Vec<WsSubscription> = match address {
+            Some(address) => vec![WsSubscription::from_meta(channel, &address)],
+            // messages without an address go to every subscription of the channel.
+            None => self
+                .handlers
+                .keys()
+                .filter(|subscription| subscription.channel == channel)
+                .cloned()
+                .collect(),
+        };
+
+        if !subscriptions
+            .iter()
+            .any(|subscription| self.handlers.contains_key(subscription))
+        {
+            return false;
+        }
+
+        let message = match decode(channel, msg) {
+            Ok(message) => message,
+            Err(err) => {
+                subscriptions
+                    .iter()
+                    .filter_map(|subscription| self.handlers.get(subscription))
+                    .for_each(|handlers| deliver_error(&err, handlers));
+                return self.notify_error(&err);
+            }
+        };
+
+        let mut stop = false;
+        for subscription in subscriptions.iter() {
+            if !self.track(subscription, &message) {
+                continue;
+            }
+            if let Some(handlers) = self.handlers.get(subscription) {
+                if deliver(&message, handlers) {
+                    stop = true;
+                    break;
+                }
+            }
+        }
+        stop
+    }
+
+    /// Records the height reached by `message`; returns `false` if the last backfill already
+    /// passed it to the handlers of `subscription`.
+    fn track(&mut self, subscription: &WsSubscription, message: &WsEvent) -> bool {
+        match message {
+            WsEvent::Block(block_info) => {
+                let height = block_info.height.as_u64();
+                self.synced_height = self.synced_height.max(height);
+                height > self.backfilled_height
+            }
+            WsEvent::ConfirmedAdded(tx) => {
+                self.synced_height = self.synced_height.max(tx.height().as_u64());
+
+                let address = subscription.address.clone().unwrap_or_default();
+                let hash = tx.transaction_hash().to_uppercase();
+                !self.backfilled.remove(&(address, hash))
+            }
+            _ => true,
+        }
+    }
+
+    fn notify(&self, state: ConnectionState) -> bool {
+        self.state_handlers
+            .iter()
+            .any(|handler| handler(state.clone()))
+    }
+
+    fn notify_error(&self, err: &Error) -> bool {
+        self.error_handlers.iter().any(|handler| handler(err))
+    }
+
+    /// Opens a new connection with the backoff of `policy` and replays every subscription;
+    /// returns `false` if a state handler stops listening.
+    async fn reconnect(&mut self, policy: ReconnectPolicy) -> Result<bool> {
+        let mut last_error = None;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            if let Some(max_attempts) = policy.max_attempts {
+                if attempt > max_attempts {
+                    return Err(last_error.unwrap_or(Error::Websocket(WsError::ConnectionClosed)));
+                }
+            }
+
+            let delay = policy.delay(attempt);
+            if self.notify(ConnectionState::Reconnecting { attempt, delay }) {
+                return Ok(false);
+            }
+
+            delay_for(delay).await;
+
+            match connect(&self.url).await {
+                Ok((conn, uid)) => {
+                    self.conn = conn;
+                    self.uid = uid;
+
+                    match self.resubscribe().await {
+                        Ok(()) => {
+                            let uid = self.uid();
+                            return Ok(!self.notify(ConnectionState::Reconnected { uid }));
                         }
+                        Err(err) => last_error = Some(err),
                     }
-                };
+                }
+                Err(err) => last_error = Some(err),
             }
         }
-        self.close().await
     }
+
+    async fn resubscribe(&mut self) -> Result<()> {
+        // the new connection has no subscription, dropped handlers are only forgotten.
+        while let Some(Some((subscription, id))) = self.removed_receiver.recv().now_or_never() {
+            self.forget_handler(&subscription, id);
+        }
+
+        let paths: Vec<String> = self.handlers.keys().map(|s| s.path()).collect();
+        for path in paths.iter() {
+            self.publish_subscribe_message(path).await?;
+        }
+        Ok(())
+    }
+
+    /// Runs the backfill, if any; returns `true` if a handler stops listening.
+    async fn backfill(&mut self) -> bool {
+        let client = match self.backfill.clone() {
+            Some(client) => client,
+            None => return false,
+        };
+
+        match self.backfill_gap(&client).await {
+            Ok(stop) => stop,
+            Err(err) => self.notify(ConnectionState::BackfillFailed {
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    async fn backfill_gap(&mut self, client: &SiriusClient) -> Result<bool> {
+        let to_height = client
+            .chain_api()
+            .get_blockchain_height()
+            .await?
+            .height
+            .as_u64();
+
+        let from_height = self.synced_height + 1;
+        if self.synced_height == 0 || to_height < from_height {
+            self.synced_height = self.synced_height.max(to_height);
+            return Ok(false);
+        }
+
+        self.backfilled.clear();
+
+        let blocks = WsSubscription::new(WsChannel::Block, None);
+        let accounts: Vec<WsSubscription> = self
+            .handlers
+            .keys()
+            .filter(|subscription| subscription.channel == WsChannel::ConfirmedAdded)
+            .cloned()
+            .collect();
+
+        for height in from_height..=to_height {
+            if self.handlers.contains_key(&blocks) {
+                let block_info = client.block_api().get_block_by_height(height).await?;
+                self.backfilled_height = height;
+
+                let message = WsEvent::Block(block_info);
+                if let Some(handlers) = self.handlers.get(&blocks) {
+                    if deliver(&message, handlers) {
+                        return Ok(true);
+                    }
+                }
+            }
+
+            if !accounts.is_empty()
+                && self
+                    .backfill_transactions(client, height, &accounts)
+                    .await?
+            {
+                return Ok(true);
+            }
+
+            self.synced_height = height;
+        }
+
+        Ok(self.notify(ConnectionState::Backfilled {
+            from_height,
+            to_height,
+        }))
+    }
+
+    /// Passes the transactions of the block at `height` sent to the address of one of
+    /// `accounts` to its handlers; returns `true` if one of them stops listening.
+    async fn backfill_transactions(
+        &mut self,
+        client: &SiriusClient,
+        height: u64,
+        accounts: &[WsSubscription],
+    ) -> Result<bool> {
+        let mut transactions = client
+            .block_api()
+            .get_block_transactions(height, Some(BACKFILL_PAGE_SIZE), None)
+            .await?;
+
+        if transactions.is_empty() {
+            return Ok(false);
+        }
+
+        // the recipients given as namespace aliases are resolved by the block receipts.
+        let statements = client.block_api().get_block_receipts(height).await?;
+
+        loop {
+            for tx in transactions.iter() {
+                let hash = tx.transaction_hash().to_uppercase();
+                let addresses = transaction_addresses(tx.as_ref(), &statements);
+
+                let mut stop = false;
+                for subscription in accounts.iter() {
+                    let address = match &subscription.address {
+                        Some(address) if addresses.contains(address) => address,
+                        _ => continue,
+                    };
+
+                    self.backfilled
+                        .insert((address.to_owned(), hash.to_owned()));
+
+                    let message = WsEvent::ConfirmedAdded(tx.clone());
+                    if let Some(handlers) = self.handlers.get(subscription) {
+                        stop = stop || deliver(&message, handlers);
+                    }
+                }
+
+                if stop {
+                    return Ok(true);
+                }
+            }
+
+            let last_id = transactions
+                .last()
+                .and_then(|tx| tx.abs_transaction().transaction_info)
+                .map(|info| info.id);
+
+            let id = match last_id {
+                Some(id) if transactions.len() >= BACKFILL_PAGE_SIZE as usize => id,
+                _ => return Ok(false),
+            };
+
+            transactions = client
+                .block_api()
+                .get_block_transactions(height, Some(BACKFILL_PAGE_SIZE), Some(&id))
+                .await?;
+        }
+    }
+}
+
+/// The addresses a confirmed transaction is sent to by the node: its signer, cosigners and
+/// recipient, and those of its inner transactions.
+fn transaction_addresses(tx: &dyn Transaction, statements: &Statements) -> HashSet<String> {
+    let primary_id = tx
+        .abs_transaction()
+        .transaction_info
+        .map_or(0, |info| info.index + 1);
+
+    let mut addresses = HashSet::new();
+    addresses.insert(tx.abs_transaction().signer.address.address_string());
+
+    let aggregate = match tx.as_any().downcast_ref::<AggregateTransaction>() {
+        Some(aggregate) => aggregate,
+        None => {
+            let source = ReceiptSource {
+                primary_id,
+                secondary_id: 0,
+            };
+            add_recipient(tx, source, statements, &mut addresses);
+            return addresses;
+        }
+    };
+
+    for cosignature in aggregate.cosignatures.iter() {
+        addresses.insert(cosignature.signer.address.address_string());
+    }
+
+    for (index, inner) in aggregate.inner_transactions.iter().enumerate() {
+        addresses.insert(inner.abs_transaction().signer.address.address_string());
+
+        let source = ReceiptSource {
+            primary_id,
+            secondary_id: index as u32 + 1,
+        };
+        add_recipient(inner.as_ref(), source, statements, &mut addresses);
+    }
+
+    addresses
+}
+
+fn add_recipient(
+    tx: &dyn Transaction,
+    source: ReceiptSource,
+    statements: &Statements,
+    addresses: &mut HashSet<String>,
+) {
+    if let Some(transfer) = tx.as_any().downcast_ref::<TransferTransaction>() {
+        let recipient = statements.resolve_address(&transfer.recipient, source);
+        addresses.insert(recipient.address_string());
+    }
+}
+
+/// Calls the handlers of type `H` until one of them returns `true`.
+fn call_handlers<H, F>(handlers: &[(u64, Box<dyn Handler>)], call: F) -> bool
+where
+    H: Handler,
+    F: Fn(&H) -> bool,
+{
+    handlers
+        .iter()
+        .filter_map(|(_, base)| base.downcast_ref::<H>())
+        .any(|handler_info| call(handler_info))
+}
+
+/// Decodes `msg` of `channel`.
+fn decode(channel: WsChannel, msg: &str) -> Result<WsEvent> {
+    let message = match channel {
+        WsChannel::Block => {
+            WsEvent::Block(get_channel_data::<WsBlockInfoDto>(msg, false)?.compact()?)
+        }
+        WsChannel::Status => {
+            WsEvent::Status(get_channel_data::<WsStatusInfoDto>(msg, false)?.compact())
+        }
+        WsChannel::ConfirmedAdded => WsEvent::ConfirmedAdded(
+            get_channel_data::<Box<dyn TransactionDto>>(msg, true)?.compact()?,
+        ),
+        WsChannel::UnconfirmedAdded => WsEvent::UnconfirmedAdded(
+            get_channel_data::<Box<dyn TransactionDto>>(msg, true)?.compact()?,
+        ),
+        WsChannel::UnconfirmedRemoved => WsEvent::UnconfirmedRemoved(
+            get_channel_data::<WsUnconfirmedRemovedDto>(msg, false)?.compact(),
+        ),
+        WsChannel::PartialAdded => {
+            let tx = get_channel_data::<Box<dyn TransactionDto>>(msg, true)?.compact()?;
+            let aggregate = tx.downcast::<AggregateTransaction>().map_err(|_| {
+                Error::decode(crate::errors_const::ERR_INVALID_AGGREGATE_TRANSACTION)
+            })?;
+            WsEvent::PartialAdded(*aggregate)
+        }
+        WsChannel::PartialRemoved => {
+            WsEvent::PartialRemoved(get_channel_data::<WsPartialRemoveDto>(msg, false)?.compact())
+        }
+        WsChannel::Cosignature => {
+            WsEvent::Cosignature(get_channel_data::<CosignatureInfo>(msg, false)?)
+        }
+    };
+
+    Ok(message)
+}
+
+/// Passes `message` to the event streams of a subscription, then to each of its handlers
+/// until one of them returns `true`.
+fn deliver(message: &WsEvent, handlers: &[(u64, Box<dyn Handler>)]) -> bool {
+    handlers
+        .iter()
+        .filter_map(|(_, base)| base.downcast_ref::<HandlerStream>())
+        .for_each(|handler_info| {
+            // a closed stream is removed with its subscription handle.
+            let _ = handler_info.sender.send(Ok(message.clone()));
+        });
+
+    match message {
+        WsEvent::Block(block_info) => call_handlers(handlers, |handler_info: &HandlerBlock| {
+            (handler_info.handler)(block_info.clone())
+        }),
+        WsEvent::Status(status) => call_handlers(handlers, |handler_info: &HandlerStatus| {
+            (handler_info.handler)(status.clone())
+        }),
+        WsEvent::ConfirmedAdded(tx) => {
+            call_handlers(handlers, |handler_info: &HandlerConfirmedAdd| {
+                (handler_info.handler)(tx.clone())
+            })
+        }
+        WsEvent::UnconfirmedAdded(tx) => {
+            call_handlers(handlers, |handler_info: &HandlerUnconfirmedAdd| {
+                (handler_info.handler)(tx.clone())
+            })
+        }
+        WsEvent::UnconfirmedRemoved(info) => {
+            call_handlers(handlers, |handler_info: &HandlerUnconfirmedRemoved| {
+                (handler_info.handler)(info.clone())
+            })
+        }
+        WsEvent::PartialAdded(aggregate) => {
+            call_handlers(handlers, |handler_info: &HandlerPartialAdd| {
+                (handler_info.handler)(aggregate.clone())
+            })
+        }
+        WsEvent::PartialRemoved(info) => {
+            call_handlers(handlers, |handler_info: &HandlerPartialRemove| {
+                (handler_info.handler)(info.clone())
+            })
+        }
+        WsEvent::Cosignature(cosignature) => {
+            call_handlers(handlers, |handler_info: &HandlerCosignature| {
+                (handler_info.handler)(cosignature.clone())
+            })
+        }
+    }
+}
+
+/// Passes `err` to each event stream of a subscription.
+fn deliver_error(err: &Error, handlers: &[(u64, Box<dyn Handler>)]) {
+    handlers
+        .iter()
+        .filter_map(|(_, base)| base.downcast_ref::<HandlerStream>())
+        .for_each(|handler_info| {
+            let _ = handler_info.sender.send(Err(err.duplicate()));
+        });
+}
+
+async fn connect(url: &str) -> Result<(Connection, WsConnectionResponse)> {
+    let scheme_str = convert_to_ws_url(url)?;
+
+    let (mut conn, _) = connect_async(scheme_str).await?;
+
+    let msg = match conn.next().await {
+        Some(msg) => msg?,
+        None => return Err(Error::decode(crate::errors_const::ERR_WS_CLOSED)),
+    };
+
+    let rwa_uid = msg.into_text()?;
+
+    let uid: WsConnectionResponse = serde_json::from_str(&rwa_uid)?;
+
+    Ok((Connection::Node(conn), uid))
 }
 
 fn convert_to_ws_url(url: &str) -> Result<Url> {
@@ -333,12 +1257,6 @@ fn convert_to_ws_url(url: &str) -> Result<Url> {
     Url::parse(&url).map_err(|e| Error::Url(Cow::from(e.to_string())))
 }
 
-fn path_parse_address(mut path: String, address: &Address) -> String {
-    path.push_str("/");
-    path.push_str(&address.address);
-    path
-}
-
 fn get_channel_data<U>(msg: &str, is_tx: bool) -> Result<U>
 where
     for<'de> U: serde::Deserialize<'de>,
@@ -351,8 +1269,526 @@ where
     }
 }
 
-fn get_channel_name(msg: &str) -> Result<String> {
+/// The channel name and, for account channels, the address of a message.
+fn get_channel_meta(msg: &str) -> Result<(String, Option<String>)> {
     let value_dto: Value = serde_json::from_str(msg)?;
-    let res = value_dto["meta"]["channelName"].as_str().unwrap();
-    Ok(res.to_string())
+
+    let channel_name = match value_dto["meta"]["channelName"].as_str() {
+        Some(channel_name) => channel_name.to_string(),
+        None => return Err(Error::decode(crate::errors_const::ERR_INVALID_WS_MESSAGE)),
+    };
+
+    let address = value_dto["meta"]["address"]
+        .as_str()
+        .map(|address| address.to_string());
+
+    Ok((channel_name, address))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        ::std::sync::{Arc, Mutex},
+        futures_util::StreamExt,
+        serde_json::{json, Value},
+        tokio_tungstenite::tungstenite::Error as WsError,
+    };
+
+    use crate::{account::Address, models::error::Error, websocket::WsChannel};
+
+    use super::{
+        HandlerPartialRemove, SiriusWebsocketClient, WsEvent, WsRecord, WsReplay, WsSubscription,
+    };
+
+    const ALICE: &str = "VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS";
+    const BOB: &str = "VAWOEOWTABXR7O3ZAK2XNA5GIBNE6PZIXDAFDWBU";
+
+    const TRANSFER: &str = r#"{"meta":{"height":[10,0],"index":0,"id":"5E6A9E2B1C0D4E3F2A1B0C9D"},"transaction":{"signature":"AB3C6F0E0A7B3C3D1E4F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F8A9B0C1D2E3F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3B4C5D6E7F","signer":"C5F7A0C1E3B1D2F4A6B8C0D2E4F6A8B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","version":2818572291,"type":16724,"maxFee":[0,0],"deadline":[123456,0],"recipient":"A8F5B1E3C8D2A4F6B0C2D4E6F8A0B2C4D6E8F0A2B4C6D8E0F2","message":{"type":0,"payload":"48656C6C6F"},"mosaics":[{"id":[519256100,642862634],"amount":[10000000,0]}]}}"#;
+
+    type Received = Arc<Mutex<Vec<String>>>;
+
+    fn hash(n: u8) -> String {
+        format!("{:02X}", n).repeat(32)
+    }
+
+    /// A `confirmedAdded` message of the transfer `hash`, sent to `address`.
+    fn confirmed(address: &str, hash: &str) -> WsRecord {
+        let mut message: Value = serde_json::from_str(TRANSFER).unwrap();
+        message["meta"]["channelName"] = json!("confirmedAdded");
+        message["meta"]["address"] = json!(address);
+        message["meta"]["hash"] = json!(hash);
+        message["meta"]["merkleComponentHash"] = json!(hash);
+
+        WsRecord::new(&message.to_string())
+    }
+
+    fn partial_removed(address: &str, hash: &str) -> WsRecord {
+        let message = json!({
+            "meta": { "channelName": "partialRemoved", "address": address, "hash": hash }
+        });
+
+        WsRecord::new(&message.to_string())
+    }
+
+    async fn add_confirmed(ws: &mut SiriusWebsocketClient, address: &str, received: &Received) {
+        let received = received.clone();
+        ws.add_confirmed_added_handlers(&Address::from_raw(address).unwrap(), move |tx| {
+            received.lock().unwrap().push(tx.transaction_hash());
+            false
+        })
+        .await
+        .unwrap()
+        .detach();
+    }
+
+    #[tokio::test]
+    async fn messages_go_to_the_subscription_of_their_address() {
+        let replay = WsReplay::new(vec![
+            confirmed(ALICE, &hash(1)),
+            confirmed(BOB, &hash(2)),
+            // addresses are matched whatever their case or dashes.
+            confirmed("vc4a3z-6alfgj-pyagdk-2cne2j-axomqk-ilybvn-lqfs", &hash(3)),
+            confirmed("VDPQS6-FBYDN3-O42TE7-PJUOQW-VYJGQM-CVUXUB-R3T4", &hash(4)),
+        ]);
+        let mut ws = SiriusWebsocketClient::replay(replay);
+
+        let alice = Received::default();
+        let bob = Received::default();
+        add_confirmed(&mut ws, ALICE, &alice).await;
+        add_confirmed(&mut ws, BOB, &bob).await;
+
+        assert_eq!(ws.subscriptions().len(), 2);
+
+        ws.listen().await.unwrap();
+
+        assert_eq!(*alice.lock().unwrap(), vec![hash(1), hash(3)]);
+        assert_eq!(*bob.lock().unwrap(), vec![hash(2)]);
+    }
+
+    #[tokio::test]
+    async fn every_handler_of_a_subscription_is_called() {
+        let replay = WsReplay::new(vec![confirmed(ALICE, &hash(1)), confirmed(ALICE, &hash(2))]);
+        let mut ws = SiriusWebsocketClient::replay(replay);
+
+        let first = Received::default();
+        let second = Received::default();
+        add_confirmed(&mut ws, ALICE, &first).await;
+        add_confirmed(&mut ws, ALICE, &second).await;
+
+        let alice = Address::from_raw(ALICE).unwrap();
+        let events = ws
+            .subscribe(WsChannel::ConfirmedAdded, Some(&alice))
+            .await
+            .unwrap();
+
+        let subscription = WsSubscription::new(WsChannel::ConfirmedAdded, Some(&alice));
+        assert_eq!(ws.subscriptions(), vec![subscription.clone()]);
+        assert_eq!(ws.handlers[&subscription].len(), 3);
+
+        ws.listen().await.unwrap();
+        drop(ws);
+
+        assert_eq!(*first.lock().unwrap(), vec![hash(1), hash(2)]);
+        assert_eq!(*second.lock().unwrap(), vec![hash(1), hash(2)]);
+
+        let streamed: Vec<String> = events
+            .map(|event| match event.unwrap() {
+                WsEvent::ConfirmedAdded(tx) => tx.transaction_hash(),
+                event => panic!("unexpected event {:?}", event),
+            })
+            .collect()
+            .await;
+        assert_eq!(streamed, vec![hash(1), hash(2)]);
+    }
+
+    #[tokio::test]
+    async fn partial_removed_handlers_receive_the_removed_hash() {
+        let replay = WsReplay::new(vec![
+            partial_removed(BOB, &hash(1)),
+            partial_removed(ALICE, &hash(2)),
+        ]);
+        let mut ws = SiriusWebsocketClient::replay(replay);
+
+        let alice = Address::from_raw(ALICE).unwrap();
+        let received = Received::default();
+        let handler_received = received.clone();
+        ws.add_partial_removed_handlers(&alice, move |info| {
+            handler_received
+                .lock()
+                .unwrap()
+                .push(info.transaction_hash());
+            false
+        })
+        .await
+        .unwrap()
+        .detach();
+
+        let subscription = WsSubscription::new(WsChannel::PartialRemoved, Some(&alice));
+        assert!(ws.handlers[&subscription]
+            .iter()
+            .all(|(_, handler)| handler.is::<HandlerPartialRemove>()));
+
+        ws.listen().await.unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![hash(2)]);
+    }
+
+    #[tokio::test]
+    async fn undecodable_messages_keep_their_error_kind() {
+        let broken = WsRecord::new(r#"{"meta":{"channelName":"block"},"block":{}}"#);
+        let mut ws = SiriusWebsocketClient::replay(WsReplay::new(vec![broken]));
+
+        ws.add_block_handlers(|_| false).await.unwrap().detach();
+        let events = ws.subscribe(WsChannel::Block, None).await.unwrap();
+
+        let errors = Received::default();
+        let handler_errors = errors.clone();
+        ws.add_error_handlers(move |err| {
+            if let Error::Decode(msg) = err {
+                handler_errors.lock().unwrap().push(msg.to_string());
+            }
+            false
+        });
+
+        ws.listen().await.unwrap();
+        drop(ws);
+
+        assert_eq!(errors.lock().unwrap().len(), 1);
+
+        let streamed: Vec<_> = events.collect().await;
+        assert_eq!(streamed.len(), 1);
+        assert!(matches!(streamed[0], Err(Error::Decode(_))));
+    }
+
+    #[tokio::test]
+    async fn handles_fail_once_listen_returns() {
+        let mut ws = SiriusWebsocketClient::replay(WsReplay::new(vec![]));
+
+        let handle = ws.handle();
+        ws.listen().await.unwrap();
+
+        match handle.subscriptions().await {
+            Err(Error::Websocket(WsError::AlreadyClosed)) => {}
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock-node"))]
+mod node_tests {
+    use {
+        ::std::{
+            sync::{Arc, Mutex},
+            thread,
+            time::Duration,
+        },
+        tokio::{task::block_in_place, time::delay_for},
+    };
+
+    use crate::{
+        account::Account,
+        api::SiriusClient,
+        message::PlainMessage,
+        mock::MockNode,
+        models::error::Error,
+        mosaic::Mosaic,
+        transaction::{Deadline, TransferTransaction},
+    };
+
+    use super::{ConnectionState, KeepAlive, ReconnectPolicy, SiriusWebsocketClient};
+
+    type States = Arc<Mutex<Vec<ConnectionState>>>;
+
+    type Received<T> = Arc<Mutex<Vec<T>>>;
+
+    /// Waits up to two seconds for `condition`.
+    async fn wait_until<F: Fn() -> bool>(condition: F) {
+        for _ in 0..200 {
+            if condition() {
+                return;
+            }
+            delay_for(Duration::from_millis(10)).await;
+        }
+        panic!("condition not met in time");
+    }
+
+    fn record_states(ws: &mut SiriusWebsocketClient) -> States {
+        let states = States::default();
+        let handler_states = states.clone();
+        ws.add_state_handlers(move |state| {
+            handler_states.lock().unwrap().push(state);
+            false
+        });
+        states
+    }
+
+    async fn record_heights(ws: &mut SiriusWebsocketClient) -> Received<u64> {
+        let heights = Received::default();
+        let handler_heights = heights.clone();
+        ws.add_block_handlers(move |block| {
+            handler_heights.lock().unwrap().push(block.height.as_u64());
+            false
+        })
+        .await
+        .unwrap()
+        .detach();
+        heights
+    }
+
+    #[tokio::test]
+    async fn reconnect_replays_the_subscriptions() {
+        let node = MockNode::start().await.unwrap();
+        let alice = Account::new(node.network_type()).address();
+
+        let policy = ReconnectPolicy::default().with_initial_delay(Duration::from_millis(50));
+        let mut ws = SiriusWebsocketClient::new(node.url())
+            .await
+            .unwrap()
+            .with_reconnect(policy);
+
+        let states = record_states(&mut ws);
+        let heights = record_heights(&mut ws).await;
+        ws.add_confirmed_added_handlers(&alice, |_| false)
+            .await
+            .unwrap()
+            .detach();
+
+        let first_uid = ws.uid();
+        let handle = ws.handle();
+        let listen = tokio::spawn(async move {
+            let result = ws.listen().await;
+            result.map(|()| ws)
+        });
+
+        let subscriptions = vec![
+            "block".to_string(),
+            format!("confirmedAdded/{}", alice.address_string()),
+        ];
+        wait_until(|| node.subscriptions() == subscriptions).await;
+
+        node.disconnect_websockets();
+        wait_until(|| {
+            states
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|state| matches!(state, ConnectionState::Reconnected { .. }))
+        })
+        .await;
+        wait_until(|| node.subscriptions() == subscriptions).await;
+
+        let height = node.harvest();
+        wait_until(|| *heights.lock().unwrap() == vec![height]).await;
+
+        handle.close().await.unwrap();
+        let ws = listen.await.unwrap().unwrap();
+
+        assert_ne!(ws.uid(), first_uid);
+
+        let states = states.lock().unwrap();
+        assert!(matches!(states[0], ConnectionState::Disconnected { .. }));
+        assert_eq!(
+            states[1..],
+            [
+                ConnectionState::Reconnecting {
+                    attempt: 1,
+                    delay: Duration::from_millis(50)
+                },
+                ConnectionState::Reconnected { uid: ws.uid() },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reconnect_backs_off_and_gives_up() {
+        let node = MockNode::start().await.unwrap();
+
+        let policy = ReconnectPolicy::default()
+            .with_initial_delay(Duration::from_millis(10))
+            .with_multiplier(3)
+            .with_max_attempts(3);
+        let mut ws = SiriusWebsocketClient::new(node.url())
+            .await
+            .unwrap()
+            .with_reconnect(policy);
+
+        let states = record_states(&mut ws);
+        record_heights(&mut ws).await;
+
+        let listen = tokio::spawn(async move { ws.listen().await });
+        wait_until(|| node.subscriptions() == ["block"]).await;
+
+        node.refuse_websockets(true);
+        node.disconnect_websockets();
+
+        match listen.await.unwrap() {
+            Err(Error::Websocket(_)) => {}
+            result => panic!("unexpected result {:?}", result),
+        }
+
+        let delays: Vec<Duration> = states
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|state| match state {
+                ConnectionState::Reconnecting { delay, .. } => Some(*delay),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(10),
+                Duration::from_millis(30),
+                Duration::from_millis(90)
+            ]
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn backfilled_messages_are_not_passed_again() {
+        let node = MockNode::start().await.unwrap();
+        let client = SiriusClient::new(vec![node.url()]).await.unwrap();
+
+        let network_type = node.network_type();
+        let sender = Account::new(network_type);
+        let recipient = Account::new(network_type).address();
+
+        let policy = ReconnectPolicy::default().with_initial_delay(Duration::from_millis(50));
+        let mut ws = SiriusWebsocketClient::new(node.url())
+            .await
+            .unwrap()
+            .with_reconnect(policy)
+            .with_backfill(&client);
+
+        let subscriptions = vec![
+            "block".to_string(),
+            format!("confirmedAdded/{}", recipient.address_string()),
+        ];
+
+        // a block harvested between the replay of the subscriptions and the backfill is both
+        // sent by the node and read by the backfill.
+        let harvester = node.clone();
+        let replayed = subscriptions.clone();
+        ws.add_state_handlers(move |state| {
+            if let ConnectionState::Reconnected { .. } = state {
+                block_in_place(|| {
+                    for _ in 0..200 {
+                        if harvester.subscriptions() == replayed {
+                            break;
+                        }
+                        thread::sleep(Duration::from_millis(10));
+                    }
+                    harvester.harvest();
+                });
+            }
+            false
+        });
+
+        let states = record_states(&mut ws);
+        let heights = record_heights(&mut ws).await;
+
+        let hashes = Received::default();
+        let handler_hashes = hashes.clone();
+        ws.add_confirmed_added_handlers(&recipient, move |tx| {
+            handler_hashes
+                .lock()
+                .unwrap()
+                .push(tx.transaction_hash().to_uppercase());
+            false
+        })
+        .await
+        .unwrap()
+        .detach();
+
+        let handle = ws.handle();
+        let listen = tokio::spawn(async move { ws.listen().await });
+        wait_until(|| node.subscriptions() == subscriptions).await;
+
+        let transfer = TransferTransaction::new(
+            Deadline::default(),
+            recipient.clone(),
+            vec![Mosaic::xpx(1)],
+            PlainMessage::empty(),
+            network_type,
+        )
+        .unwrap();
+        let signed = sender.sign(transfer, &node.generation_hash()).unwrap();
+        client.transaction_api().announce(&signed).await.unwrap();
+
+        node.disconnect_websockets();
+        wait_until(|| {
+            states
+                .lock()
+                .unwrap()
+                .contains(&ConnectionState::Backfilled {
+                    from_height: 2,
+                    to_height: 2,
+                })
+        })
+        .await;
+
+        // the messages of the next block come after the ones sent twice.
+        let next = node.harvest();
+        wait_until(|| heights.lock().unwrap().last() == Some(&next)).await;
+
+        handle.close().await.unwrap();
+        listen.await.unwrap().unwrap();
+
+        assert_eq!(*heights.lock().unwrap(), vec![2, 3]);
+        assert_eq!(*hashes.lock().unwrap(), vec![signed.get_hash()]);
+    }
+
+    fn short_keepalive() -> KeepAlive {
+        KeepAlive::new(Duration::from_millis(20), Duration::from_millis(100))
+    }
+
+    #[tokio::test]
+    async fn pongs_keep_an_idle_connection_alive() {
+        let node = MockNode::start().await.unwrap();
+        let mut ws = SiriusWebsocketClient::new(node.url())
+            .await
+            .unwrap()
+            .with_keepalive(short_keepalive());
+
+        let states = record_states(&mut ws);
+
+        let handle = ws.handle();
+        let listen = tokio::spawn(async move { ws.listen().await });
+
+        // no block is harvested, only the pongs are received.
+        delay_for(Duration::from_millis(500)).await;
+
+        handle.close().await.unwrap();
+        listen.await.unwrap().unwrap();
+
+        assert!(states.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unanswered_pings_make_the_connection_stale() {
+        let node = MockNode::start().await.unwrap();
+        let mut ws = SiriusWebsocketClient::new(node.url())
+            .await
+            .unwrap()
+            .with_keepalive(short_keepalive());
+
+        let states = record_states(&mut ws);
+        record_heights(&mut ws).await;
+
+        let listen = tokio::spawn(async move { ws.listen().await });
+        wait_until(|| node.subscriptions() == ["block"]).await;
+
+        node.stall_websockets();
+
+        match listen.await.unwrap() {
+            Err(Error::Stale(idle)) => assert!(idle >= Duration::from_millis(100)),
+            result => panic!("unexpected result {:?}", result),
+        }
+
+        let states = states.lock().unwrap();
+        assert_eq!(states.len(), 1);
+        assert!(matches!(states[0], ConnectionState::Stale { .. }));
+    }
 }
@@ -0,0 +1,54 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    futures_util::{SinkExt, StreamExt},
+    tokio_tungstenite::{
+        tungstenite::{Error as WsError, Message},
+        WebSocketStream,
+    },
+};
+
+use super::{client::AutoStream, WsReplay};
+
+/// The messages read by a [SiriusWebsocketClient], from a node or a recording.
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+pub(crate) enum Connection {
+    Node(WebSocketStream<AutoStream<tokio::net::TcpStream>>),
+    /// Messages sent to a replay are dropped.
+    Replay(WsReplay),
+}
+
+impl Connection {
+    pub fn is_replay(&self) -> bool {
+        match self {
+            Connection::Replay(_) => true,
+            Connection::Node(_) => false,
+        }
+    }
+
+    pub async fn next(&mut self) -> Option<Result<Message, WsError>> {
+        match self {
+            Connection::Node(conn) => conn.next().await,
+            Connection::Replay(replay) => replay.next().await.map(|msg| Ok(Message::text(msg))),
+        }
+    }
+
+    pub async fn send(&mut self, msg: Message) -> Result<(), WsError> {
+        match self {
+            Connection::Node(conn) => conn.send(msg).await,
+            Connection::Replay(_) => Ok(()),
+        }
+    }
+
+    pub async fn close(&mut self) -> Result<(), WsError> {
+        match self {
+            Connection::Node(conn) => conn.close(None).await,
+            Connection::Replay(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        pin::Pin,
+        task::{Context, Poll},
+    },
+    futures_util::stream::Stream,
+    tokio::sync::mpsc::UnboundedSender,
+};
+
+use crate::{
+    blockchain::BlockInfo,
+    multisig::CosignatureInfo,
+    transaction::{AggregateTransaction, Transaction, TransactionInfo, TransactionStatus},
+    Result,
+};
+
+use super::{Handler, WsChannel};
+
+/// A message received on a websocket channel.
+#[derive(Debug, Clone)]
+pub enum WsEvent {
+    Block(BlockInfo),
+    ConfirmedAdded(Box<dyn Transaction>),
+    UnconfirmedAdded(Box<dyn Transaction>),
+    UnconfirmedRemoved(TransactionInfo),
+    PartialAdded(AggregateTransaction),
+    PartialRemoved(TransactionInfo),
+    Cosignature(CosignatureInfo),
+    /// A transaction rejected by the node.
+    Status(TransactionStatus),
+}
+
+impl WsEvent {
+    pub fn channel(&self) -> WsChannel {
+        match self {
+            WsEvent::Block(_) => WsChannel::Block,
+            WsEvent::ConfirmedAdded(_) => WsChannel::ConfirmedAdded,
+            WsEvent::UnconfirmedAdded(_) => WsChannel::UnconfirmedAdded,
+            WsEvent::UnconfirmedRemoved(_) => WsChannel::UnconfirmedRemoved,
+            WsEvent::PartialAdded(_) => WsChannel::PartialAdded,
+            WsEvent::PartialRemoved(_) => WsChannel::PartialRemoved,
+            WsEvent::Cosignature(_) => WsChannel::Cosignature,
+            WsEvent::Status(_) => WsChannel::Status,
+        }
+    }
+}
+
+pub(crate) struct HandlerStream {
+    pub sender: UnboundedSender<Result<WsEvent>>,
+}
+
+impl Handler for HandlerStream {}
+
+/// Stream of the [WsEvent] of a subscription, returned by
+/// [SiriusWebsocketClient::subscribe].
+///
+/// [SiriusWebsocketClient::subscribe]: struct.SiriusWebsocketClient.html#method.subscribe
+pub struct WsEventStream {
+    inner: Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>,
+}
+
+impl WsEventStream {
+    pub(crate) fn new<S>(inner: S) -> Self
+    where
+        S: Stream<Item = Result<WsEvent>> + Send + 'static,
+    {
+        WsEventStream {
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl Stream for WsEventStream {
+    type Item = Result<WsEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
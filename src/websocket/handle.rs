@@ -0,0 +1,143 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use tokio::sync::{mpsc::UnboundedSender, oneshot};
+use tokio_tungstenite::tungstenite::Error as WsError;
+
+use crate::{account::Address, models::error::Error, Result};
+
+use super::{WsChannel, WsEventStream, WsSubscription};
+
+/// Requests sent by a [WsHandle] to the listening client.
+pub(crate) enum Command {
+    Subscribe {
+        channel: WsChannel,
+        address: Option<Address>,
+        reply: oneshot::Sender<Result<WsEventStream>>,
+    },
+    Unsubscribe {
+        channel: WsChannel,
+        address: Option<Address>,
+        reply: oneshot::Sender<Result<()>>,
+    },
+    Subscriptions {
+        reply: oneshot::Sender<Vec<WsSubscription>>,
+    },
+    Close {
+        reply: oneshot::Sender<Result<()>>,
+    },
+}
+
+/// Cloneable handle changing the subscriptions of a [SiriusWebsocketClient] from other tasks
+/// while it listens, returned by [SiriusWebsocketClient::handle].
+///
+/// The requests are served by [SiriusWebsocketClient::listen], so they wait until it runs,
+/// and fail with `Error::Websocket(AlreadyClosed)` once it has returned. A client listening
+/// again needs a new handle.
+///
+/// # Example
+///
+/// ```
+///use futures_util::StreamExt;
+///use xpx_chain_sdk::{
+///    account::Address,
+///    websocket::{SiriusWebsocketClient, WsChannel},
+///};
+///
+///#[tokio::main]
+///async fn main() {
+/// let node_url = "http://bctestnet1.brimstone.xpxsirius.io:3000";
+/// let mut ws = SiriusWebsocketClient::new(node_url).await.unwrap();
+///
+///    let handle = ws.handle();
+///    tokio::spawn(async move { ws.listen().await });
+///
+///    let address = Address::from_raw("VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS").unwrap();
+///    let mut confirmed = handle
+///        .subscribe(WsChannel::ConfirmedAdded, Some(&address))
+///        .await
+///        .unwrap();
+///
+///    if let Some(Ok(event)) = confirmed.next().await {
+///        println!("{:?}", event);
+///    }
+///
+///    handle.close().await.unwrap();
+///}
+/// ```
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+/// [SiriusWebsocketClient::handle]: struct.SiriusWebsocketClient.html#method.handle
+/// [SiriusWebsocketClient::listen]: struct.SiriusWebsocketClient.html#method.listen
+#[derive(Clone)]
+pub struct WsHandle {
+    commands: UnboundedSender<Command>,
+}
+
+impl WsHandle {
+    pub(crate) fn new(commands: UnboundedSender<Command>) -> Self {
+        WsHandle { commands }
+    }
+
+    /// Same as [SiriusWebsocketClient::subscribe].
+    ///
+    /// [SiriusWebsocketClient::subscribe]: struct.SiriusWebsocketClient.html#method.subscribe
+    pub async fn subscribe(
+        &self,
+        channel: WsChannel,
+        address: Option<&Address>,
+    ) -> Result<WsEventStream> {
+        let address = address.cloned();
+        self.request(|reply| Command::Subscribe {
+            channel,
+            address,
+            reply,
+        })
+        .await?
+    }
+
+    /// Same as [SiriusWebsocketClient::unsubscribe].
+    ///
+    /// [SiriusWebsocketClient::unsubscribe]: struct.SiriusWebsocketClient.html#method.unsubscribe
+    pub async fn unsubscribe(&self, channel: WsChannel, address: Option<&Address>) -> Result<()> {
+        let address = address.cloned();
+        self.request(|reply| Command::Unsubscribe {
+            channel,
+            address,
+            reply,
+        })
+        .await?
+    }
+
+    /// Same as [SiriusWebsocketClient::subscriptions].
+    ///
+    /// [SiriusWebsocketClient::subscriptions]: struct.SiriusWebsocketClient.html#method.subscriptions
+    pub async fn subscriptions(&self) -> Result<Vec<WsSubscription>> {
+        self.request(|reply| Command::Subscriptions { reply }).await
+    }
+
+    /// Ends [SiriusWebsocketClient::listen] and closes the connection.
+    ///
+    /// [SiriusWebsocketClient::listen]: struct.SiriusWebsocketClient.html#method.listen
+    pub async fn close(&self) -> Result<()> {
+        self.request(|reply| Command::Close { reply }).await?
+    }
+
+    async fn request<T, F>(&self, command: F) -> Result<T>
+    where
+        F: FnOnce(oneshot::Sender<T>) -> Command,
+    {
+        let (reply, response) = oneshot::channel();
+
+        self.commands
+            .send(command(reply))
+            .map_err(|_| Error::Websocket(WsError::AlreadyClosed))?;
+
+        response
+            .await
+            .map_err(|_| Error::Websocket(WsError::AlreadyClosed))
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::time::Duration;
+
+/// Expected block time of the Sirius chain.
+pub const DEFAULT_BLOCK_TIME: Duration = Duration::from_secs(15);
+
+/// Keepalive of a [SiriusWebsocketClient] connection.
+///
+/// A ping is sent every `ping_interval` and the connection is stale once nothing, pongs
+/// included, was received for `idle_timeout`. A stale connection ends
+/// [SiriusWebsocketClient::listen] with `Error::Stale`, or is reconnected when a
+/// [ReconnectPolicy] is set.
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+/// [SiriusWebsocketClient::listen]: struct.SiriusWebsocketClient.html#method.listen
+/// [ReconnectPolicy]: struct.ReconnectPolicy.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeepAlive {
+    pub ping_interval: Duration,
+    pub idle_timeout: Duration,
+}
+
+impl KeepAlive {
+    pub fn new(ping_interval: Duration, idle_timeout: Duration) -> Self {
+        KeepAlive {
+            ping_interval,
+            idle_timeout,
+        }
+    }
+
+    /// Pings every block and gives up after four blocks without traffic; a `block`
+    /// subscription receives a message every `block_time`.
+    pub fn for_block_time(block_time: Duration) -> Self {
+        KeepAlive::new(block_time, block_time * 4)
+    }
+}
+
+impl Default for KeepAlive {
+    fn default() -> Self {
+        KeepAlive::for_block_time(DEFAULT_BLOCK_TIME)
+    }
+}
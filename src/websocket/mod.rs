@@ -8,15 +8,28 @@ pub use self::block::*;
 pub use self::client::*;
 pub use self::confirmed::*;
 pub use self::cosignature::*;
+pub use self::event::*;
+pub use self::handle::*;
+pub use self::keepalive::*;
 pub use self::partial::*;
+pub use self::reconnect::*;
+pub use self::record::*;
 pub use self::status::*;
+pub use self::subscription::*;
 pub use self::unconfirmed::*;
 
 mod block;
 mod client;
+mod connection;
 mod confirmed;
 mod cosignature;
+mod event;
+mod handle;
+mod keepalive;
 mod model;
 mod partial;
+mod reconnect;
+mod record;
 mod status;
+mod subscription;
 mod unconfirmed;
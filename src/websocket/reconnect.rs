@@ -0,0 +1,87 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use ::std::time::Duration;
+
+/// Exponential backoff used by [SiriusWebsocketClient::listen] to reconnect after the
+/// connection to the node is lost.
+///
+/// The n-th attempt waits `initial_delay * multiplier^(n - 1)`, capped at `max_delay`.
+///
+/// [SiriusWebsocketClient::listen]: struct.SiriusWebsocketClient.html#method.listen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectPolicy {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: u32,
+    /// Gives up after this number of failed attempts; retries forever when `None`.
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// The wait before the `attempt`-th reconnection, starting at 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt.saturating_sub(1));
+
+        self.initial_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2,
+            max_attempts: None,
+        }
+    }
+}
+
+/// Connection events of a reconnecting [SiriusWebsocketClient].
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState {
+    /// Nothing was received from the node for `idle`, see [KeepAlive].
+    ///
+    /// [KeepAlive]: struct.KeepAlive.html
+    Stale { idle: Duration },
+    /// The connection was lost, with the error that closed it if any.
+    Disconnected { error: Option<String> },
+    /// Waiting `delay` before the `attempt`-th reconnection.
+    Reconnecting { attempt: u32, delay: Duration },
+    /// Connected again with a new `uid` and every subscription replayed.
+    Reconnected { uid: String },
+    /// The blocks and confirmed transactions between these heights, both included, were
+    /// read from the REST routes and passed to the handlers.
+    Backfilled { from_height: u64, to_height: u64 },
+    /// The missed blocks or transactions could not be read; listening goes on.
+    BackfillFailed { error: String },
+}
@@ -0,0 +1,319 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {
+    ::std::{
+        collections::VecDeque,
+        fs::File,
+        io::{BufRead, BufReader, BufWriter, Write},
+        path::Path,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+    tokio::time::{delay_until, Instant},
+};
+
+use crate::Result;
+
+/// A text message received from the node, one JSON line of a recording.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsRecord {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The raw message, as sent by the node.
+    pub message: String,
+}
+
+impl WsRecord {
+    pub fn new(message: &str) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_millis() as u64)
+            .unwrap_or_default();
+
+        WsRecord {
+            timestamp,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Writes every text message received by a [SiriusWebsocketClient] as a line of JSON,
+/// see [SiriusWebsocketClient::with_recorder].
+///
+/// Each line is flushed as it is written, so a recording survives a crash of the process.
+/// The writes block the listening loop: record to a local file or to memory, not to a slow
+/// writer such as a network share.
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+/// [SiriusWebsocketClient::with_recorder]: struct.SiriusWebsocketClient.html#method.with_recorder
+pub struct WsRecorder {
+    writer: Box<dyn Write + Send>,
+}
+
+impl WsRecorder {
+    pub fn new<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        WsRecorder {
+            writer: Box::new(writer),
+        }
+    }
+
+    /// Records to the file at `path`, truncated if it exists.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(WsRecorder::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Writes and flushes `message`, blocking until done.
+    pub fn record(&mut self, message: &str) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, &WsRecord::new(message))?;
+        self.writer.write_all(b"\n")?;
+        Ok(self.writer.flush()?)
+    }
+}
+
+/// Recorded messages replayed by [SiriusWebsocketClient::replay] through the decoding and
+/// handlers of the client, without a node.
+///
+/// The messages are replayed as fast as they are read, unless
+/// [WsReplay::with_original_timing] waits the time elapsed between them when they were
+/// recorded.
+///
+/// # Example
+///
+/// ```
+///use xpx_chain_sdk::{
+///    account::Address,
+///    websocket::{SiriusWebsocketClient, WsRecord, WsReplay},
+///};
+///
+///#[tokio::main]
+///async fn main() {
+///    // a recording written by a `WsRecorder` is read with `WsReplay::open`.
+///    let replay = WsReplay::new(vec![WsRecord::new(
+///        r#"{"meta":{"channelName":"partialRemoved","address":"VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS","hash":"B2E6B4D4E0A5D8A4AE1B7C2C4AB4BB4A1C0E5D1F6E0C2A6F4E5D9A8C7B6A5F4E"}}"#,
+///    )]);
+///
+///    let mut ws = SiriusWebsocketClient::replay(replay);
+///
+///    let address = Address::from_raw("VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS").unwrap();
+///    ws.add_partial_removed_handlers(&address, |info| {
+///        println!("removed {}", info.transaction_hash());
+///        false
+///    })
+///    .await
+///    .unwrap()
+///    .detach();
+///
+///    ws.listen().await.unwrap();
+///}
+/// ```
+///
+/// [SiriusWebsocketClient::replay]: struct.SiriusWebsocketClient.html#method.replay
+#[derive(Debug, Clone)]
+pub struct WsReplay {
+    records: VecDeque<WsRecord>,
+    original_timing: bool,
+    last_timestamp: Option<u64>,
+    /// When the next message is due with the original timing.
+    due: Option<Instant>,
+}
+
+impl WsReplay {
+    pub fn new(records: Vec<WsRecord>) -> Self {
+        WsReplay {
+            records: records.into(),
+            original_timing: false,
+            last_timestamp: None,
+            due: None,
+        }
+    }
+
+    /// Reads the JSON lines written by a [WsRecorder]; blank lines are skipped.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let mut records = vec![];
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            records.push(serde_json::from_str(&line)?);
+        }
+
+        Ok(WsReplay::new(records))
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        WsReplay::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn with_original_timing(mut self) -> Self {
+        self.original_timing = true;
+        self
+    }
+
+    /// The messages left to replay.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The next message; a call cancelled while waiting keeps the message and its due time.
+    pub(crate) async fn next(&mut self) -> Option<String> {
+        let timestamp = self.records.front()?.timestamp;
+
+        if self.original_timing {
+            if let Some(last_timestamp) = self.last_timestamp {
+                let elapsed = Duration::from_millis(timestamp.saturating_sub(last_timestamp));
+                let due = *self.due.get_or_insert_with(|| Instant::now() + elapsed);
+                delay_until(due).await;
+            }
+        }
+        self.due = None;
+        self.last_timestamp = Some(timestamp);
+
+        self.records.pop_front().map(|record| record.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        ::std::{
+            io::{self, Write},
+            sync::{Arc, Mutex},
+            time::Duration,
+        },
+        futures_util::{FutureExt, StreamExt},
+        serde_json::json,
+    };
+
+    use crate::{
+        account::Address,
+        websocket::{SiriusWebsocketClient, WsChannel, WsEvent},
+    };
+
+    use super::{WsRecord, WsRecorder, WsReplay};
+
+    const ALICE: &str = "VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS";
+
+    /// A writer whose bytes stay readable once it is handed to a [WsRecorder].
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn partial_removed(hash: &str) -> String {
+        json!({
+            "meta": { "channelName": "partialRemoved", "address": ALICE, "hash": hash }
+        })
+        .to_string()
+    }
+
+    /// Replays `replay` and returns the hashes passed to a handler and to a stream.
+    async fn listen(replay: WsReplay, recorder: Option<WsRecorder>) -> (Vec<String>, Vec<String>) {
+        let mut ws = SiriusWebsocketClient::replay(replay);
+        if let Some(recorder) = recorder {
+            ws = ws.with_recorder(recorder);
+        }
+
+        let alice = Address::from_raw(ALICE).unwrap();
+        let handled = Arc::new(Mutex::new(vec![]));
+        let handler_handled = handled.clone();
+        ws.add_partial_removed_handlers(&alice, move |info| {
+            handler_handled
+                .lock()
+                .unwrap()
+                .push(info.transaction_hash());
+            false
+        })
+        .await
+        .unwrap()
+        .detach();
+
+        let events = ws
+            .subscribe(WsChannel::PartialRemoved, Some(&alice))
+            .await
+            .unwrap();
+
+        ws.listen().await.unwrap();
+        drop(ws);
+
+        let streamed = events
+            .map(|event| match event.unwrap() {
+                WsEvent::PartialRemoved(info) => info.transaction_hash(),
+                event => panic!("unexpected event {:?}", event),
+            })
+            .collect()
+            .await;
+
+        let handled = handled.lock().unwrap().clone();
+        (handled, streamed)
+    }
+
+    #[tokio::test]
+    async fn recordings_replay_the_same_messages() {
+        let hashes = vec!["A".repeat(64), "B".repeat(64)];
+        let source = WsReplay::new(
+            hashes
+                .iter()
+                .map(|hash| WsRecord::new(&partial_removed(hash)))
+                .collect(),
+        );
+
+        let buffer = SharedBuffer::default();
+        let recorded = listen(source, Some(WsRecorder::new(buffer.clone()))).await;
+        assert_eq!(recorded, (hashes.clone(), hashes.clone()));
+
+        let recording = buffer.0.lock().unwrap().clone();
+        assert_eq!(recording.iter().filter(|byte| **byte == b'\n').count(), 2);
+
+        let replay = WsReplay::from_reader(&recording[..]).unwrap();
+        assert_eq!(replay.len(), 2);
+
+        let replayed = listen(replay, None).await;
+        assert_eq!(replayed, (hashes.clone(), hashes));
+    }
+
+    #[tokio::test]
+    async fn a_cancelled_wait_keeps_the_message() {
+        let mut replay = WsReplay::new(vec![
+            WsRecord {
+                timestamp: 0,
+                message: "first".to_string(),
+            },
+            WsRecord {
+                timestamp: 50,
+                message: "second".to_string(),
+            },
+        ])
+        .with_original_timing();
+
+        assert_eq!(replay.next().await.as_deref(), Some("first"));
+        assert_eq!(replay.next().now_or_never(), None);
+        assert_eq!(replay.len(), 1);
+
+        tokio::time::delay_for(Duration::from_millis(60)).await;
+        assert_eq!(
+            replay.next().now_or_never(),
+            Some(Some("second".to_string()))
+        );
+    }
+}
@@ -6,7 +6,7 @@
 
 use crate::{
     api::Uint64Dto,
-    transaction::{BlockchainTimestamp, Deadline},
+    transaction::{BlockchainTimestamp, Deadline, TransactionGroup, TransactionStatusCode},
 };
 
 use super::{model::WsSubscribeDto, Handler};
@@ -37,8 +37,8 @@ impl WsSubscribeDto for WsStatusInfoDto {
         let deadline = Deadline::from(blockchain_timestamp);
 
         crate::transaction::TransactionStatus {
-            group: "".to_string(),
-            status: self.status,
+            group: TransactionGroup::Failed,
+            status: TransactionStatusCode::from(self.status),
             hash: self.hash,
             deadline: Some(deadline),
             height: None,
@@ -0,0 +1,217 @@
+/*
+ * Copyright 2018 ProximaX Limited. All rights reserved.
+ * Use of this source code is governed by the Apache 2.0
+ * license that can be found in the LICENSE file.
+ */
+
+use {::std::fmt, tokio::sync::mpsc::UnboundedSender};
+
+use crate::account::Address;
+
+use super::model::{
+    PATH_BLOCK, PATH_CONFIRMED_ADDED, PATH_COSIGNATURE, PATH_PARTIAL_ADDED, PATH_PARTIAL_REMOVED,
+    PATH_STATUS, PATH_UNCONFIRMED_ADDED, PATH_UNCONFIRMED_REMOVED,
+};
+
+/// The channels a [SiriusWebsocketClient] can subscribe to.
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WsChannel {
+    Block,
+    Status,
+    ConfirmedAdded,
+    UnconfirmedAdded,
+    UnconfirmedRemoved,
+    PartialAdded,
+    PartialRemoved,
+    Cosignature,
+}
+
+impl WsChannel {
+    pub fn as_str(&self) -> &'static str {
+        use WsChannel::*;
+
+        match self {
+            Block => PATH_BLOCK,
+            Status => PATH_STATUS,
+            ConfirmedAdded => PATH_CONFIRMED_ADDED,
+            UnconfirmedAdded => PATH_UNCONFIRMED_ADDED,
+            UnconfirmedRemoved => PATH_UNCONFIRMED_REMOVED,
+            PartialAdded => PATH_PARTIAL_ADDED,
+            PartialRemoved => PATH_PARTIAL_REMOVED,
+            Cosignature => PATH_COSIGNATURE,
+        }
+    }
+
+    /// The channel named `name` in the `meta.channelName` field of a message.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        use WsChannel::*;
+
+        match name {
+            PATH_BLOCK => Some(Block),
+            PATH_STATUS => Some(Status),
+            PATH_CONFIRMED_ADDED => Some(ConfirmedAdded),
+            PATH_UNCONFIRMED_ADDED => Some(UnconfirmedAdded),
+            PATH_UNCONFIRMED_REMOVED => Some(UnconfirmedRemoved),
+            PATH_PARTIAL_ADDED => Some(PartialAdded),
+            PATH_PARTIAL_REMOVED => Some(PartialRemoved),
+            PATH_COSIGNATURE => Some(Cosignature),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WsChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A channel subscription, for a single address on every channel but `block`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WsSubscription {
+    pub channel: WsChannel,
+    pub address: Option<String>,
+}
+
+impl WsSubscription {
+    pub fn new(channel: WsChannel, address: Option<&Address>) -> Self {
+        WsSubscription {
+            channel,
+            address: address.map(|address| normalize_address(&address.address)),
+        }
+    }
+
+    pub(crate) fn from_meta(channel: WsChannel, address: &str) -> Self {
+        WsSubscription {
+            channel,
+            address: Some(normalize_address(address)),
+        }
+    }
+
+    /// The path sent to the node to subscribe, e.g. `confirmedAdded/VC5IBQ...`.
+    pub fn path(&self) -> String {
+        match &self.address {
+            Some(address) => format!("{}/{}", self.channel, address),
+            None => self.channel.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for WsSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path())
+    }
+}
+
+/// Handle of a handler added to a [SiriusWebsocketClient].
+///
+/// Dropping the handle removes the handler, and the subscription is cancelled on the node
+/// once its last handler is removed. Call [SubscriptionHandle::detach] to keep the handler for
+/// the lifetime of the connection.
+///
+/// [SiriusWebsocketClient]: struct.SiriusWebsocketClient.html
+#[must_use = "the handler is removed when the handle is dropped, see `SubscriptionHandle::detach`"]
+pub struct SubscriptionHandle {
+    subscription: WsSubscription,
+    id: u64,
+    removed: Option<UnboundedSender<(WsSubscription, u64)>>,
+}
+
+impl SubscriptionHandle {
+    pub(crate) fn new(
+        subscription: WsSubscription,
+        id: u64,
+        removed: UnboundedSender<(WsSubscription, u64)>,
+    ) -> Self {
+        SubscriptionHandle {
+            subscription,
+            id,
+            removed: Some(removed),
+        }
+    }
+
+    pub fn subscription(&self) -> &WsSubscription {
+        &self.subscription
+    }
+
+    /// Keeps the handler after the handle is dropped.
+    pub fn detach(mut self) {
+        self.removed = None;
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        if let Some(removed) = self.removed.take() {
+            // the client is gone when the send fails, nothing left to remove.
+            let _ = removed.send((self.subscription.clone(), self.id));
+        }
+    }
+}
+
+fn normalize_address(address: &str) -> String {
+    address.replace("-", "").to_uppercase()
+}
+
+#[cfg(all(test, feature = "mock-node"))]
+mod tests {
+    use {::std::time::Duration, tokio::time::delay_for};
+
+    use crate::{account::Address, mock::MockNode, websocket::SiriusWebsocketClient};
+
+    use super::{WsChannel, WsSubscription};
+
+    const ALICE: &str = "VC4A3Z6ALFGJPYAGDK2CNE2JAXOMQKILYBVNLQFS";
+
+    /// Waits for the subscriptions received by `node` to be `expected`.
+    async fn wait_for_subscriptions(node: &MockNode, expected: &[&str]) {
+        for _ in 0..100 {
+            if node.subscriptions() == expected {
+                return;
+            }
+            delay_for(Duration::from_millis(10)).await;
+        }
+        assert_eq!(node.subscriptions(), expected);
+    }
+
+    #[tokio::test]
+    async fn dropping_the_last_handle_unsubscribes() {
+        let node = MockNode::start().await.unwrap();
+        let mut ws = SiriusWebsocketClient::new(node.url()).await.unwrap();
+        let alice = Address::from_raw(ALICE).unwrap();
+
+        let blocks = ws.add_block_handlers(|_| false).await.unwrap();
+        let first = ws
+            .add_confirmed_added_handlers(&alice, |_| false)
+            .await
+            .unwrap();
+        let second = ws
+            .add_confirmed_added_handlers(&alice, |_| false)
+            .await
+            .unwrap();
+
+        let confirmed = format!("confirmedAdded/{}", ALICE);
+        wait_for_subscriptions(&node, &["block", &confirmed]).await;
+
+        let handle = ws.handle();
+        blocks.detach();
+        drop(first);
+        let listen = tokio::spawn(async move { ws.listen().await });
+
+        // `second` still handles the subscription.
+        assert_eq!(handle.subscriptions().await.unwrap().len(), 2);
+        wait_for_subscriptions(&node, &["block", &confirmed]).await;
+
+        drop(second);
+        wait_for_subscriptions(&node, &["block"]).await;
+        assert_eq!(
+            handle.subscriptions().await.unwrap(),
+            vec![WsSubscription::new(WsChannel::Block, None)]
+        );
+
+        handle.close().await.unwrap();
+        listen.await.unwrap().unwrap();
+    }
+}